@@ -0,0 +1,58 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uses cgmath from a `no_std` crate, to check that it builds for targets
+//! without the standard library, such as `thumbv7em-none-eabihf`.
+
+#![no_std]
+
+extern crate cgmath;
+
+use cgmath::prelude::*;
+use cgmath::{
+    frustum, ortho, perspective, Basis3, Decomposed, Deg, Euler, Matrix3, Matrix4, Point3,
+    Quaternion, Rad, Vector3,
+};
+
+pub fn view_projection(eye: Point3<f32>, center: Point3<f32>) -> Option<Matrix4<f32>> {
+    let view = Matrix4::look_at_rh(eye, center, Vector3::unit_y());
+    let proj = perspective(Deg(60.0), 1.5, 0.1, 100.0);
+    (proj * view).invert()
+}
+
+pub fn projections() -> [Matrix4<f64>; 2] {
+    [
+        frustum(-1.0, 1.0, -1.0, 1.0, 0.1, 10.0),
+        ortho(-1.0, 1.0, -1.0, 1.0, 0.1, 10.0),
+    ]
+}
+
+pub fn interpolate(a: Quaternion<f32>, b: Quaternion<f32>, amount: f32) -> Vector3<f32> {
+    let rot = a.slerp(b, amount).normalize();
+    rot.rotate_vector(Vector3::unit_x())
+}
+
+pub fn euler_to_matrix(x: Rad<f32>, y: Rad<f32>, z: Rad<f32>) -> Matrix3<f32> {
+    let basis = Basis3::from(Quaternion::from(Euler { x, y, z }));
+    Matrix3::from(basis)
+        .invert()
+        .unwrap_or_else(Matrix3::identity)
+}
+
+pub fn transform(t: &Decomposed<Vector3<f32>, Quaternion<f32>>, p: Point3<f32>) -> Point3<f32> {
+    t.inverse_transform()
+        .map(|inverse| inverse.transform_point(p))
+        .unwrap_or(p)
+}
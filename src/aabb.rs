@@ -0,0 +1,306 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes.
+
+use core::iter;
+
+use structure::*;
+
+use matrix::{Matrix3, Matrix4};
+use num::BaseNum;
+use point::{Point2, Point3};
+use vector::{Vector2, Vector3};
+
+#[inline]
+fn partial_min<S: PartialOrd>(a: S, b: S) -> S {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+fn partial_max<S: PartialOrd>(a: S, b: S) -> S {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// An axis-aligned bounding box in 2-dimensional space.
+///
+/// The box is closed, so it contains the points on its boundary. Its `min`
+/// corner is expected to be less than or equal to its `max` corner on every
+/// axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb2<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point2<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point2<S>,
+}
+
+/// An axis-aligned bounding box in 3-dimensional space.
+///
+/// The box is closed, so it contains the points on its boundary. Its `min`
+/// corner is expected to be less than or equal to its `max` corner on every
+/// axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb3<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+macro_rules! impl_aabb {
+    ($Aabb:ident, $Point:ident, $Vector:ident, { $($field:ident),+ }) => {
+        impl<S: BaseNum> $Aabb<S> {
+            /// Construct the smallest box containing two points, which can be
+            /// any two opposite corners of the box.
+            #[inline]
+            pub fn new(a: $Point<S>, b: $Point<S>) -> $Aabb<S> {
+                $Aabb {
+                    min: a.zip(b, partial_min),
+                    max: a.zip(b, partial_max),
+                }
+            }
+
+            /// Construct the box containing a single point.
+            #[inline]
+            pub fn from_point(point: $Point<S>) -> $Aabb<S> {
+                $Aabb { min: point, max: point }
+            }
+
+            /// Construct the smallest box containing all the points yielded
+            /// by an iterator, or `None` if the iterator is empty.
+            pub fn from_points<I>(points: I) -> Option<$Aabb<S>>
+            where
+                I: IntoIterator<Item = $Point<S>>,
+            {
+                let mut points = points.into_iter();
+                points.next().map(|first| {
+                    let mut aabb = $Aabb::from_point(first);
+                    aabb.extend(points);
+                    aabb
+                })
+            }
+
+            /// Grow the box so that it contains `point`.
+            #[inline]
+            pub fn grow(&mut self, point: $Point<S>) {
+                self.min = self.min.zip(point, partial_min);
+                self.max = self.max.zip(point, partial_max);
+            }
+
+            /// The smallest box containing both this box and `other`.
+            #[inline]
+            pub fn union(&self, other: &$Aabb<S>) -> $Aabb<S> {
+                $Aabb {
+                    min: self.min.zip(other.min, partial_min),
+                    max: self.max.zip(other.max, partial_max),
+                }
+            }
+
+            /// The box containing the points inside both this box and
+            /// `other`, or `None` if the boxes do not overlap.
+            #[inline]
+            pub fn intersection(&self, other: &$Aabb<S>) -> Option<$Aabb<S>> {
+                if self.overlaps(other) {
+                    Some($Aabb {
+                        min: self.min.zip(other.min, partial_max),
+                        max: self.max.zip(other.max, partial_min),
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Check if the boxes have any points in common, including points
+            /// on their boundaries.
+            #[inline]
+            pub fn overlaps(&self, other: &$Aabb<S>) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+
+            /// Check if `point` is inside the box or on its boundary.
+            #[inline]
+            pub fn contains(&self, point: $Point<S>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+
+            /// The point inside the box or on its boundary closest to
+            /// `point`.
+            #[inline]
+            pub fn closest_point(&self, point: $Point<S>) -> $Point<S> {
+                point.zip(self.min, partial_max).zip(self.max, partial_min)
+            }
+
+            /// The squared distance from the box to `point`, which is zero
+            /// if the point is inside the box.
+            #[inline]
+            pub fn distance2(&self, point: $Point<S>) -> S {
+                // Subtract in the direction that can't underflow for
+                // unsigned scalars
+                $Vector::new($(
+                    if point.$field < self.min.$field {
+                        self.min.$field - point.$field
+                    } else if point.$field > self.max.$field {
+                        point.$field - self.max.$field
+                    } else {
+                        S::zero()
+                    }
+                ),+).magnitude2()
+            }
+
+            /// Check if `other` lies completely inside this box.
+            #[inline]
+            pub fn contains_aabb(&self, other: &$Aabb<S>) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// The point at the center of the box. For integer scalars, the
+            /// center is rounded towards the `min` corner.
+            #[inline]
+            pub fn center(&self) -> $Point<S> {
+                let two = S::one() + S::one();
+                self.min + self.extents() / two
+            }
+
+            /// The size of the box along each axis.
+            #[inline]
+            pub fn extents(&self) -> $Vector<S> {
+                self.max - self.min
+            }
+        }
+
+        impl<S: BaseNum> iter::Extend<$Point<S>> for $Aabb<S> {
+            fn extend<I: IntoIterator<Item = $Point<S>>>(&mut self, points: I) {
+                for point in points {
+                    self.grow(point);
+                }
+            }
+        }
+    };
+}
+
+impl_aabb!(Aabb2, Point2, Vector2, { x, y });
+impl_aabb!(Aabb3, Point3, Vector3, { x, y, z });
+
+impl<S: BaseNum> Aabb2<S> {
+    /// The area of the box.
+    #[inline]
+    pub fn area(&self) -> S {
+        self.extents().product()
+    }
+
+    /// The corners of the box, in counter-clockwise order starting at `min`.
+    #[inline]
+    pub fn corners(&self) -> [Point2<S>; 4] {
+        let (min, max) = (self.min, self.max);
+        [
+            min,
+            Point2::new(max.x, min.y),
+            max,
+            Point2::new(min.x, max.y),
+        ]
+    }
+
+    /// Apply an affine transformation to the box, returning the smallest box
+    /// containing the transformed box.
+    ///
+    /// The transformation is given as a homogeneous matrix, or anything that
+    /// converts to one, such as a `Decomposed`. The result is computed with
+    /// Arvo's method, without transforming each corner.
+    pub fn transform<T: Into<Matrix3<S>>>(&self, transform: T) -> Aabb2<S> {
+        let m = transform.into();
+        let translation = Point2::new(m.z.x, m.z.y);
+        let (mut min, mut max) = (translation, translation);
+
+        for &(axis, lo, hi) in &[
+            (m.x.truncate(), self.min.x, self.max.x),
+            (m.y.truncate(), self.min.y, self.max.y),
+        ] {
+            let (a, b) = (axis * lo, axis * hi);
+            min += a.zip(b, partial_min);
+            max += a.zip(b, partial_max);
+        }
+
+        Aabb2 { min, max }
+    }
+}
+
+impl<S: BaseNum> Aabb3<S> {
+    /// The volume of the box.
+    #[inline]
+    pub fn volume(&self) -> S {
+        self.extents().product()
+    }
+
+    /// The area of the surface of the box.
+    #[inline]
+    pub fn surface_area(&self) -> S {
+        let e = self.extents();
+        let two = S::one() + S::one();
+        two * (e.x * e.y + e.y * e.z + e.z * e.x)
+    }
+
+    /// The corners of the box. The corner at index `i` takes its `x`
+    /// coordinate from `max` if bit 0 of `i` is set, its `y` coordinate if
+    /// bit 1 is set, and its `z` coordinate if bit 2 is set.
+    #[inline]
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            min,
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            max,
+        ]
+    }
+
+    /// Apply an affine transformation to the box, returning the smallest box
+    /// containing the transformed box.
+    ///
+    /// The transformation is given as a homogeneous matrix, or anything that
+    /// converts to one, such as a `Decomposed`. The result is computed with
+    /// Arvo's method, without transforming each corner.
+    pub fn transform<T: Into<Matrix4<S>>>(&self, transform: T) -> Aabb3<S> {
+        let m = transform.into();
+        let translation = Point3::new(m.w.x, m.w.y, m.w.z);
+        let (mut min, mut max) = (translation, translation);
+
+        for &(axis, lo, hi) in &[
+            (m.x.truncate(), self.min.x, self.max.x),
+            (m.y.truncate(), self.min.y, self.max.y),
+            (m.z.truncate(), self.min.z, self.max.z),
+        ] {
+            let (a, b) = (axis * lo, axis * hi);
+            min += a.zip(b, partial_min);
+            max += a.zip(b, partial_max);
+        }
+
+        Aabb3 { min, max }
+    }
+}
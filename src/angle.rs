@@ -15,10 +15,10 @@
 
 //! Angle units for type-safe, self-documenting code.
 
-use std::f64;
-use std::fmt;
-use std::iter;
-use std::ops::*;
+use core::f64;
+use core::fmt;
+use core::iter;
+use core::ops::*;
 
 use num_traits::{cast, Bounded};
 #[cfg(feature = "rand")]
@@ -231,3 +231,8 @@ macro_rules! impl_angle {
 
 impl_angle!(Rad, "{:?} rad", f64::consts::PI * 2.0, f64::consts::PI);
 impl_angle!(Deg, "{:?}°", 360, 180);
+
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Rad);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Deg);
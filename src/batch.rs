@@ -0,0 +1,485 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::array;
+use core::convert::TryInto;
+use core::mem;
+
+use structure::*;
+
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation2};
+use transform::{Decomposed, Transform};
+use vector::{Vector2, Vector3, Vector4};
+use wide::{Scalarx4, Vector3x4};
+
+/// Points and vectors that can be read from and written to byte buffers, such
+/// as interleaved vertex buffers. The components are stored one after the
+/// other in native byte order, without padding.
+pub trait StridedElement: Copy {
+    /// The number of bytes used by the element.
+    const SIZE: usize;
+
+    /// Read the element from the start of `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is shorter than `SIZE`.
+    fn read_bytes(bytes: &[u8]) -> Self;
+
+    /// Write the element to the start of `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is shorter than `SIZE`.
+    fn write_bytes(&self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_strided_element {
+    ($T:ident<$S:ident> { $($field:ident),+ }, $n:expr) => {
+        impl StridedElement for $T<$S> {
+            const SIZE: usize = $n * mem::size_of::<$S>();
+
+            #[inline]
+            fn read_bytes(bytes: &[u8]) -> $T<$S> {
+                let mut chunks = bytes[..Self::SIZE].chunks_exact(mem::size_of::<$S>());
+                let mut next = || $S::from_ne_bytes(chunks.next().unwrap().try_into().unwrap());
+                $T { $($field: next()),+ }
+            }
+
+            #[inline]
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                let mut chunks = bytes[..Self::SIZE].chunks_exact_mut(mem::size_of::<$S>());
+                $(chunks.next().unwrap().copy_from_slice(&self.$field.to_ne_bytes());)+
+            }
+        }
+    };
+}
+
+impl_strided_element!(Point2<f32> { x, y }, 2);
+impl_strided_element!(Point2<f64> { x, y }, 2);
+impl_strided_element!(Point3<f32> { x, y, z }, 3);
+impl_strided_element!(Point3<f64> { x, y, z }, 3);
+impl_strided_element!(Vector2<f32> { x, y }, 2);
+impl_strided_element!(Vector2<f64> { x, y }, 2);
+impl_strided_element!(Vector3<f32> { x, y, z }, 3);
+impl_strided_element!(Vector3<f64> { x, y, z }, 3);
+impl_strided_element!(Vector4<f32> { x, y, z, w }, 4);
+impl_strided_element!(Vector4<f64> { x, y, z, w }, 4);
+
+/// Transformations of many points or vectors at once.
+///
+/// This is implemented for `Matrix3`, `Matrix4`, `Decomposed` and
+/// `Quaternion`, which rotates points about the origin. The three-dimensional
+/// transforms process four elements at a time in the structure-of-arrays
+/// layout of `Vector3x4`, giving the same results as transforming them one by
+/// one. `Matrix4` skips the perspective divide when its last row is
+/// `(0, 0, 0, 1)`.
+///
+/// The strided methods work on interleaved vertex buffers: the first element
+/// starts at `offset` bytes into the buffer, and each following element starts
+/// `stride` bytes after the previous one. Every element that fits in the buffer
+/// is transformed.
+pub trait BatchTransform<P: EuclideanSpace> {
+    /// Transform the points of a slice in place.
+    fn transform_points_in_place(&self, points: &mut [P]);
+
+    /// Transform the vectors of a slice in place.
+    fn transform_vectors_in_place(&self, vectors: &mut [P::Diff]);
+
+    /// Transform the points of `input`, writing the results to `output`.
+    ///
+    /// # Panics
+    ///
+    /// If the slices have different lengths.
+    fn transform_points_into(&self, input: &[P], output: &mut [P]);
+
+    /// Transform the vectors of `input`, writing the results to `output`.
+    ///
+    /// # Panics
+    ///
+    /// If the slices have different lengths.
+    fn transform_vectors_into(&self, input: &[P::Diff], output: &mut [P::Diff]);
+
+    /// Transform the points stored in a byte buffer in place.
+    ///
+    /// # Panics
+    ///
+    /// If `stride` is smaller than the size of a point.
+    fn transform_points_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+    where
+        P: StridedElement;
+
+    /// Transform the vectors stored in a byte buffer in place.
+    ///
+    /// # Panics
+    ///
+    /// If `stride` is smaller than the size of a vector.
+    fn transform_vectors_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+    where
+        P::Diff: StridedElement;
+}
+
+#[inline]
+fn map_in_place<T: Copy, F: Fn(T) -> T>(items: &mut [T], f: F) {
+    for item in items {
+        *item = f(*item);
+    }
+}
+
+#[inline]
+fn map_into<T: Copy, U, F: Fn(T) -> U>(input: &[T], output: &mut [U], f: F) {
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "input and output slices have different lengths"
+    );
+    for (out, &item) in output.iter_mut().zip(input) {
+        *out = f(item);
+    }
+}
+
+#[inline]
+fn map_strided<T: StridedElement, F: Fn(T) -> T>(
+    buffer: &mut [u8],
+    offset: usize,
+    stride: usize,
+    f: F,
+) {
+    assert!(
+        stride >= T::SIZE,
+        "stride of {} bytes is smaller than the element size of {} bytes",
+        stride,
+        T::SIZE
+    );
+    let mut start = offset;
+    while buffer.len().saturating_sub(start) >= T::SIZE {
+        let bytes = &mut buffer[start..start + T::SIZE];
+        f(T::read_bytes(bytes)).write_bytes(bytes);
+        start += stride;
+    }
+}
+
+/// Points and vectors that are transformed as lanes of a `Vector3x4`.
+trait Lane<S>: Copy {
+    fn to_lane(self) -> Vector3<S>;
+    fn from_lane(v: Vector3<S>) -> Self;
+}
+
+impl<S: Copy> Lane<S> for Vector3<S> {
+    #[inline]
+    fn to_lane(self) -> Vector3<S> {
+        self
+    }
+
+    #[inline]
+    fn from_lane(v: Vector3<S>) -> Vector3<S> {
+        v
+    }
+}
+
+impl<S: Copy> Lane<S> for Point3<S> {
+    #[inline]
+    fn to_lane(self) -> Vector3<S> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn from_lane(v: Vector3<S>) -> Point3<S> {
+        Point3::new(v.x, v.y, v.z)
+    }
+}
+
+/// Load up to four items into the lanes of a wide vector, repeating the last
+/// item in the unused lanes.
+#[inline]
+fn gather<S: Copy, T: Lane<S>>(items: &[T]) -> Vector3x4<S> {
+    Vector3x4::from_vectors(array::from_fn(|i| items[i.min(items.len() - 1)].to_lane()))
+}
+
+/// Store the first lanes of a wide vector into up to four items.
+#[inline]
+fn scatter<S: Copy, T: Lane<S>>(wide: Vector3x4<S>, items: &mut [T]) {
+    for (item, v) in items.iter_mut().zip(wide.to_vectors()) {
+        *item = T::from_lane(v);
+    }
+}
+
+#[inline]
+fn map_wide_in_place<S, T, F>(items: &mut [T], f: F)
+where
+    S: Copy,
+    T: Lane<S>,
+    F: Fn(Vector3x4<S>) -> Vector3x4<S>,
+{
+    for chunk in items.chunks_mut(4) {
+        scatter(f(gather(chunk)), chunk);
+    }
+}
+
+#[inline]
+fn map_wide_into<S, T, F>(input: &[T], output: &mut [T], f: F)
+where
+    S: Copy,
+    T: Lane<S>,
+    F: Fn(Vector3x4<S>) -> Vector3x4<S>,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "input and output slices have different lengths"
+    );
+    for (chunk, out) in input.chunks(4).zip(output.chunks_mut(4)) {
+        scatter(f(gather(chunk)), out);
+    }
+}
+
+#[inline]
+fn map_wide_strided<S, T, F>(buffer: &mut [u8], offset: usize, stride: usize, f: F)
+where
+    S: Copy,
+    T: Lane<S> + StridedElement,
+    F: Fn(Vector3x4<S>) -> Vector3x4<S>,
+{
+    assert!(
+        stride >= T::SIZE,
+        "stride of {} bytes is smaller than the element size of {} bytes",
+        stride,
+        T::SIZE
+    );
+    let count = match buffer.len().checked_sub(offset.saturating_add(T::SIZE)) {
+        Some(room) => room / stride + 1,
+        None => 0,
+    };
+    for first in (0..count).step_by(4) {
+        let lanes = (count - first).min(4);
+        let start = |i: usize| offset + (first + i) * stride;
+        let items: [T; 4] = array::from_fn(|i| T::read_bytes(&buffer[start(i.min(lanes - 1))..]));
+        let mut results = items;
+        scatter(f(gather(&items)), &mut results);
+        for (i, item) in results[..lanes].iter().enumerate() {
+            item.write_bytes(&mut buffer[start(i)..]);
+        }
+    }
+}
+
+/// Compute `a * x + (b * y + c)` for every lane, which sums the products in
+/// the same order as `Vector3::dot` and `Vector4::dot` when `c` is the
+/// product of the last components.
+#[inline]
+fn mul_add2<S: BaseFloat>(a: S, x: [S; 4], b: S, y: [S; 4], c: Scalarx4<S>) -> Scalarx4<S> {
+    Scalarx4::splat(a) * Scalarx4(x) + (Scalarx4::splat(b) * Scalarx4(y) + c)
+}
+
+/// Multiply every lane by a matrix, giving the same results as
+/// `Matrix3 * Vector3`.
+#[inline]
+fn mul_matrix3<S: BaseFloat>(m: &Matrix3<S>, v: Vector3x4<S>) -> Vector3x4<S> {
+    let row = |r: usize| {
+        let c = Scalarx4::splat(m.z[r]) * Scalarx4(v.z);
+        mul_add2(m.x[r], v.x, m.y[r], v.y, c).0
+    };
+    Vector3x4::new(row(0), row(1), row(2))
+}
+
+fn matrix3_kernel<'a, S: BaseFloat>(
+    m: &'a Matrix3<S>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |v| mul_matrix3(m, v)
+}
+
+fn matrix4_point_kernel<'a, S: BaseFloat>(
+    m: &'a Matrix4<S>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    let affine = m.x.w.is_zero() && m.y.w.is_zero() && m.z.w.is_zero() && m.w.w.is_one();
+    // The products are summed in the same order as `Matrix4 * Vector4`, with
+    // the translation standing in for the product with a `w` of `1`
+    let row = move |r: usize, p: &Vector3x4<S>| {
+        let c = Scalarx4::splat(m.z[r]) * Scalarx4(p.z) + Scalarx4::splat(m.w[r]);
+        mul_add2(m.x[r], p.x, m.y[r], p.y, c)
+    };
+    move |p| {
+        let xyz = Vector3x4::new(row(0, &p).0, row(1, &p).0, row(2, &p).0);
+        if affine {
+            // The divide by a `w` of `1` would leave the points unchanged
+            xyz
+        } else {
+            xyz * (Scalarx4::one() / row(3, &p))
+        }
+    }
+}
+
+fn matrix4_vector_kernel<S: BaseFloat>(m: &Matrix4<S>) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> {
+    let linear = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+    move |v| mul_matrix3(&linear, v)
+}
+
+fn quaternion_kernel<'a, S: BaseFloat>(
+    q: &'a Quaternion<S>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |v| q * v
+}
+
+fn decomposed_quaternion_point_kernel<'a, S: BaseFloat>(
+    t: &'a Decomposed<Vector3<S>, Quaternion<S>>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |p| t.rot * (p * t.scale) + Vector3x4::splat(t.disp)
+}
+
+fn decomposed_quaternion_vector_kernel<'a, S: BaseFloat>(
+    t: &'a Decomposed<Vector3<S>, Quaternion<S>>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |v| t.rot * (v * t.scale)
+}
+
+fn decomposed_basis3_point_kernel<'a, S: BaseFloat>(
+    t: &'a Decomposed<Vector3<S>, Basis3<S>>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |p| mul_matrix3(t.rot.as_ref(), p * t.scale) + Vector3x4::splat(t.disp)
+}
+
+fn decomposed_basis3_vector_kernel<'a, S: BaseFloat>(
+    t: &'a Decomposed<Vector3<S>, Basis3<S>>,
+) -> impl Fn(Vector3x4<S>) -> Vector3x4<S> + 'a {
+    move |v| mul_matrix3(t.rot.as_ref(), v * t.scale)
+}
+
+macro_rules! impl_batch_transform_wide {
+    ($T:ty, $points:ident, $vectors:ident) => {
+        impl<S: BaseFloat> BatchTransform<Point3<S>> for $T {
+            fn transform_points_in_place(&self, points: &mut [Point3<S>]) {
+                map_wide_in_place(points, $points(self));
+            }
+
+            fn transform_vectors_in_place(&self, vectors: &mut [Vector3<S>]) {
+                map_wide_in_place(vectors, $vectors(self));
+            }
+
+            fn transform_points_into(&self, input: &[Point3<S>], output: &mut [Point3<S>]) {
+                map_wide_into(input, output, $points(self));
+            }
+
+            fn transform_vectors_into(&self, input: &[Vector3<S>], output: &mut [Vector3<S>]) {
+                map_wide_into(input, output, $vectors(self));
+            }
+
+            fn transform_points_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+            where
+                Point3<S>: StridedElement,
+            {
+                map_wide_strided::<S, Point3<S>, _>(buffer, offset, stride, $points(self));
+            }
+
+            fn transform_vectors_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+            where
+                Vector3<S>: StridedElement,
+            {
+                map_wide_strided::<S, Vector3<S>, _>(buffer, offset, stride, $vectors(self));
+            }
+        }
+    };
+}
+
+impl_batch_transform_wide!(Matrix3<S>, matrix3_kernel, matrix3_kernel);
+impl_batch_transform_wide!(Matrix4<S>, matrix4_point_kernel, matrix4_vector_kernel);
+impl_batch_transform_wide!(Quaternion<S>, quaternion_kernel, quaternion_kernel);
+impl_batch_transform_wide!(
+    Decomposed<Vector3<S>, Quaternion<S>>,
+    decomposed_quaternion_point_kernel,
+    decomposed_quaternion_vector_kernel
+);
+impl_batch_transform_wide!(
+    Decomposed<Vector3<S>, Basis3<S>>,
+    decomposed_basis3_point_kernel,
+    decomposed_basis3_vector_kernel
+);
+
+/// Two-dimensional transforms, which are applied to one element at a time.
+macro_rules! impl_batch_transform_2d {
+    () => {
+        fn transform_points_in_place(&self, points: &mut [Point2<S>]) {
+            map_in_place(points, |p| Transform::<Point2<S>>::transform_point(self, p));
+        }
+
+        fn transform_vectors_in_place(&self, vectors: &mut [Vector2<S>]) {
+            map_in_place(vectors, |v| {
+                Transform::<Point2<S>>::transform_vector(self, v)
+            });
+        }
+
+        fn transform_points_into(&self, input: &[Point2<S>], output: &mut [Point2<S>]) {
+            map_into(input, output, |p| {
+                Transform::<Point2<S>>::transform_point(self, p)
+            });
+        }
+
+        fn transform_vectors_into(&self, input: &[Vector2<S>], output: &mut [Vector2<S>]) {
+            map_into(input, output, |v| {
+                Transform::<Point2<S>>::transform_vector(self, v)
+            });
+        }
+
+        fn transform_points_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+        where
+            Point2<S>: StridedElement,
+        {
+            map_strided(buffer, offset, stride, |p: Point2<S>| {
+                Transform::<Point2<S>>::transform_point(self, p)
+            });
+        }
+
+        fn transform_vectors_strided(&self, buffer: &mut [u8], offset: usize, stride: usize)
+        where
+            Vector2<S>: StridedElement,
+        {
+            map_strided(buffer, offset, stride, |v: Vector2<S>| {
+                Transform::<Point2<S>>::transform_vector(self, v)
+            });
+        }
+    };
+}
+
+impl<S: BaseFloat> BatchTransform<Point2<S>> for Matrix3<S> {
+    impl_batch_transform_2d!();
+}
+
+impl<S: BaseFloat, R: Rotation2<Scalar = S>> BatchTransform<Point2<S>>
+    for Decomposed<Vector2<S>, R>
+{
+    impl_batch_transform_2d!();
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Transform homogeneous points, writing the results to `output` after
+    /// dividing by their `w` component.
+    ///
+    /// # Panics
+    ///
+    /// If the slices have different lengths.
+    pub fn transform_homogeneous_into(&self, input: &[Vector4<S>], output: &mut [Point3<S>]) {
+        map_into(input, output, |v| Point3::from_homogeneous(self * v));
+    }
+
+    /// Transform homogeneous points in place, dividing the results by their
+    /// `w` component so that it becomes `1`.
+    pub fn transform_homogeneous_in_place(&self, points: &mut [Vector4<S>]) {
+        map_in_place(points, |v| {
+            Point3::from_homogeneous(self * v).to_homogeneous()
+        });
+    }
+}
@@ -0,0 +1,338 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Camera controllers that turn user input into view matrices.
+//!
+//! All of the controllers use a right-handed, y-up coordinate system, and
+//! produce view matrices that look down the negative z axis, like
+//! `Matrix4::look_at_rh`. They only deal with the math, so input has to be
+//! translated into angles, distances or cursor positions by the caller.
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::{Deg, Rad};
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation3;
+use vector::{Vector2, Vector3};
+
+/// Compute the direction a camera with the given yaw and pitch looks in.
+///
+/// A yaw and pitch of zero look down the negative z axis. Positive yaw turns
+/// to the left, around the y axis, and positive pitch looks up.
+fn yaw_pitch_forward<S: BaseFloat>(yaw: Rad<S>, pitch: Rad<S>) -> Vector3<S> {
+    let (sin_yaw, cos_yaw) = Rad::sin_cos(yaw);
+    let (sin_pitch, cos_pitch) = Rad::sin_cos(pitch);
+    Vector3::new(-sin_yaw * cos_pitch, sin_pitch, -cos_yaw * cos_pitch)
+}
+
+/// Clamp the pitch to the range `[-limit, limit]`.
+fn clamp_pitch<S: BaseFloat>(pitch: Rad<S>, limit: Rad<S>) -> Rad<S> {
+    if pitch > limit {
+        limit
+    } else if pitch < -limit {
+        -limit
+    } else {
+        pitch
+    }
+}
+
+/// The default pitch limit, just short of looking straight up or down, where
+/// the view matrix would be undefined.
+fn default_pitch_limit<S: BaseFloat>() -> Rad<S> {
+    Deg(cast(89).unwrap()).into()
+}
+
+/// A first-person camera, controlled by a yaw and a pitch angle.
+///
+/// ```rust
+/// use cgmath::{Deg, FirstPersonCamera, Point3, Vector3};
+///
+/// let mut camera = FirstPersonCamera::new(Point3::new(0.0, 1.8, 5.0), Deg(0.0), Deg(0.0));
+///
+/// // Turn right and look up, as if the mouse moved up and to the right
+/// camera.rotate(Deg(-10.0), Deg(5.0));
+/// // Walk forwards
+/// camera.translate_local(Vector3::new(0.0, 0.0, 1.5));
+///
+/// let view = camera.view();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FirstPersonCamera<S> {
+    /// The position of the eye.
+    pub position: Point3<S>,
+    /// The rotation around the y axis. Positive values turn to the left.
+    pub yaw: Rad<S>,
+    /// The rotation around the camera's horizontal axis. Positive values look
+    /// up.
+    pub pitch: Rad<S>,
+    /// The largest absolute pitch that `FirstPersonCamera::rotate` allows.
+    pub pitch_limit: Rad<S>,
+}
+
+impl<S: BaseFloat> FirstPersonCamera<S> {
+    /// Create a new camera, with a pitch limit of 89°.
+    pub fn new<A: Into<Rad<S>>>(position: Point3<S>, yaw: A, pitch: A) -> FirstPersonCamera<S> {
+        let pitch_limit = default_pitch_limit();
+        FirstPersonCamera {
+            position,
+            yaw: yaw.into(),
+            pitch: clamp_pitch(pitch.into(), pitch_limit),
+            pitch_limit,
+        }
+    }
+
+    /// Turn the camera by the given angles, clamping the resulting pitch to the
+    /// pitch limit.
+    pub fn rotate<A: Into<Rad<S>>>(&mut self, yaw: A, pitch: A) {
+        self.yaw += yaw.into();
+        self.pitch = clamp_pitch(self.pitch + pitch.into(), self.pitch_limit);
+    }
+
+    /// The direction the camera is looking in.
+    pub fn forward(&self) -> Vector3<S> {
+        yaw_pitch_forward(self.yaw, self.pitch)
+    }
+
+    /// The direction to the right of the camera, parallel to the ground.
+    pub fn right(&self) -> Vector3<S> {
+        let (sin_yaw, cos_yaw) = Rad::sin_cos(self.yaw);
+        Vector3::new(cos_yaw, S::zero(), -sin_yaw)
+    }
+
+    /// Move the camera relative to the direction it is facing on the ground.
+    ///
+    /// The `x` component moves to the right, the `y` component moves up, and
+    /// the `z` component moves forwards. The pitch is ignored, so looking up
+    /// or down does not change the speed of walking.
+    pub fn translate_local(&mut self, offset: Vector3<S>) {
+        let (sin_yaw, cos_yaw) = Rad::sin_cos(self.yaw);
+        let right = Vector3::new(cos_yaw, S::zero(), -sin_yaw);
+        let forward = Vector3::new(-sin_yaw, S::zero(), -cos_yaw);
+        self.position += right * offset.x + Vector3::unit_y() * offset.y + forward * offset.z;
+    }
+
+    /// The orientation of the camera, rotating the negative z axis onto the
+    /// forward direction.
+    pub fn orientation(&self) -> Quaternion<S> {
+        Quaternion::from_angle_y(self.yaw) * Quaternion::from_angle_x(self.pitch)
+    }
+
+    /// Create the view matrix of the camera.
+    pub fn view(&self) -> Matrix4<S> {
+        Matrix4::look_to_rh(self.position, self.forward(), Vector3::unit_y())
+    }
+}
+
+/// A turntable camera, orbiting around a target point at a distance.
+///
+/// ```rust
+/// use cgmath::{Deg, OrbitCamera, Point3};
+///
+/// let mut camera = OrbitCamera::new(Point3::new(0.0, 0.0, 0.0), 10.0, Deg(45.0), Deg(30.0));
+///
+/// // Spin the model around, and zoom in
+/// camera.rotate(Deg(15.0), Deg(0.0));
+/// camera.zoom(0.5);
+///
+/// let view = camera.view();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrbitCamera<S> {
+    /// The point that the camera orbits around and looks at.
+    pub target: Point3<S>,
+    /// The distance from the target to the eye.
+    pub distance: S,
+    /// The rotation around the y axis. Positive values orbit to the right,
+    /// turning the camera to the left.
+    pub yaw: Rad<S>,
+    /// The elevation of the camera. Positive values look down at the target
+    /// from above.
+    pub pitch: Rad<S>,
+    /// The largest absolute pitch that `OrbitCamera::rotate` allows.
+    pub pitch_limit: Rad<S>,
+    /// The smallest distance that `OrbitCamera::zoom` allows.
+    pub min_distance: S,
+    /// The largest distance that `OrbitCamera::zoom` allows.
+    pub max_distance: S,
+}
+
+impl<S: BaseFloat> OrbitCamera<S> {
+    /// Create a new camera, with a pitch limit of 89° and no limits on the
+    /// distance other than it being positive.
+    pub fn new<A: Into<Rad<S>>>(
+        target: Point3<S>,
+        distance: S,
+        yaw: A,
+        pitch: A,
+    ) -> OrbitCamera<S> {
+        let pitch_limit = default_pitch_limit();
+        OrbitCamera {
+            target,
+            distance,
+            yaw: yaw.into(),
+            pitch: clamp_pitch(pitch.into(), pitch_limit),
+            pitch_limit,
+            min_distance: S::epsilon(),
+            max_distance: S::infinity(),
+        }
+    }
+
+    /// Orbit the camera by the given angles, clamping the resulting pitch to
+    /// the pitch limit.
+    pub fn rotate<A: Into<Rad<S>>>(&mut self, yaw: A, pitch: A) {
+        self.yaw += yaw.into();
+        self.pitch = clamp_pitch(self.pitch + pitch.into(), self.pitch_limit);
+    }
+
+    /// Multiply the distance to the target by `factor`, clamping it to the
+    /// distance limits. Factors below one zoom in, and factors above one zoom
+    /// out.
+    pub fn zoom(&mut self, factor: S) {
+        self.distance = (self.distance * factor)
+            .max(self.min_distance)
+            .min(self.max_distance);
+    }
+
+    /// Move the target in the plane of the screen, by `offset.x` to the right
+    /// and by `offset.y` up.
+    pub fn pan(&mut self, offset: Vector2<S>) {
+        let forward = self.forward();
+        let right = forward.cross(Vector3::unit_y()).normalize();
+        let up = right.cross(forward);
+        self.target += right * offset.x + up * offset.y;
+    }
+
+    /// The direction the camera is looking in.
+    pub fn forward(&self) -> Vector3<S> {
+        yaw_pitch_forward(self.yaw, -self.pitch)
+    }
+
+    /// The position of the eye.
+    pub fn eye(&self) -> Point3<S> {
+        self.target - self.forward() * self.distance
+    }
+
+    /// Create the view matrix of the camera.
+    pub fn view(&self) -> Matrix4<S> {
+        Matrix4::look_to_rh(self.eye(), self.forward(), Vector3::unit_y())
+    }
+}
+
+/// Ken Shoemake's [arcball] controller, rotating a camera around a target as
+/// if the cursor was dragging a sphere enclosing the scene.
+///
+/// Cursor positions are given in normalized coordinates, with `x` pointing
+/// right and `y` pointing up. The arcball sphere has a radius of `1`, so
+/// dividing pixel coordinates relative to the center of the viewport by half
+/// of its smaller dimension yields a sphere that just fits in the viewport.
+///
+/// Rotations produced by the arcball are free of hysteresis: dragging the
+/// cursor back to where it started restores the original orientation.
+///
+/// ```rust
+/// use cgmath::{Arcball, Point3, Vector2};
+///
+/// let mut arcball = Arcball::new(Point3::new(0.0, 0.0, 0.0), 5.0);
+///
+/// arcball.begin_drag(Vector2::new(0.0, 0.0));
+/// arcball.drag(Vector2::new(0.2, 0.1));
+/// arcball.end_drag();
+///
+/// let view = arcball.view();
+/// ```
+///
+/// [arcball]: https://www.talisman.org/~erlkonig/misc/shoemake92-arcball.pdf
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Arcball<S> {
+    /// The point that the camera rotates around and looks at.
+    pub target: Point3<S>,
+    /// The distance from the target to the eye.
+    pub distance: S,
+    /// The rotation of the scene around the target, relative to a camera
+    /// looking down the negative z axis.
+    pub rotation: Quaternion<S>,
+    drag: Option<(Vector2<S>, Quaternion<S>)>,
+}
+
+impl<S: BaseFloat> Arcball<S> {
+    /// Create a new arcball, with no rotation.
+    pub fn new(target: Point3<S>, distance: S) -> Arcball<S> {
+        Arcball {
+            target,
+            distance,
+            rotation: Quaternion::one(),
+            drag: None,
+        }
+    }
+
+    /// Project a cursor position onto the arcball sphere. Positions outside of
+    /// the sphere are moved to the closest point on its silhouette.
+    pub fn project_cursor(cursor: Vector2<S>) -> Vector3<S> {
+        let r2 = cursor.magnitude2();
+        if r2 <= S::one() {
+            cursor.extend((S::one() - r2).sqrt())
+        } else {
+            cursor.normalize().extend(S::zero())
+        }
+    }
+
+    /// Compute the rotation of the sphere when dragged from one cursor position
+    /// to another. This is twice the rotation between the points that they are
+    /// projected onto.
+    pub fn drag_rotation(from: Vector2<S>, to: Vector2<S>) -> Quaternion<S> {
+        let from = Arcball::project_cursor(from);
+        let to = Arcball::project_cursor(to);
+        Quaternion::from_sv(from.dot(to), from.cross(to))
+    }
+
+    /// Start dragging the sphere at the cursor position.
+    pub fn begin_drag(&mut self, cursor: Vector2<S>) {
+        self.drag = Some((cursor, self.rotation));
+    }
+
+    /// Move the cursor while dragging, updating the rotation. This has no
+    /// effect if no drag was started.
+    pub fn drag(&mut self, cursor: Vector2<S>) {
+        if let Some((start, start_rotation)) = self.drag {
+            let rotation = Arcball::drag_rotation(start, cursor);
+            self.rotation = (rotation * start_rotation).normalize();
+        }
+    }
+
+    /// Stop dragging the sphere, keeping the current rotation.
+    pub fn end_drag(&mut self) {
+        self.drag = None;
+    }
+
+    /// Whether the sphere is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Create the view matrix of the camera.
+    pub fn view(&self) -> Matrix4<S> {
+        Matrix4::from_translation(Vector3::new(S::zero(), S::zero(), -self.distance))
+            * Matrix4::from(self.rotation)
+            * Matrix4::from_translation(Point3::origin() - self.target)
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for rendering to and sampling from cube maps.
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use projection::{DepthRange, PerspectiveFov};
+use vector::{Vector2, Vector3};
+
+/// A face of a cube map, in the standard order used by both OpenGL and
+/// Direct3D.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+/// The graphics API whose conventions are used to render the faces of a cube
+/// map.
+///
+/// Both APIs address the texels of a cube map in the same way, but they
+/// disagree on the vertical orientation of render targets and on the
+/// handedness of clip space, so the faces have to be rendered differently.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CubeMapConvention {
+    /// Right-handed view matrices, with clip space depth in `[-1, 1]`, and
+    /// the first row of a render target at the bottom of the viewport.
+    OpenGl,
+    /// Left-handed view matrices, with clip space depth in `[0, 1]`, and the
+    /// first row of a render target at the top of the viewport.
+    Direct3D,
+}
+
+impl CubeFace {
+    /// All six faces, in the standard order.
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ,
+    ];
+
+    /// The index of the face, ie. its layer in a cube map texture.
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Get the face at `index`, or `None` if `index` is not less than six.
+    #[inline]
+    pub fn from_index(index: usize) -> Option<CubeFace> {
+        CubeFace::ALL.get(index).cloned()
+    }
+
+    /// The axis pointing out of the center of the face.
+    pub fn normal<S: BaseFloat>(self) -> Vector3<S> {
+        match self {
+            CubeFace::PositiveX => Vector3::unit_x(),
+            CubeFace::NegativeX => -Vector3::unit_x(),
+            CubeFace::PositiveY => Vector3::unit_y(),
+            CubeFace::NegativeY => -Vector3::unit_y(),
+            CubeFace::PositiveZ => Vector3::unit_z(),
+            CubeFace::NegativeZ => -Vector3::unit_z(),
+        }
+    }
+
+    /// The directions in which the `u` and `v` texture coordinates of the face
+    /// increase.
+    fn tangents<S: BaseFloat>(self) -> (Vector3<S>, Vector3<S>) {
+        let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+        match self {
+            CubeFace::PositiveX => (-z, -y),
+            CubeFace::NegativeX => (z, -y),
+            CubeFace::PositiveY => (x, z),
+            CubeFace::NegativeY => (x, -z),
+            CubeFace::PositiveZ => (x, -y),
+            CubeFace::NegativeZ => (-x, -y),
+        }
+    }
+
+    /// The up vector of the camera used to render the face.
+    pub fn up<S: BaseFloat>(self, convention: CubeMapConvention) -> Vector3<S> {
+        let (_, v) = self.tangents();
+        match convention {
+            CubeMapConvention::OpenGl => v,
+            CubeMapConvention::Direct3D => -v,
+        }
+    }
+
+    /// Create the view matrix used to render the face from `eye`.
+    pub fn view<S: BaseFloat>(self, eye: Point3<S>, convention: CubeMapConvention) -> Matrix4<S> {
+        match convention {
+            CubeMapConvention::OpenGl => {
+                Matrix4::look_to_rh(eye, self.normal(), self.up(convention))
+            }
+            CubeMapConvention::Direct3D => {
+                Matrix4::look_to_lh(eye, self.normal(), self.up(convention))
+            }
+        }
+    }
+
+    /// Find the face that a direction points at, along with the texture
+    /// coordinates of the point that it hits on that face, in the range
+    /// `[0, 1]`.
+    ///
+    /// The direction does not need to be normalized. When the direction is
+    /// exactly between two faces, the `x` faces are preferred over the `y`
+    /// faces, which are preferred over the `z` faces.
+    ///
+    /// ```rust
+    /// use cgmath::{CubeFace, Vector2, Vector3};
+    ///
+    /// let (face, uv) = CubeFace::from_direction(Vector3::new(0.0, 0.0, -2.0));
+    /// assert_eq!(face, CubeFace::NegativeZ);
+    /// assert_eq!(uv, Vector2::new(0.5, 0.5));
+    /// ```
+    pub fn from_direction<S: BaseFloat>(dir: Vector3<S>) -> (CubeFace, Vector2<S>) {
+        let (ax, ay, az) = (dir.x.abs(), dir.y.abs(), dir.z.abs());
+        let face = if ax >= ay && ax >= az {
+            if dir.x >= S::zero() {
+                CubeFace::PositiveX
+            } else {
+                CubeFace::NegativeX
+            }
+        } else if ay >= az {
+            if dir.y >= S::zero() {
+                CubeFace::PositiveY
+            } else {
+                CubeFace::NegativeY
+            }
+        } else if dir.z >= S::zero() {
+            CubeFace::PositiveZ
+        } else {
+            CubeFace::NegativeZ
+        };
+
+        let (u, v) = face.tangents();
+        let major = dir.dot(face.normal());
+        let half: S = cast(0.5).unwrap();
+        let uv = Vector2::new(dir.dot(u), dir.dot(v)) / major;
+        (face, (uv + Vector2::new(S::one(), S::one())) * half)
+    }
+
+    /// Get the normalized direction pointing at the given texture coordinates
+    /// of the face. This is the inverse of `CubeFace::from_direction`.
+    pub fn direction<S: BaseFloat>(self, uv: Vector2<S>) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+        let (u, v) = self.tangents();
+        let s = uv.x * two - S::one();
+        let t = uv.y * two - S::one();
+        (self.normal() + u * s + v * t).normalize()
+    }
+}
+
+impl CubeMapConvention {
+    /// Create the six view matrices used to render a cube map from `eye`, in
+    /// the standard face order.
+    ///
+    /// ```rust
+    /// use cgmath::{CubeFace, CubeMapConvention, Point3};
+    ///
+    /// let eye = Point3::new(1.0, 2.0, 3.0);
+    /// let views = CubeMapConvention::OpenGl.views(eye);
+    /// let projection = CubeMapConvention::OpenGl.projection(0.1, 100.0);
+    ///
+    /// for face in CubeFace::ALL.iter() {
+    ///     let view_projection = projection * views[face.index()];
+    /// }
+    /// ```
+    pub fn views<S: BaseFloat>(self, eye: Point3<S>) -> [Matrix4<S>; 6] {
+        [
+            CubeFace::PositiveX.view(eye, self),
+            CubeFace::NegativeX.view(eye, self),
+            CubeFace::PositiveY.view(eye, self),
+            CubeFace::NegativeY.view(eye, self),
+            CubeFace::PositiveZ.view(eye, self),
+            CubeFace::NegativeZ.view(eye, self),
+        ]
+    }
+
+    /// Create the projection matrix used to render each face of a cube map,
+    /// with a 90° field of view and a square aspect ratio.
+    pub fn projection<S: BaseFloat>(self, near: S, far: S) -> Matrix4<S> {
+        let persp: Matrix4<S> = PerspectiveFov {
+            fovy: Rad::turn_div_4(),
+            aspect: S::one(),
+            near,
+            far,
+        }
+        .into();
+
+        match self {
+            CubeMapConvention::OpenGl => persp,
+            CubeMapConvention::Direct3D => {
+                // Look down the positive z axis instead of the negative one
+                let flip = Matrix4::from_nonuniform_scale(S::one(), S::one(), -S::one());
+                DepthRange::ZeroToOne.remap(persp) * flip
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `std140` and `std430` memory layouts of GLSL uniform and storage
+//! buffers.
+//!
+//! These layouts pad some types, so the `#[repr(C)]` layout of the cgmath
+//! types does not always match them. For example, the columns of a `mat3` are
+//! each padded to 16 bytes, and in `std140` the elements of an array are
+//! aligned to 16 bytes.
+//!
+//! The `Std140` and `Std430` traits give the alignment and size of a type in
+//! each layout, and write its padded byte representation. The `Std140Writer`
+//! and `Std430Writer` place the members of a block one after the other, with
+//! the padding between them:
+//!
+//! ```rust
+//! use cgmath::{Matrix3, Vector3};
+//! use cgmath::layout::Std140Writer;
+//!
+//! // layout(std140) uniform Light {
+//! //     vec3 position;
+//! //     float intensity;
+//! //     mat3 orientation;
+//! // };
+//! let mut bytes = [0; 64];
+//! let mut writer = Std140Writer::new(&mut bytes);
+//! assert_eq!(writer.write(&Vector3::new(1.0f32, 2.0, 3.0)), 0);
+//! assert_eq!(writer.write(&0.5f32), 12);
+//! assert_eq!(writer.write(&Matrix3::<f32>::from_scale(2.0)), 16);
+//! assert_eq!(writer.finish(), 64);
+//! ```
+//!
+//! Values are written in native byte order. Nested structs are not handled
+//! by the writers, but can be written by calling `align_to` with the
+//! alignment of the struct before and after its members.
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// Scalars that can be stored in GPU buffers: `float`, `double`, `int` and
+/// `uint`.
+pub trait LayoutScalar: Copy {
+    /// The size of the scalar in bytes, which is also its alignment.
+    const SIZE: usize;
+
+    /// Write the scalar to the start of `bytes`.
+    fn write_bytes(&self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_layout_scalar {
+    ($S:ident) => {
+        impl LayoutScalar for $S {
+            const SIZE: usize = ::core::mem::size_of::<$S>();
+
+            #[inline]
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                bytes[..<$S as LayoutScalar>::SIZE].copy_from_slice(&self.to_ne_bytes());
+            }
+        }
+
+        impl Std140 for $S {
+            const ALIGN: usize = <$S as LayoutScalar>::SIZE;
+            const SIZE: usize = <$S as LayoutScalar>::SIZE;
+
+            #[inline]
+            fn write_std140(&self, bytes: &mut [u8]) {
+                self.write_bytes(bytes);
+            }
+        }
+
+        impl Std430 for $S {
+            const ALIGN: usize = <$S as LayoutScalar>::SIZE;
+            const SIZE: usize = <$S as LayoutScalar>::SIZE;
+
+            #[inline]
+            fn write_std430(&self, bytes: &mut [u8]) {
+                self.write_bytes(bytes);
+            }
+        }
+    };
+}
+
+impl_layout_scalar!(f32);
+impl_layout_scalar!(f64);
+impl_layout_scalar!(i32);
+impl_layout_scalar!(u32);
+
+/// Types that can be stored in a buffer with the `std140` layout, which is
+/// used by uniform blocks.
+pub trait Std140 {
+    /// The base alignment of the type in bytes.
+    const ALIGN: usize;
+    /// The size of the type in bytes, including any padding inside it.
+    const SIZE: usize;
+
+    /// Write the `std140` representation of the value to the start of
+    /// `bytes`, zeroing the padding inside it.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is shorter than `SIZE`.
+    fn write_std140(&self, bytes: &mut [u8]);
+}
+
+/// Types that can be stored in a buffer with the `std430` layout, which is
+/// used by shader storage blocks.
+pub trait Std430 {
+    /// The base alignment of the type in bytes.
+    const ALIGN: usize;
+    /// The size of the type in bytes, including any padding inside it.
+    const SIZE: usize;
+
+    /// Write the `std430` representation of the value to the start of
+    /// `bytes`, zeroing the padding inside it.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is shorter than `SIZE`.
+    fn write_std430(&self, bytes: &mut [u8]);
+}
+
+/// Round `offset` up to a multiple of `align`.
+#[inline]
+const fn round_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// The base alignment of a vector with `n` components, where three-component
+/// vectors are aligned like four-component ones.
+#[inline]
+const fn vector_align(n: usize, scalar_size: usize) -> usize {
+    if n == 3 {
+        4 * scalar_size
+    } else {
+        n * scalar_size
+    }
+}
+
+// Vectors have the same layout in `std140` and `std430`.
+macro_rules! impl_layout {
+    ($Std:ident, $write:ident, $Type:ident<$S:ident> { $($field:ident),+ }, $n:expr) => {
+        impl<$S: LayoutScalar> $Std for $Type<$S> {
+            const ALIGN: usize = vector_align($n, <$S as LayoutScalar>::SIZE);
+            const SIZE: usize = $n * <$S as LayoutScalar>::SIZE;
+
+            #[inline]
+            fn $write(&self, bytes: &mut [u8]) {
+                let size = <$S as LayoutScalar>::SIZE;
+                let mut chunks = bytes[..$n * size].chunks_exact_mut(size);
+                $(self.$field.write_bytes(chunks.next().unwrap());)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector_layouts {
+    ($Std:ident, $write:ident) => {
+        impl_layout!($Std, $write, Vector1<S> { x }, 1);
+        impl_layout!($Std, $write, Vector2<S> { x, y }, 2);
+        impl_layout!($Std, $write, Vector3<S> { x, y, z }, 3);
+        impl_layout!($Std, $write, Vector4<S> { x, y, z, w }, 4);
+        impl_layout!($Std, $write, Point1<S> { x }, 1);
+        impl_layout!($Std, $write, Point2<S> { x, y }, 2);
+        impl_layout!($Std, $write, Point3<S> { x, y, z }, 3);
+
+        /// Quaternions are stored as a `vec4`, with the vector part in `xyz`
+        /// and the scalar part in `w`.
+        impl<S: LayoutScalar> $Std for Quaternion<S> {
+            const ALIGN: usize = vector_align(4, <S as LayoutScalar>::SIZE);
+            const SIZE: usize = 4 * <S as LayoutScalar>::SIZE;
+
+            #[inline]
+            fn $write(&self, bytes: &mut [u8]) {
+                self.v.$write(bytes);
+                self.s.write_bytes(&mut bytes[3 * <S as LayoutScalar>::SIZE..]);
+            }
+        }
+    };
+}
+
+impl_vector_layouts!(Std140, write_std140);
+impl_vector_layouts!(Std430, write_std430);
+
+/// Write `len` elements spaced `stride` bytes apart, zeroing the padding after
+/// each of them.
+#[inline]
+fn write_elements<F: Fn(usize, &mut [u8])>(
+    bytes: &mut [u8],
+    len: usize,
+    size: usize,
+    stride: usize,
+    write: F,
+) {
+    for (i, element) in bytes[..len * stride].chunks_exact_mut(stride).enumerate() {
+        write(i, element);
+        for byte in &mut element[size..] {
+            *byte = 0;
+        }
+    }
+}
+
+// Matrices are stored like arrays of their columns.
+macro_rules! impl_matrix_layout {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: LayoutScalar> Std140 for $MatrixN<S> {
+            const ALIGN: usize = round_up(<$VectorN<S> as Std140>::ALIGN, 16);
+            const SIZE: usize = $n * <Self as Std140>::ALIGN;
+
+            #[inline]
+            fn write_std140(&self, bytes: &mut [u8]) {
+                let size = <$VectorN<S> as Std140>::SIZE;
+                write_elements(bytes, $n, size, <Self as Std140>::ALIGN, |i, b| {
+                    self[i].write_std140(b)
+                });
+            }
+        }
+
+        impl<S: LayoutScalar> Std430 for $MatrixN<S> {
+            const ALIGN: usize = <$VectorN<S> as Std430>::ALIGN;
+            const SIZE: usize = $n * <Self as Std430>::ALIGN;
+
+            #[inline]
+            fn write_std430(&self, bytes: &mut [u8]) {
+                let size = <$VectorN<S> as Std430>::SIZE;
+                write_elements(bytes, $n, size, <Self as Std430>::ALIGN, |i, b| {
+                    self[i].write_std430(b)
+                });
+            }
+        }
+    };
+}
+
+impl_matrix_layout!(Matrix2, Vector2, 2);
+impl_matrix_layout!(Matrix3, Vector3, 3);
+impl_matrix_layout!(Matrix4, Vector4, 4);
+
+/// In `std140`, array elements are aligned to 16 bytes.
+impl<T: Std140, const N: usize> Std140 for [T; N] {
+    const ALIGN: usize = round_up(T::ALIGN, 16);
+    const SIZE: usize = N * round_up(T::SIZE, <Self as Std140>::ALIGN);
+
+    #[inline]
+    fn write_std140(&self, bytes: &mut [u8]) {
+        let stride = round_up(T::SIZE, <Self as Std140>::ALIGN);
+        write_elements(bytes, N, T::SIZE, stride, |i, b| self[i].write_std140(b));
+    }
+}
+
+impl<T: Std430, const N: usize> Std430 for [T; N] {
+    const ALIGN: usize = T::ALIGN;
+    const SIZE: usize = N * round_up(T::SIZE, T::ALIGN);
+
+    #[inline]
+    fn write_std430(&self, bytes: &mut [u8]) {
+        let stride = round_up(T::SIZE, T::ALIGN);
+        write_elements(bytes, N, T::SIZE, stride, |i, b| self[i].write_std430(b));
+    }
+}
+
+macro_rules! impl_writer {
+    ($Writer:ident, $Std:ident, $write:ident, $min_struct_align:expr, $layout:expr) => {
+        #[doc = concat!("Writes the members of a `", $layout, "` block to a byte buffer.")]
+        ///
+        /// Each member is placed at the next offset that satisfies its
+        /// alignment, and the padding before it is zeroed.
+        pub struct $Writer<'a> {
+            bytes: &'a mut [u8],
+            offset: usize,
+            align: usize,
+        }
+
+        impl<'a> $Writer<'a> {
+            /// Start writing a block at the beginning of `bytes`.
+            #[inline]
+            pub fn new(bytes: &'a mut [u8]) -> $Writer<'a> {
+                $Writer {
+                    bytes,
+                    offset: 0,
+                    align: $min_struct_align,
+                }
+            }
+
+            /// The offset of the end of the last member that was written.
+            #[inline]
+            pub fn offset(&self) -> usize {
+                self.offset
+            }
+
+            /// Advance to the next multiple of `align`, zeroing the bytes that
+            /// are skipped.
+            ///
+            /// # Panics
+            ///
+            /// If the buffer is too small.
+            pub fn align_to(&mut self, align: usize) {
+                let offset = round_up(self.offset, align);
+                for byte in &mut self.bytes[self.offset..offset] {
+                    *byte = 0;
+                }
+                self.offset = offset;
+                self.align = self.align.max(align);
+            }
+
+            /// Write the next member of the block, and return its offset.
+            ///
+            /// # Panics
+            ///
+            /// If the buffer is too small.
+            pub fn write<T: $Std>(&mut self, value: &T) -> usize {
+                self.align_to(T::ALIGN);
+                let offset = self.offset;
+                value.$write(&mut self.bytes[offset..offset + T::SIZE]);
+                self.offset += T::SIZE;
+                offset
+            }
+
+            /// Pad the block to a multiple of its alignment, and return its
+            /// size. This is the stride of the block when it is stored in an
+            /// array.
+            ///
+            /// # Panics
+            ///
+            /// If the buffer is too small.
+            pub fn finish(mut self) -> usize {
+                let align = self.align;
+                self.align_to(align);
+                self.offset
+            }
+        }
+    };
+}
+
+impl_writer!(Std140Writer, Std140, write_std140, 16, "std140");
+impl_writer!(Std430Writer, Std430, write_std430, 1, "std430");
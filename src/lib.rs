@@ -49,12 +49,27 @@
 //! ```rust
 //! use cgmath::prelude::*;
 //! ```
+//!
+//! # `no_std` support
+//!
+//! The library can be used without the standard library by disabling the
+//! default `std` feature. Floating point functions are then provided by
+//! `libm`, through `num-traits`. The `mesh` and `predicates` modules, and the
+//! other functions that allocate, need the `alloc` feature, which is enabled
+//! by `std`.
 
-#![cfg_attr(feature = "simd", feature(specialization))]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
 
 #[macro_use]
 extern crate approx;
 
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+
 #[cfg(feature = "mint")]
 pub extern crate mint;
 
@@ -66,9 +81,6 @@ extern crate rand;
 #[macro_use]
 extern crate serde;
 
-#[cfg(feature = "simd")]
-extern crate simd;
-
 // Re-exports
 
 pub use approx::*;
@@ -85,11 +97,30 @@ pub use point::{point1, point2, point3, Point1, Point2, Point3};
 pub use rotation::*;
 pub use transform::*;
 
+pub use aabb::*;
+pub use batch::*;
+pub use camera::*;
+pub use cubemap::*;
+pub use line::*;
+pub use mask::*;
+pub use obb::*;
+pub use plane::*;
 pub use projection::*;
+pub use raycast::*;
+pub use shadow::*;
+pub use sphere::*;
+pub use triangle::*;
+pub use wide::*;
 
 // Modules
 
 pub mod conv;
+pub mod layout;
+#[cfg(feature = "alloc")]
+pub mod mesh;
+pub mod polygon;
+#[cfg(feature = "alloc")]
+pub mod predicates;
 pub mod prelude;
 
 mod macros;
@@ -99,19 +130,27 @@ mod structure;
 
 mod matrix;
 mod quaternion;
-
-#[cfg(feature = "simd")]
-mod quaternion_simd;
+mod simd;
 
 mod vector;
 
-#[cfg(feature = "simd")]
-mod vector_simd;
-
 mod angle;
 mod euler;
 mod point;
 mod rotation;
 mod transform;
 
+mod aabb;
+mod batch;
+mod camera;
+mod cubemap;
+mod line;
+mod mask;
+mod obb;
+mod plane;
 mod projection;
+mod raycast;
+mod shadow;
+mod sphere;
+mod triangle;
+mod wide;
@@ -0,0 +1,372 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rays, lines and line segments in Euclidean space.
+
+use num_traits::Float;
+
+use structure::*;
+
+use point::{Point2, Point3};
+use transform::Transform;
+
+/// A half-line, starting at an origin and extending infinitely in one
+/// direction.
+///
+/// The direction does not need to be normalized, but if it is, the parameter
+/// of `Ray::at` is the distance from the origin.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray<P: EuclideanSpace> {
+    /// The point where the ray starts.
+    pub origin: P,
+    /// The direction that the ray extends in.
+    pub direction: P::Diff,
+}
+
+/// A ray in 2-dimensional space.
+pub type Ray2<S> = Ray<Point2<S>>;
+
+/// A ray in 3-dimensional space.
+pub type Ray3<S> = Ray<Point3<S>>;
+
+/// An infinite line, passing through a point in a direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Line<P: EuclideanSpace> {
+    /// A point that the line passes through.
+    pub point: P,
+    /// The direction of the line.
+    pub direction: P::Diff,
+}
+
+/// A line segment between two end points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment<P> {
+    /// The point where the segment starts.
+    pub start: P,
+    /// The point where the segment ends.
+    pub end: P,
+}
+
+impl<P: EuclideanSpace> Ray<P> {
+    /// Construct a new ray.
+    #[inline]
+    pub fn new(origin: P, direction: P::Diff) -> Ray<P> {
+        Ray { origin, direction }
+    }
+
+    /// Get the point at parameter `t` along the ray, ie. `origin + direction * t`.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.origin + self.direction * t
+    }
+
+    /// Apply a transformation to the origin and direction of the ray.
+    ///
+    /// The parameters of points along the ray are preserved, so the direction
+    /// is not normalized after a scaling transformation.
+    #[inline]
+    pub fn transform<T: Transform<P>>(&self, transform: &T) -> Ray<P> {
+        Ray::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
+
+impl<P: EuclideanSpace> Ray<P>
+where
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    /// Get a copy of the ray with a normalized direction.
+    #[inline]
+    pub fn normalize(&self) -> Ray<P> {
+        Ray::new(self.origin, self.direction.normalize())
+    }
+
+    /// The parameter of the point on the ray closest to `point`.
+    #[inline]
+    pub fn closest_parameter(&self, point: P) -> P::Scalar {
+        let length2 = self.direction.magnitude2();
+        if length2 == P::Scalar::zero() {
+            return P::Scalar::zero();
+        }
+        ((point - self.origin).dot(self.direction) / length2).max(P::Scalar::zero())
+    }
+
+    /// The point on the ray closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: P) -> P {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// The squared distance from the ray to `point`.
+    #[inline]
+    pub fn distance2(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).magnitude2()
+    }
+
+    /// The parameters of the closest pair of points on two rays, as
+    /// `(t_self, t_other)`. If the rays are parallel, one of the closest pairs
+    /// is chosen.
+    pub fn closest_parameters(&self, other: &Ray<P>) -> (P::Scalar, P::Scalar) {
+        closest_parameters(
+            self.origin,
+            self.direction,
+            other.origin,
+            other.direction,
+            P::Scalar::infinity(),
+        )
+    }
+
+    /// The closest pair of points on two rays, as `(on_self, on_other)`.
+    #[inline]
+    pub fn closest_points(&self, other: &Ray<P>) -> (P, P) {
+        let (s, t) = self.closest_parameters(other);
+        (self.at(s), other.at(t))
+    }
+
+    /// The squared distance between two rays.
+    #[inline]
+    pub fn distance2_to_ray(&self, other: &Ray<P>) -> P::Scalar {
+        let (p, q) = self.closest_points(other);
+        (q - p).magnitude2()
+    }
+}
+
+impl<P: EuclideanSpace> Line<P> {
+    /// Construct a new line.
+    #[inline]
+    pub fn new(point: P, direction: P::Diff) -> Line<P> {
+        Line { point, direction }
+    }
+
+    /// Construct the line passing through two points, with a direction
+    /// pointing from `a` to `b`.
+    #[inline]
+    pub fn from_points(a: P, b: P) -> Line<P> {
+        Line::new(a, b - a)
+    }
+
+    /// Get the point at parameter `t` along the line, ie. `point + direction * t`.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.point + self.direction * t
+    }
+
+    /// Apply a transformation to the point and direction of the line.
+    #[inline]
+    pub fn transform<T: Transform<P>>(&self, transform: &T) -> Line<P> {
+        Line::new(
+            transform.transform_point(self.point),
+            transform.transform_vector(self.direction),
+        )
+    }
+}
+
+impl<P: EuclideanSpace> From<Ray<P>> for Line<P> {
+    #[inline]
+    fn from(ray: Ray<P>) -> Line<P> {
+        Line::new(ray.origin, ray.direction)
+    }
+}
+
+impl<P: EuclideanSpace> Segment<P> {
+    /// Construct a new line segment.
+    #[inline]
+    pub fn new(start: P, end: P) -> Segment<P> {
+        Segment { start, end }
+    }
+
+    /// The vector from the start to the end of the segment.
+    #[inline]
+    pub fn direction(&self) -> P::Diff {
+        self.end - self.start
+    }
+
+    /// Get the point at parameter `t` along the segment, where `0` is the
+    /// start and `1` is the end.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.start + self.direction() * t
+    }
+
+    /// The point halfway between the ends of the segment.
+    #[inline]
+    pub fn midpoint(&self) -> P {
+        self.start.midpoint(self.end)
+    }
+
+    /// Swap the start and end of the segment.
+    #[inline]
+    pub fn reverse(&self) -> Segment<P> {
+        Segment::new(self.end, self.start)
+    }
+
+    /// Apply a transformation to both end points of the segment.
+    #[inline]
+    pub fn transform<T: Transform<P>>(&self, transform: &T) -> Segment<P> {
+        Segment::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+        )
+    }
+
+    /// The ray starting at the start of the segment and passing through its
+    /// end.
+    #[inline]
+    pub fn to_ray(&self) -> Ray<P> {
+        Ray::new(self.start, self.direction())
+    }
+
+    /// The line passing through both ends of the segment.
+    #[inline]
+    pub fn to_line(&self) -> Line<P> {
+        Line::from_points(self.start, self.end)
+    }
+}
+
+impl<P: EuclideanSpace> Segment<P>
+where
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    /// The squared length of the segment.
+    #[inline]
+    pub fn length2(&self) -> P::Scalar {
+        self.direction().magnitude2()
+    }
+
+    /// The length of the segment.
+    #[inline]
+    pub fn length(&self) -> P::Scalar {
+        self.direction().magnitude()
+    }
+
+    /// The parameter of the point on the segment closest to `point`.
+    #[inline]
+    pub fn closest_parameter(&self, point: P) -> P::Scalar {
+        let length2 = self.length2();
+        if length2 == P::Scalar::zero() {
+            return P::Scalar::zero();
+        }
+        let t = (point - self.start).dot(self.direction()) / length2;
+        t.max(P::Scalar::zero()).min(P::Scalar::one())
+    }
+
+    /// The point on the segment closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: P) -> P {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// The squared distance from the segment to `point`.
+    #[inline]
+    pub fn distance2(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).magnitude2()
+    }
+
+    /// The parameters of the closest pair of points on two segments, as
+    /// `(t_self, t_other)`. If the segments are parallel, one of the closest
+    /// pairs is chosen.
+    pub fn closest_parameters(&self, other: &Segment<P>) -> (P::Scalar, P::Scalar) {
+        closest_parameters(
+            self.start,
+            self.direction(),
+            other.start,
+            other.direction(),
+            P::Scalar::one(),
+        )
+    }
+
+    /// The closest pair of points on two segments, as `(on_self, on_other)`.
+    #[inline]
+    pub fn closest_points(&self, other: &Segment<P>) -> (P, P) {
+        let (s, t) = self.closest_parameters(other);
+        (self.at(s), other.at(t))
+    }
+
+    /// The squared distance between two segments.
+    #[inline]
+    pub fn distance2_to_segment(&self, other: &Segment<P>) -> P::Scalar {
+        let (p, q) = self.closest_points(other);
+        (q - p).magnitude2()
+    }
+}
+
+/// Find the parameters of the closest points on `p1 + d1 * s` and
+/// `p2 + d2 * t`, with both parameters clamped to `[0, max]`.
+///
+/// This follows `ClosestPtSegmentSegment` from Real-Time Collision Detection
+/// by Christer Ericson.
+fn closest_parameters<P>(
+    p1: P,
+    d1: P::Diff,
+    p2: P,
+    d2: P::Diff,
+    max: P::Scalar,
+) -> (P::Scalar, P::Scalar)
+where
+    P: EuclideanSpace,
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    let zero = P::Scalar::zero();
+    let clamp = |x: P::Scalar| x.max(zero).min(max);
+
+    let r = p1 - p2;
+    let a = d1.magnitude2();
+    let e = d2.magnitude2();
+    let f = d2.dot(r);
+
+    if a == zero && e == zero {
+        return (zero, zero);
+    }
+    if a == zero {
+        return (zero, clamp(f / e));
+    }
+
+    let c = d1.dot(r);
+    if e == zero {
+        return (clamp(-c / a), zero);
+    }
+
+    let b = d1.dot(d2);
+    let denominator = a * e - b * b;
+
+    // Find the closest point on the first line to the second line, unless
+    // the lines are parallel
+    let mut s = if denominator != zero {
+        clamp((b * f - c * e) / denominator)
+    } else {
+        zero
+    };
+
+    // Find the closest point on the second line to that point, and if it
+    // needs clamping, find the closest point on the first line to that
+    let mut t = (b * s + f) / e;
+    if t < zero {
+        t = zero;
+        s = clamp(-c / a);
+    } else if t > max {
+        t = max;
+        s = clamp((b * max - c) / a);
+    }
+
+    (s, t)
+}
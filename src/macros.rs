@@ -17,16 +17,6 @@
 
 #![macro_use]
 
-#[cfg(feature = "simd")]
-macro_rules! default_fn {
-    { $($tt:tt)* } => { default fn $( $tt )* };
-}
-
-#[cfg(not(feature = "simd"))]
-macro_rules! default_fn {
-    { $($tt:tt)* } => { fn $( $tt )* };
-}
-
 /// Generates a binary operator implementation for the permutations of by-ref and by-val
 macro_rules! impl_operator {
     // When it is an unary operator
@@ -36,17 +26,17 @@ macro_rules! impl_operator {
         impl<$S: $Constraint> $Op for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!($op(self) -> $Output {
+            fn $op(self) -> $Output {
                 let $x = self; $body
-            });
+            }
         }
 
         impl<'a, $S: $Constraint> $Op for &'a $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!($op(self) -> $Output {
+            fn $op(self) -> $Output {
                 let $x = self; $body
-            });
+            }
         }
     };
     // When the right operand is a scalar
@@ -56,17 +46,17 @@ macro_rules! impl_operator {
         impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!($op(self, other: $Rhs) -> $Output {
+            fn $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
 
         impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!($op(self, other: $Rhs) -> $Output {
+            fn $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
     };
     // When the right operand is a compound type
@@ -76,33 +66,33 @@ macro_rules! impl_operator {
         impl<$S: $Constraint> $Op<$Rhs> for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: $Rhs) -> $Output {
+            fn $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
 
         impl<'a, $S: $Constraint> $Op<&'a $Rhs> for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: &'a $Rhs) -> $Output {
+            fn $op(self, other: &'a $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
 
         impl<'a, $S: $Constraint> $Op<$Rhs> for &'a $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: $Rhs) -> $Output {
+            fn $op(self, other: $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
 
         impl<'a, 'b, $S: $Constraint> $Op<&'a $Rhs> for &'b $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: &'a $Rhs) -> $Output {
+            fn $op(self, other: &'a $Rhs) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
     };
     // When the left operand is a scalar
@@ -112,17 +102,17 @@ macro_rules! impl_operator {
         impl $Op<$Rhs<$S>> for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: $Rhs<$S>) -> $Output {
+            fn $op(self, other: $Rhs<$S>) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
 
         impl<'a> $Op<&'a $Rhs<$S>> for $Lhs {
             type Output = $Output;
             #[inline]
-            default_fn!( $op(self, other: &'a $Rhs<$S>) -> $Output {
+            fn $op(self, other: &'a $Rhs<$S>) -> $Output {
                 let ($lhs, $rhs) = (self, other); $body
-            });
+            }
         }
     };
 }
@@ -133,7 +123,7 @@ macro_rules! impl_assignment_operator {
     }) => {
         impl<$S: $Constraint + $Op<$S>> $Op<$Rhs> for $Lhs {
             #[inline]
-            default_fn!( $op(&mut $lhs, $rhs: $Rhs) $body );
+            fn $op(&mut $lhs, $rhs: $Rhs) $body
         }
     };
 }
@@ -261,101 +251,6 @@ macro_rules! impl_index_operators {
     };
 }
 
-/// Generates a binary operator implementation for the permutations of by-ref and by-val, for simd
-#[cfg(feature = "simd")]
-macro_rules! impl_operator_simd {
-    // When it is an unary operator
-    ([$Simd:ident]; $Op:ident for $Lhs:ty {
-        fn $op:ident($x:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op for $Lhs {
-            #[inline]
-            fn $op(self) -> $Output {
-                let $x: $Simd = self.into();
-                $body
-            }
-        }
-    };
-    // When the right operand is a scalar
-    (@rs [$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), $Simd::splat(other));
-                $body
-            }
-        }
-
-        impl<'a> $Op<$Rhs> for &'a $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), $Simd::splat(other));
-                $body
-            }
-        }
-    };
-
-    // When the right operand is a compound type
-    ([$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ty {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), other.into());
-                $body
-            }
-        }
-
-        impl<'a> $Op<&'a $Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = (self.into(), (*other).into());
-                $body
-            }
-        }
-
-        impl<'a> $Op<$Rhs> for &'a $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), other.into());
-                $body
-            }
-        }
-
-        impl<'a, 'b> $Op<&'a $Rhs> for &'b $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ((*self).into(), (*other).into());
-                $body
-            }
-        }
-    };
-
-    // When the left operand is a scalar
-    (@ls [$Simd:ident]; $Op:ident<$Rhs:ty> for $Lhs:ident {
-        fn $op:ident($lhs:ident, $rhs:ident) -> $Output:ty { $body:expr }
-    }) => {
-        impl $Op<$Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ($Simd::splat(self), other.into());
-                $body
-            }
-        }
-
-        impl<'a> $Op<&'a $Rhs> for $Lhs {
-            #[inline]
-            fn $op(self, other: &'a $Rhs) -> $Output {
-                let ($lhs, $rhs): ($Simd, $Simd) = ($Simd::splat(self), (*other).into());
-                $body
-            }
-        }
-    };
-}
-
 /// Generate `mint` types conversion implementations
 #[cfg(feature = "mint")]
 macro_rules! impl_mint_conversions {
@@ -376,4 +271,14 @@ macro_rules! impl_mint_conversions {
     }
 }
 
+/// Generate `bytemuck` trait implementations for a `#[repr(C)]` type whose
+/// fields all have the type of its parameter, so that it has no padding
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_bytemuck_cast {
+    ($Type:ident) => {
+        unsafe impl<S: ::bytemuck::Pod> ::bytemuck::Pod for $Type<S> {}
+        unsafe impl<S: ::bytemuck::Zeroable> ::bytemuck::Zeroable for $Type<S> {}
+    };
+}
+
 include!(concat!(env!("OUT_DIR"), "/swizzle_operator_macro.rs"));
@@ -0,0 +1,237 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::ops::*;
+
+use structure::*;
+
+use point::{Point2, Point3};
+use vector::{Vector2, Vector3, Vector4};
+
+/// A 2-dimensional boolean vector, like GLSL's `bvec2`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BVec2 {
+    /// The x component of the mask.
+    pub x: bool,
+    /// The y component of the mask.
+    pub y: bool,
+}
+
+/// A 3-dimensional boolean vector, like GLSL's `bvec3`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BVec3 {
+    /// The x component of the mask.
+    pub x: bool,
+    /// The y component of the mask.
+    pub y: bool,
+    /// The z component of the mask.
+    pub z: bool,
+}
+
+/// A 4-dimensional boolean vector, like GLSL's `bvec4`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BVec4 {
+    /// The x component of the mask.
+    pub x: bool,
+    /// The y component of the mask.
+    pub y: bool,
+    /// The z component of the mask.
+    pub z: bool,
+    /// The w component of the mask.
+    pub w: bool,
+}
+
+macro_rules! impl_bvec {
+    ($BVecN:ident { $($field:ident),+ }, $n:expr) => {
+        impl $BVecN {
+            /// Construct a new mask, using the provided values.
+            #[inline]
+            pub const fn new($($field: bool),+) -> $BVecN {
+                $BVecN { $($field),+ }
+            }
+
+            /// Construct a mask with every component set to `value`.
+            #[inline]
+            pub const fn splat(value: bool) -> $BVecN {
+                $BVecN { $($field: value),+ }
+            }
+
+            /// Whether any component is set, like GLSL's `any`.
+            #[inline]
+            pub fn any(self) -> bool {
+                false $(|| self.$field)+
+            }
+
+            /// Whether every component is set, like GLSL's `all`.
+            #[inline]
+            pub fn all(self) -> bool {
+                true $(&& self.$field)+
+            }
+
+            /// Whether no component is set.
+            #[inline]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            /// Pick the components of `a` where the mask is set, and of `b`
+            /// elsewhere.
+            #[inline]
+            pub fn select<T: Copy, V>(self, a: V, b: V) -> V
+            where
+                V: Into<[T; $n]> + From<[T; $n]>,
+            {
+                let (a, b): ([T; $n], [T; $n]) = (a.into(), b.into());
+                let mask: [bool; $n] = self.into();
+                let mut result = b;
+                for i in 0..$n {
+                    if mask[i] {
+                        result[i] = a[i];
+                    }
+                }
+                result.into()
+            }
+        }
+
+        impl From<[bool; $n]> for $BVecN {
+            #[inline]
+            fn from(v: [bool; $n]) -> $BVecN {
+                let [$($field),+] = v;
+                $BVecN { $($field),+ }
+            }
+        }
+
+        impl From<$BVecN> for [bool; $n] {
+            #[inline]
+            fn from(v: $BVecN) -> [bool; $n] {
+                [$(v.$field),+]
+            }
+        }
+
+        impl Not for $BVecN {
+            type Output = $BVecN;
+
+            #[inline]
+            fn not(self) -> $BVecN {
+                $BVecN { $($field: !self.$field),+ }
+            }
+        }
+
+        impl BitAnd for $BVecN {
+            type Output = $BVecN;
+
+            #[inline]
+            fn bitand(self, rhs: $BVecN) -> $BVecN {
+                $BVecN { $($field: self.$field & rhs.$field),+ }
+            }
+        }
+
+        impl BitOr for $BVecN {
+            type Output = $BVecN;
+
+            #[inline]
+            fn bitor(self, rhs: $BVecN) -> $BVecN {
+                $BVecN { $($field: self.$field | rhs.$field),+ }
+            }
+        }
+
+        impl BitXor for $BVecN {
+            type Output = $BVecN;
+
+            #[inline]
+            fn bitxor(self, rhs: $BVecN) -> $BVecN {
+                $BVecN { $($field: self.$field ^ rhs.$field),+ }
+            }
+        }
+    };
+}
+
+impl_bvec!(BVec2 { x, y }, 2);
+impl_bvec!(BVec3 { x, y, z }, 3);
+impl_bvec!(BVec4 { x, y, z, w }, 4);
+
+macro_rules! impl_element_wise_cmp {
+    ($ArrayN:ident, $BVecN:ident { $($field:ident),+ }) => {
+        impl<S: PartialOrd> ElementWiseCmp for $ArrayN<S> {
+            type Mask = $BVecN;
+
+            #[inline] fn lt_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field < rhs.$field),+ } }
+            #[inline] fn le_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field <= rhs.$field),+ } }
+            #[inline] fn gt_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field > rhs.$field),+ } }
+            #[inline] fn ge_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field >= rhs.$field),+ } }
+            #[inline] fn eq_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field == rhs.$field),+ } }
+            #[inline] fn ne_element_wise(self, rhs: $ArrayN<S>) -> $BVecN { $BVecN { $($field: self.$field != rhs.$field),+ } }
+        }
+
+        impl<S: PartialOrd> ElementWiseCmp<S> for $ArrayN<S> {
+            type Mask = $BVecN;
+
+            #[inline] fn lt_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field < rhs),+ } }
+            #[inline] fn le_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field <= rhs),+ } }
+            #[inline] fn gt_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field > rhs),+ } }
+            #[inline] fn ge_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field >= rhs),+ } }
+            #[inline] fn eq_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field == rhs),+ } }
+            #[inline] fn ne_element_wise(self, rhs: S) -> $BVecN { $BVecN { $($field: self.$field != rhs),+ } }
+        }
+
+        impl<S: AbsDiffEq> $ArrayN<S>
+        where
+            S::Epsilon: Copy,
+        {
+            /// Compare each component with `AbsDiffEq::abs_diff_eq`.
+            #[inline]
+            pub fn abs_diff_eq_element_wise(&self, other: &$ArrayN<S>, epsilon: S::Epsilon) -> $BVecN {
+                $BVecN { $($field: self.$field.abs_diff_eq(&other.$field, epsilon)),+ }
+            }
+        }
+
+        impl<S: RelativeEq> $ArrayN<S>
+        where
+            S::Epsilon: Copy,
+        {
+            /// Compare each component with `RelativeEq::relative_eq`.
+            #[inline]
+            pub fn relative_eq_element_wise(
+                &self,
+                other: &$ArrayN<S>,
+                epsilon: S::Epsilon,
+                max_relative: S::Epsilon,
+            ) -> $BVecN {
+                $BVecN { $($field: self.$field.relative_eq(&other.$field, epsilon, max_relative)),+ }
+            }
+        }
+
+        impl<S: UlpsEq> $ArrayN<S>
+        where
+            S::Epsilon: Copy,
+        {
+            /// Compare each component with `UlpsEq::ulps_eq`.
+            #[inline]
+            pub fn ulps_eq_element_wise(&self, other: &$ArrayN<S>, epsilon: S::Epsilon, max_ulps: u32) -> $BVecN {
+                $BVecN { $($field: self.$field.ulps_eq(&other.$field, epsilon, max_ulps)),+ }
+            }
+        }
+    };
+}
+
+impl_element_wise_cmp!(Vector2, BVec2 { x, y });
+impl_element_wise_cmp!(Vector3, BVec3 { x, y, z });
+impl_element_wise_cmp!(Vector4, BVec4 { x, y, z, w });
+impl_element_wise_cmp!(Point2, BVec2 { x, y });
+impl_element_wise_cmp!(Point3, BVec3 { x, y, z });
@@ -19,11 +19,11 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::fmt;
-use std::iter;
-use std::mem;
-use std::ops::*;
-use std::ptr;
+use core::fmt;
+use core::iter;
+use core::mem;
+use core::ops::*;
+use core::ptr;
 
 use structure::*;
 
@@ -33,6 +33,7 @@ use euler::Euler;
 use num::BaseFloat;
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use simd;
 use transform::{Transform, Transform2, Transform3};
 use vector::{Vector2, Vector3, Vector4};
 
@@ -806,6 +807,10 @@ impl<S: BaseFloat> Matrix for Matrix4<S> {
     }
 
     fn transpose(&self) -> Matrix4<S> {
+        if let Some(transpose) = simd::unary(self, simd::matrix4_transpose) {
+            return transpose;
+        }
+
         #[cfg_attr(rustfmt, rustfmt_skip)]
         Matrix4::new(
             self[0][0], self[1][0], self[2][0], self[3][0],
@@ -860,13 +865,11 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         Vector4::new(self[0][0], self[1][1], self[2][2], self[3][3])
     }
 
-    // The new implementation results in negative optimization when used
-    // without SIMD. so we opt them in with configuration.
-    // A better option would be using specialization. But currently somewhat
-    // specialization is too buggy, and it won't apply here. I'm getting
-    // weird error msgs. Help wanted.
-    #[cfg(not(feature = "simd"))]
     fn invert(&self) -> Option<Matrix4<S>> {
+        if let Some(inverse) = simd::unary(self, simd::matrix4_invert) {
+            return inverse;
+        }
+
         let det = self.determinant();
         if det == S::zero() {
             None
@@ -906,22 +909,6 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
             ))
         }
     }
-    #[cfg(feature = "simd")]
-    fn invert(&self) -> Option<Matrix4<S>> {
-        let tmp0 = unsafe { det_sub_proc_unsafe(self, 1, 2, 3) };
-        let det = tmp0.dot(Vector4::new(self[0][0], self[1][0], self[2][0], self[3][0]));
-
-        if det == S::zero() {
-            None
-        } else {
-            let inv_det = S::one() / det;
-            let tmp0 = tmp0 * inv_det;
-            let tmp1 = unsafe { det_sub_proc_unsafe(self, 0, 3, 2) * inv_det };
-            let tmp2 = unsafe { det_sub_proc_unsafe(self, 0, 1, 3) * inv_det };
-            let tmp3 = unsafe { det_sub_proc_unsafe(self, 0, 2, 1) * inv_det };
-            Some(Matrix4::from_cols(tmp0, tmp1, tmp2, tmp3))
-        }
-    }
 
     fn is_diagonal(&self) -> bool {
         ulps_eq!(self[0][1], &S::zero())
@@ -1312,24 +1299,19 @@ impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
 macro_rules! impl_mv_operator {
     ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
         impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
-            fn mul(matrix, vector) -> $VectorN<S> {$VectorN::new($(matrix.row($row_index).dot(vector.clone())),+)}
+            fn mul(matrix, vector) -> $VectorN<S> {
+                simd::binary(&matrix, &vector, simd::matrix4_mul_vector4)
+                    .unwrap_or_else(|| $VectorN::new($(matrix.row($row_index).dot(vector.clone())),+))
+            }
         });
     }
 }
 
 impl_mv_operator!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_mv_operator!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
-#[cfg(not(feature = "simd"))]
 #[cfg_attr(rustfmt, rustfmt_skip)]
 impl_mv_operator!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
 
-#[cfg(feature = "simd")]
-impl_operator!(<S: BaseFloat> Mul<Vector4<S> > for Matrix4<S> {
-    fn mul(matrix, vector) -> Vector4<S> {
-        matrix[0] * vector[0] + matrix[1] * vector[1] + matrix[2] * vector[2] + matrix[3] * vector[3]
-    }
-});
-
 impl_operator!(<S: BaseFloat> Mul<Matrix2<S> > for Matrix2<S> {
     fn mul(lhs, rhs) -> Matrix2<S> {
         Matrix2::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]),
@@ -1353,7 +1335,7 @@ impl_operator!(<S: BaseFloat> Mul<Matrix3<S> > for Matrix3<S> {
 
 impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
     fn mul(lhs, rhs) -> Matrix4<S> {
-        {
+        simd::binary(&lhs, &rhs, simd::matrix4_mul).unwrap_or_else(|| {
             let a = lhs[0];
             let b = lhs[1];
             let c = lhs[2];
@@ -1366,7 +1348,7 @@ impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
                 a*rhs[2][0] + b*rhs[2][1] + c*rhs[2][2] + d*rhs[2][3],
                 a*rhs[3][0] + b*rhs[3][1] + c*rhs[3][2] + d*rhs[3][3],
             )
-        }
+        })
     }
 });
 
@@ -1569,6 +1551,13 @@ mint_conversions!(Matrix3 { x, y, z }, ColumnMatrix3);
 #[cfg(feature = "mint")]
 mint_conversions!(Matrix4 { x, y, z, w }, ColumnMatrix4);
 
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix2);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix3);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix4);
+
 impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
     /// of a 3-dimensional identity matrix.
@@ -0,0 +1,660 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Normal and tangent generation for indexed triangle meshes.
+//!
+//! Meshes are given as a slice of vertex attributes together with a slice of
+//! triangles, each holding the indices of its three vertices in
+//! counter-clockwise order.
+//!
+//! ```rust
+//! use cgmath::{Point3, Vector2, Vector3};
+//! use cgmath::mesh::{self, NormalWeighting};
+//!
+//! let positions = [
+//!     Point3::new(0.0, 0.0, 0.0),
+//!     Point3::new(1.0, 0.0, 0.0),
+//!     Point3::new(1.0, 1.0, 0.0),
+//!     Point3::new(0.0, 1.0, 0.0),
+//! ];
+//! let uvs = [
+//!     Vector2::new(0.0, 0.0),
+//!     Vector2::new(1.0, 0.0),
+//!     Vector2::new(1.0, 1.0),
+//!     Vector2::new(0.0, 1.0),
+//! ];
+//! let triangles = [[0, 1, 2], [0, 2, 3]];
+//!
+//! let normals = mesh::vertex_normals(&positions, &triangles, NormalWeighting::Angle);
+//! let tangents = mesh::tangents(&positions, &normals, &uvs, &triangles);
+//!
+//! assert_eq!(normals[0], Vector3::unit_z());
+//! assert_eq!(tangents.triangles, triangles);
+//! assert_eq!(tangents.tangents[0].truncate(), Vector3::unit_x());
+//! assert_eq!(mesh::bitangent(normals[0], tangents.tangents[0]), Vector3::unit_y());
+//! ```
+
+use alloc::vec::Vec;
+
+use structure::*;
+
+use num::BaseFloat;
+use point::Point3;
+use vector::{Vector2, Vector3, Vector4};
+
+/// How the normals of the faces around a vertex contribute to its normal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NormalWeighting {
+    /// Weight each face by its area, so that large faces dominate.
+    Area,
+    /// Weight each face by the angle of its corner at the vertex, which does
+    /// not depend on how the faces are tessellated.
+    Angle,
+}
+
+/// The unnormalized normal of a triangle, with a length of twice its area.
+#[inline]
+fn scaled_normal<S: BaseFloat>(positions: &[Point3<S>], triangle: [usize; 3]) -> Vector3<S> {
+    let [a, b, c] = triangle;
+    (positions[b] - positions[a]).cross(positions[c] - positions[a])
+}
+
+/// Normalize a vector, leaving zero vectors unchanged.
+#[inline]
+fn normalize_or_zero<S: BaseFloat>(v: Vector3<S>) -> Vector3<S> {
+    let length = v.magnitude();
+    if length > S::zero() {
+        v / length
+    } else {
+        v
+    }
+}
+
+/// The angle between two vectors, or zero if either vector is zero.
+#[inline]
+fn corner_angle<S: BaseFloat>(a: Vector3<S>, b: Vector3<S>) -> S {
+    let (a, b) = (normalize_or_zero(a), normalize_or_zero(b));
+    if a == Vector3::zero() || b == Vector3::zero() {
+        S::zero()
+    } else {
+        a.dot(b).max(-S::one()).min(S::one()).acos()
+    }
+}
+
+/// Compute the unit normal of each triangle. Degenerate triangles get a zero
+/// normal.
+pub fn face_normals<S: BaseFloat>(
+    positions: &[Point3<S>],
+    triangles: &[[usize; 3]],
+) -> Vec<Vector3<S>> {
+    triangles
+        .iter()
+        .map(|&triangle| normalize_or_zero(scaled_normal(positions, triangle)))
+        .collect()
+}
+
+/// Compute a unit normal for each vertex by averaging the normals of the
+/// triangles around it. Vertices that are not part of any triangle with an
+/// area get a zero normal.
+pub fn vertex_normals<S: BaseFloat>(
+    positions: &[Point3<S>],
+    triangles: &[[usize; 3]],
+    weighting: NormalWeighting,
+) -> Vec<Vector3<S>> {
+    let mut normals = vec![Vector3::zero(); positions.len()];
+
+    for &triangle in triangles {
+        let normal = scaled_normal(positions, triangle);
+        match weighting {
+            NormalWeighting::Area => {
+                for &i in &triangle {
+                    normals[i] += normal;
+                }
+            }
+            NormalWeighting::Angle => {
+                let normal = normalize_or_zero(normal);
+                for corner in 0..3 {
+                    let i = triangle[corner];
+                    let next = positions[triangle[(corner + 1) % 3]] - positions[i];
+                    let prev = positions[triangle[(corner + 2) % 3]] - positions[i];
+                    normals[i] += normal * corner_angle(next, prev);
+                }
+            }
+        }
+    }
+
+    normals.into_iter().map(normalize_or_zero).collect()
+}
+
+/// The tangents of a mesh, created by `tangents`.
+///
+/// Generating tangents may split vertices, wherever the triangles around a
+/// vertex disagree about its tangent space. The vertices of the split mesh
+/// start with the vertices of the original mesh, in the same order, followed
+/// by the extra copies of the split vertices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tangents<S> {
+    /// The tangent of each vertex of the split mesh. The `w` component is `1`
+    /// or `-1`, giving the handedness of the tangent space.
+    pub tangents: Vec<Vector4<S>>,
+    /// The index of the original vertex that each vertex of the split mesh
+    /// was copied from.
+    pub remap: Vec<usize>,
+    /// The triangles of the split mesh, in the same order as the original
+    /// triangles.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<S> Tangents<S> {
+    /// Copy the values of a vertex attribute of the original mesh, such as
+    /// its positions, to the vertices of the split mesh.
+    pub fn remap_attribute<T: Copy>(&self, values: &[T]) -> Vec<T> {
+        self.remap.iter().map(|&i| values[i]).collect()
+    }
+}
+
+/// Compute the tangent of each vertex using the [MikkTSpace] algorithm, so
+/// that normal maps baked with MikkTSpace tangents are reproduced exactly,
+/// up to floating point rounding.
+///
+/// The tangent points in the direction of increasing `u` texture coordinate,
+/// orthogonal to the vertex normal. Its `w` component is `1` or `-1`, giving
+/// the handedness of the tangent space, so that the bitangent can be
+/// reconstructed in a shader as `w * cross(normal, tangent)`, as done by
+/// `bitangent`.
+///
+/// As in MikkTSpace, vertices with identical positions, normals and texture
+/// coordinates are treated as the same vertex, and the triangles around a
+/// vertex are grouped by the orientation of their texture mapping. A vertex
+/// whose triangles form several groups, such as a vertex on a mirroring seam
+/// of the texture coordinates, is split into one vertex per group, so the
+/// returned `Tangents` hold a new index buffer. Corners that MikkTSpace
+/// cannot find a tangent for, for example because all of their triangles
+/// have degenerate texture coordinates, get its default tangent of
+/// `(1, 0, 0, -1)`. Vertices that are not part of any triangle get an
+/// arbitrary tangent that is orthogonal to the normal.
+///
+/// # Panics
+///
+/// Panics if `normals` or `uvs` don't have the same length as `positions`.
+///
+/// [MikkTSpace]: http://www.mikktspace.com/
+pub fn tangents<S: BaseFloat>(
+    positions: &[Point3<S>],
+    normals: &[Vector3<S>],
+    uvs: &[Vector2<S>],
+    triangles: &[[usize; 3]],
+) -> Tangents<S> {
+    assert_eq!(
+        normals.len(),
+        positions.len(),
+        "There must be a normal for each vertex"
+    );
+    assert_eq!(
+        uvs.len(),
+        positions.len(),
+        "There must be texture coordinates for each vertex"
+    );
+
+    let welded = weld_vertices(positions, normals, uvs);
+    let indices: Vec<[usize; 3]> = triangles
+        .iter()
+        .map(|t| [welded[t[0]], welded[t[1]], welded[t[2]]])
+        .collect();
+
+    // Triangles with coincident corners don't take part in the grouping
+    let (good, degenerate): (Vec<usize>, Vec<usize>) = (0..indices.len()).partition(|&t| {
+        let [a, b, c] = indices[t];
+        let (a, b, c) = (positions[a], positions[b], positions[c]);
+        a != b && a != c && b != c
+    });
+
+    let mut infos: Vec<TriangleInfo<S>> = good
+        .iter()
+        .map(|&t| TriangleInfo::new(positions, uvs, indices[t]))
+        .collect();
+    find_neighbors(&good, &indices, &mut infos);
+    let groups = build_groups(&good, &indices, &mut infos);
+
+    let default = (Vector3::unit_x(), false);
+    let mut spaces = vec![[default; 3]; indices.len()];
+    for (g, group) in groups.iter().enumerate() {
+        let n = normals[group.vertex];
+        let mut subgroups: Vec<(Vec<usize>, Vector3<S>)> = Vec::new();
+
+        for &f in &group.faces {
+            let corner = (0..3).find(|&i| infos[f].groups[i] == Some(g)).unwrap();
+            let (os, ot) = infos[f].project(n);
+
+            // Every triangle of the group whose tangent space is not opposite
+            // to that of this one contributes to its tangent
+            let mut members: Vec<usize> = group
+                .faces
+                .iter()
+                .cloned()
+                .filter(|&t| {
+                    let (os2, ot2) = infos[t].project(n);
+                    infos[f].any
+                        || infos[t].any
+                        || t == f
+                        || (os.dot(os2) > -S::one() && ot.dot(ot2) > -S::one())
+                })
+                .collect();
+            members.sort();
+
+            let tangent = match subgroups.iter().find(|s| s.0 == members) {
+                Some(subgroup) => subgroup.1,
+                None => {
+                    let tangent = eval_tangent(
+                        positions,
+                        normals,
+                        &good,
+                        &indices,
+                        &infos,
+                        &members,
+                        group.vertex,
+                    );
+                    subgroups.push((members, tangent));
+                    tangent
+                }
+            };
+            spaces[good[f]][corner] = (tangent, group.orient);
+        }
+    }
+
+    // Degenerate triangles copy the tangent space of the first good triangle
+    // sharing the vertex
+    let mut first_corner = vec![None; positions.len()];
+    for &t in good.iter().rev() {
+        for corner in (0..3).rev() {
+            first_corner[indices[t][corner]] = Some(spaces[t][corner]);
+        }
+    }
+    for &t in &degenerate {
+        for corner in 0..3 {
+            if let Some(space) = first_corner[indices[t][corner]] {
+                spaces[t][corner] = space;
+            }
+        }
+    }
+
+    split_vertices(normals, triangles, &spaces)
+}
+
+/// Whether a value is not zero, following MikkTSpace's `NotZero`.
+#[inline]
+fn not_zero<S: BaseFloat>(x: S) -> bool {
+    x.abs() > S::min_positive_value()
+}
+
+/// Normalize a vector if any of its components is not zero, following
+/// MikkTSpace.
+#[inline]
+fn normalize_nonzero<S: BaseFloat>(v: Vector3<S>) -> Vector3<S> {
+    if not_zero(v.x) || not_zero(v.y) || not_zero(v.z) {
+        v * (S::one() / v.magnitude())
+    } else {
+        v
+    }
+}
+
+/// Project a vector onto the plane orthogonal to the unit normal `n`.
+#[inline]
+fn project_normalized<S: BaseFloat>(v: Vector3<S>, n: Vector3<S>) -> Vector3<S> {
+    normalize_nonzero(v - n * n.dot(v))
+}
+
+/// Map each vertex to the first vertex with the same position, normal and
+/// texture coordinates.
+fn weld_vertices<S: BaseFloat>(
+    positions: &[Point3<S>],
+    normals: &[Vector3<S>],
+    uvs: &[Vector2<S>],
+) -> Vec<usize> {
+    // Compare the exact values, treating zero and negative zero as equal.
+    // Vertices with NaN components are never welded, as in MikkTSpace.
+    let key = |i: usize| {
+        let (p, n, t) = (positions[i], normals[i], uvs[i]);
+        let values = [p.x, p.y, p.z, n.x, n.y, n.z, t.x, t.y];
+        let mut key = [(0, 0, 0); 8];
+        for (k, &x) in key.iter_mut().zip(values.iter()) {
+            if x.is_nan() {
+                return None;
+            } else if x != S::zero() {
+                *k = x.integer_decode();
+            }
+        }
+        Some(key)
+    };
+
+    let mut sorted: Vec<_> = (0..positions.len())
+        .filter_map(|i| key(i).map(|k| (k, i)))
+        .collect();
+    sorted.sort();
+
+    let mut welded: Vec<usize> = (0..positions.len()).collect();
+    for pair in sorted.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            welded[pair[1].1] = welded[pair[0].1];
+        }
+    }
+    welded
+}
+
+/// The tangent space of a triangle, and its place in the groups of the
+/// vertices around it.
+struct TriangleInfo<S> {
+    /// The triangles across the edges starting at each corner.
+    neighbors: [Option<usize>; 3],
+    /// The group of each corner.
+    groups: [Option<usize>; 3],
+    /// The direction of increasing `u`.
+    os: Vector3<S>,
+    /// The direction of increasing `v`.
+    ot: Vector3<S>,
+    /// Whether the texture mapping preserves the orientation of the triangle.
+    orient: bool,
+    /// Whether the texture mapping is degenerate, so that the triangle can
+    /// join the group of any of its neighbors.
+    any: bool,
+}
+
+impl<S: BaseFloat> TriangleInfo<S> {
+    fn new(positions: &[Point3<S>], uvs: &[Vector2<S>], triangle: [usize; 3]) -> TriangleInfo<S> {
+        let [i0, i1, i2] = triangle;
+        let d1 = positions[i1] - positions[i0];
+        let d2 = positions[i2] - positions[i0];
+        let t21 = uvs[i1] - uvs[i0];
+        let t31 = uvs[i2] - uvs[i0];
+
+        let signed_area = t21.x * t31.y - t21.y * t31.x;
+        let mut os = d1 * t31.y - d2 * t21.y;
+        let mut ot = d2 * t21.x - d1 * t31.x;
+        let orient = signed_area > S::zero();
+        let mut any = true;
+
+        if not_zero(signed_area) {
+            let area = signed_area.abs();
+            let (length_os, length_ot) = (os.magnitude(), ot.magnitude());
+            let sign = if orient { S::one() } else { -S::one() };
+            if not_zero(length_os) {
+                os *= sign / length_os;
+            }
+            if not_zero(length_ot) {
+                ot *= sign / length_ot;
+            }
+            if not_zero(length_os / area) && not_zero(length_ot / area) {
+                any = false;
+            }
+        }
+
+        TriangleInfo {
+            neighbors: [None; 3],
+            groups: [None; 3],
+            os,
+            ot,
+            orient,
+            any,
+        }
+    }
+
+    /// The directions of increasing `u` and `v`, projected onto the plane
+    /// orthogonal to `n` and normalized.
+    #[inline]
+    fn project(&self, n: Vector3<S>) -> (Vector3<S>, Vector3<S>) {
+        (
+            project_normalized(self.os, n),
+            project_normalized(self.ot, n),
+        )
+    }
+}
+
+/// The corners of the triangle at either end of the edge between `a` and
+/// `b`, and the number of the edge, in the order of the triangle.
+#[inline]
+fn directed_edge(triangle: [usize; 3], a: usize, b: usize) -> (usize, usize, usize) {
+    if triangle[0] == a || triangle[0] == b {
+        if triangle[1] == a || triangle[1] == b {
+            (triangle[0], triangle[1], 0)
+        } else {
+            (triangle[2], triangle[0], 2)
+        }
+    } else {
+        (triangle[1], triangle[2], 1)
+    }
+}
+
+/// Pair up the triangles sharing an edge in opposite directions.
+fn find_neighbors<S>(good: &[usize], indices: &[[usize; 3]], infos: &mut [TriangleInfo<S>]) {
+    let mut edges = Vec::with_capacity(3 * good.len());
+    for (f, &t) in good.iter().enumerate() {
+        for i in 0..3 {
+            let (a, b) = (indices[t][i], indices[t][(i + 1) % 3]);
+            edges.push((a.min(b), a.max(b), f));
+        }
+    }
+    edges.sort();
+
+    let mut start = 0;
+    while start < edges.len() {
+        let (lo, hi, _) = edges[start];
+        let end = start
+            + edges[start..]
+                .iter()
+                .take_while(|e| (e.0, e.1) == (lo, hi))
+                .count();
+
+        for i in start..end {
+            let f = edges[i].2;
+            let (a0, a1, edge_a) = directed_edge(indices[good[f]], lo, hi);
+            if infos[f].neighbors[edge_a].is_some() {
+                continue;
+            }
+            for &(_, _, t) in &edges[(i + 1)..end] {
+                let (b0, b1, edge_b) = directed_edge(indices[good[t]], lo, hi);
+                if a0 == b1 && a1 == b0 && infos[t].neighbors[edge_b].is_none() {
+                    infos[f].neighbors[edge_a] = Some(t);
+                    infos[t].neighbors[edge_b] = Some(f);
+                    break;
+                }
+            }
+        }
+        start = end;
+    }
+}
+
+/// The triangles around a vertex that are connected through shared edges and
+/// have the same orientation of their texture mapping.
+struct Group {
+    vertex: usize,
+    orient: bool,
+    faces: Vec<usize>,
+}
+
+/// Group the corners of the triangles, following MikkTSpace's
+/// `Build4RuleGroups`.
+fn build_groups<S>(
+    good: &[usize],
+    indices: &[[usize; 3]],
+    infos: &mut [TriangleInfo<S>],
+) -> Vec<Group> {
+    let mut groups = Vec::new();
+    for f in 0..good.len() {
+        for i in 0..3 {
+            if infos[f].any || infos[f].groups[i].is_some() {
+                continue;
+            }
+            let g = groups.len();
+            groups.push(Group {
+                vertex: indices[good[f]][i],
+                orient: infos[f].orient,
+                faces: vec![f],
+            });
+            infos[f].groups[i] = Some(g);
+
+            let (left, right) = (infos[f].neighbors[i], infos[f].neighbors[(i + 2) % 3]);
+            for &neighbor in left.iter().chain(right.iter()) {
+                assign_group(good, indices, infos, &mut groups, neighbor, g);
+            }
+        }
+    }
+    groups
+}
+
+/// Add a triangle and its neighbors around the vertex of a group to the
+/// group, following MikkTSpace's `AssignRecur`.
+fn assign_group<S>(
+    good: &[usize],
+    indices: &[[usize; 3]],
+    infos: &mut [TriangleInfo<S>],
+    groups: &mut [Group],
+    f: usize,
+    g: usize,
+) {
+    let vertex = groups[g].vertex;
+    let i = (0..3).find(|&i| indices[good[f]][i] == vertex).unwrap();
+    if infos[f].groups[i].is_some() {
+        return;
+    }
+
+    // The first group to reach a triangle with a degenerate texture mapping
+    // decides its orientation
+    if infos[f].any && infos[f].groups.iter().all(Option::is_none) {
+        infos[f].orient = groups[g].orient;
+    }
+    if infos[f].orient != groups[g].orient {
+        return;
+    }
+
+    groups[g].faces.push(f);
+    infos[f].groups[i] = Some(g);
+
+    let (left, right) = (infos[f].neighbors[i], infos[f].neighbors[(i + 2) % 3]);
+    for &neighbor in left.iter().chain(right.iter()) {
+        assign_group(good, indices, infos, groups, neighbor, g);
+    }
+}
+
+/// The tangent at `vertex` of a set of triangles around it, weighted by the
+/// angles of their corners, following MikkTSpace's `EvalTspace`.
+fn eval_tangent<S: BaseFloat>(
+    positions: &[Point3<S>],
+    normals: &[Vector3<S>],
+    good: &[usize],
+    indices: &[[usize; 3]],
+    infos: &[TriangleInfo<S>],
+    members: &[usize],
+    vertex: usize,
+) -> Vector3<S> {
+    let mut tangent = Vector3::zero();
+    for &f in members {
+        if infos[f].any {
+            continue;
+        }
+        let triangle = indices[good[f]];
+        let i = (0..3).find(|&i| triangle[i] == vertex).unwrap();
+        let n = normals[vertex];
+        let os = project_normalized(infos[f].os, n);
+
+        let p = positions[vertex];
+        let prev = project_normalized(positions[triangle[(i + 2) % 3]] - p, n);
+        let next = project_normalized(positions[triangle[(i + 1) % 3]] - p, n);
+        let angle = prev.dot(next).max(-S::one()).min(S::one()).acos();
+
+        tangent += os * angle;
+    }
+    normalize_nonzero(tangent)
+}
+
+/// Create a vertex for each distinct tangent space at the corners of the
+/// triangles around each vertex.
+fn split_vertices<S: BaseFloat>(
+    normals: &[Vector3<S>],
+    triangles: &[[usize; 3]],
+    spaces: &[[(Vector3<S>, bool); 3]],
+) -> Tangents<S> {
+    let count = normals.len();
+    let mut tangents: Vec<Option<Vector4<S>>> = vec![None; count];
+    let mut remap: Vec<usize> = (0..count).collect();
+    // The next copy of the same original vertex
+    let mut next_copy: Vec<Option<usize>> = vec![None; count];
+    let mut split_triangles = Vec::with_capacity(triangles.len());
+
+    for (triangle, spaces) in triangles.iter().zip(spaces) {
+        let mut split = [0; 3];
+        for corner in 0..3 {
+            let (direction, orient) = spaces[corner];
+            let w = if orient { S::one() } else { -S::one() };
+            let tangent = direction.extend(w);
+
+            let mut vertex = triangle[corner];
+            loop {
+                if tangents[vertex].is_none() {
+                    tangents[vertex] = Some(tangent);
+                }
+                if tangents[vertex] == Some(tangent) {
+                    break;
+                }
+                vertex = match next_copy[vertex] {
+                    Some(copy) => copy,
+                    None => {
+                        next_copy[vertex] = Some(remap.len());
+                        remap.push(triangle[corner]);
+                        tangents.push(None);
+                        next_copy.push(None);
+                        remap.len() - 1
+                    }
+                };
+            }
+            split[corner] = vertex;
+        }
+        split_triangles.push(split);
+    }
+
+    Tangents {
+        tangents: tangents
+            .into_iter()
+            .zip(&remap)
+            .map(|(t, &i)| t.unwrap_or_else(|| any_orthogonal(normals[i]).extend(S::one())))
+            .collect(),
+        remap,
+        triangles: split_triangles,
+    }
+}
+
+/// Reconstruct the bitangent of a vertex from its normal and a tangent
+/// created by `tangents`.
+#[inline]
+pub fn bitangent<S: BaseFloat>(normal: Vector3<S>, tangent: Vector4<S>) -> Vector3<S> {
+    normal.cross(tangent.truncate()) * tangent.w
+}
+
+/// Find a unit vector orthogonal to `n`, or the `x` axis if `n` is zero.
+fn any_orthogonal<S: BaseFloat>(n: Vector3<S>) -> Vector3<S> {
+    let axis = if n.x.abs() <= n.y.abs() && n.x.abs() <= n.z.abs() {
+        Vector3::unit_x()
+    } else if n.y.abs() <= n.z.abs() {
+        Vector3::unit_y()
+    } else {
+        Vector3::unit_z()
+    };
+    let t = normalize_or_zero(axis - n * n.dot(axis));
+    if t == Vector3::zero() {
+        Vector3::unit_x()
+    } else {
+        t
+    }
+}
@@ -15,8 +15,8 @@
 
 use approx;
 
-use std::fmt;
-use std::ops::*;
+use core::fmt;
+use core::ops::*;
 
 use num_traits::{Float, Num, NumCast};
 
@@ -0,0 +1,284 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Oriented bounding boxes.
+
+use num_traits::cast;
+
+use structure::*;
+
+use aabb::Aabb3;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation};
+use vector::Vector3;
+
+/// A box in 3-dimensional space, rotated to an arbitrary orientation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Obb3<S> {
+    /// The center of the box.
+    pub center: Point3<S>,
+    /// Half the size of the box along each of its local axes.
+    pub half_extents: Vector3<S>,
+    /// The rotation from the local axes of the box to world space.
+    pub orientation: Basis3<S>,
+}
+
+impl<S: BaseFloat> Obb3<S> {
+    /// Construct a new oriented bounding box. The orientation can be a
+    /// `Basis3` or a `Quaternion`.
+    #[inline]
+    pub fn new<R: Into<Basis3<S>>>(
+        center: Point3<S>,
+        half_extents: Vector3<S>,
+        orientation: R,
+    ) -> Obb3<S> {
+        Obb3 {
+            center,
+            half_extents,
+            orientation: orientation.into(),
+        }
+    }
+
+    /// Construct an oriented bounding box covering the same region as an
+    /// axis-aligned bounding box.
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb3<S>) -> Obb3<S> {
+        let two: S = cast(2).unwrap();
+        Obb3::new(aabb.center(), aabb.extents() / two, Basis3::one())
+    }
+
+    /// The local axes of the box in world space.
+    #[inline]
+    pub fn axes(&self) -> [Vector3<S>; 3] {
+        let m = self.orientation.as_ref();
+        [m.x, m.y, m.z]
+    }
+
+    /// The corners of the box. The corner at index `i` is on the positive side
+    /// of the local `x` axis if bit 0 of `i` is set, of the local `y` axis if
+    /// bit 1 is set, and of the local `z` axis if bit 2 is set.
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let [x, y, z] = self.axes();
+        let (x, y, z) = (
+            x * self.half_extents.x,
+            y * self.half_extents.y,
+            z * self.half_extents.z,
+        );
+        let c = self.center;
+        [
+            c - x - y - z,
+            c + x - y - z,
+            c - x + y - z,
+            c + x + y - z,
+            c - x - y + z,
+            c + x - y + z,
+            c - x + y + z,
+            c + x + y + z,
+        ]
+    }
+
+    /// Check if `point` is inside the box or on its surface.
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        let local = self.orientation.invert().rotate_vector(point - self.center);
+        local.x.abs() <= self.half_extents.x
+            && local.y.abs() <= self.half_extents.y
+            && local.z.abs() <= self.half_extents.z
+    }
+
+    /// The point inside the box or on its surface closest to `point`.
+    pub fn closest_point(&self, point: Point3<S>) -> Point3<S> {
+        let d = point - self.center;
+        let mut closest = self.center;
+        for (i, axis) in self.axes().iter().enumerate() {
+            let extent = self.half_extents[i];
+            let distance = d.dot(*axis).max(-extent).min(extent);
+            closest += *axis * distance;
+        }
+        closest
+    }
+
+    /// The squared distance from the box to `point`, which is zero if the
+    /// point is inside the box.
+    #[inline]
+    pub fn distance2(&self, point: Point3<S>) -> S {
+        point.distance2(self.closest_point(point))
+    }
+
+    /// The smallest axis-aligned bounding box containing this box.
+    pub fn to_aabb(&self) -> Aabb3<S> {
+        let m = self.orientation.as_ref();
+        let h = self.half_extents;
+        let radius = Vector3::new(
+            m.x.x.abs() * h.x + m.y.x.abs() * h.y + m.z.x.abs() * h.z,
+            m.x.y.abs() * h.x + m.y.y.abs() * h.y + m.z.y.abs() * h.z,
+            m.x.z.abs() * h.x + m.y.z.abs() * h.y + m.z.z.abs() * h.z,
+        );
+        Aabb3::new(self.center - radius, self.center + radius)
+    }
+
+    /// Check if the boxes overlap, using the separating axis theorem.
+    pub fn overlaps(&self, other: &Obb3<S>) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let ea = self.half_extents;
+        let eb = other.half_extents;
+
+        // The rotation from the frame of `other` to the frame of `self`, with
+        // an epsilon added to the absolute values so that the cross products
+        // of nearly parallel edges do not produce false separations.
+        let mut r = [[S::zero(); 3]; 3];
+        let mut abs_r = [[S::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(b[j]);
+                abs_r[i][j] = r[i][j].abs() + S::epsilon();
+            }
+        }
+
+        let d = other.center - self.center;
+        let t = [d.dot(a[0]), d.dot(a[1]), d.dot(a[2])];
+
+        // The axes of `self`
+        for i in 0..3 {
+            let ra = ea[i];
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // The axes of `other`
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let rb = eb[j];
+            let tj = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if tj.abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // The cross products of each pair of axes
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let tij = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if tij.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Check if the box overlaps an axis-aligned bounding box, using the
+    /// separating axis theorem.
+    #[inline]
+    pub fn overlaps_aabb(&self, aabb: &Aabb3<S>) -> bool {
+        self.overlaps(&Obb3::from_aabb(aabb))
+    }
+
+    /// Fit a box to a set of points, aligning its axes with the principal
+    /// components of the points. Returns `None` if there are no points.
+    ///
+    /// The axes are the eigenvectors of the covariance matrix of the points,
+    /// which gives a good fit for elongated point sets, but not necessarily
+    /// the box with the smallest volume.
+    pub fn from_points(points: &[Point3<S>]) -> Option<Obb3<S>> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let mean = Point3::centroid(points);
+        let mut covariance = Matrix3::zero();
+        for &p in points {
+            let d = p - mean;
+            covariance += Matrix3::from_cols(d * d.x, d * d.y, d * d.z);
+        }
+        covariance /= cast(points.len()).unwrap();
+
+        let (_, mut axes) = symmetric_eigen(covariance);
+        if axes.determinant() < S::zero() {
+            axes.z = -axes.z;
+        }
+        let orientation = Basis3::from_quaternion(&Quaternion::from(axes).normalize());
+        let axes = orientation.as_ref();
+
+        let first = points[0] - mean;
+        let project = |d: Vector3<S>| Vector3::new(d.dot(axes.x), d.dot(axes.y), d.dot(axes.z));
+        let (mut min, mut max) = (project(first), project(first));
+        for &p in &points[1..] {
+            let local = project(p - mean);
+            for i in 0..3 {
+                min[i] = min[i].min(local[i]);
+                max[i] = max[i].max(local[i]);
+            }
+        }
+
+        let two: S = cast(2).unwrap();
+        let offset = (min + max) / two;
+        Some(Obb3::new(
+            mean + axes * offset,
+            (max - min) / two,
+            orientation,
+        ))
+    }
+}
+
+/// Compute the eigenvalues and eigenvectors of a symmetric matrix with the
+/// cyclic Jacobi method. The eigenvectors are returned as the columns of an
+/// orthogonal matrix.
+fn symmetric_eigen<S: BaseFloat>(mut a: Matrix3<S>) -> (Vector3<S>, Matrix3<S>) {
+    let mut v = Matrix3::identity();
+    let two: S = cast(2).unwrap();
+
+    for _ in 0..50 {
+        let off = a[1][0] * a[1][0] + a[2][0] * a[2][0] + a[2][1] * a[2][1];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= diagonal * S::epsilon() * S::epsilon() {
+            break;
+        }
+
+        for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+            if a[q][p] == S::zero() {
+                continue;
+            }
+
+            // Find the rotation that zeroes the (p, q) entry
+            let theta = (a[q][q] - a[p][p]) / (two * a[q][p]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+            let c = S::one() / (t * t + S::one()).sqrt();
+            let s = t * c;
+
+            let mut j = Matrix3::identity();
+            j[p][p] = c;
+            j[q][q] = c;
+            j[q][p] = s;
+            j[p][q] = -s;
+
+            a = j.transpose() * a * j;
+            v = v * j;
+        }
+    }
+
+    (Vector3::new(a[0][0], a[1][1], a[2][2]), v)
+}
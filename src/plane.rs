@@ -0,0 +1,284 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Planes in three-dimensional space.
+
+use core::ops::Neg;
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use transform::Transform;
+use vector::{Vector3, Vector4};
+
+/// A plane in 3-dimensional space, made up of the points `p` that satisfy the
+/// equation `normal.dot(p) + d = 0`.
+///
+/// The normal is expected to be normalized, so that `d` is the signed distance
+/// from the plane to the origin, measured against the normal. The side of the
+/// plane that the normal points towards is called its positive side.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plane<S> {
+    /// The unit normal of the plane.
+    pub normal: Vector3<S>,
+    /// The constant term of the plane equation.
+    pub d: S,
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Construct a new plane from a unit normal and the constant term of its
+    /// equation.
+    #[inline]
+    pub fn new(normal: Vector3<S>, d: S) -> Plane<S> {
+        Plane { normal, d }
+    }
+
+    /// Construct the plane passing through `point`, perpendicular to `normal`.
+    /// The normal does not need to be normalized.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, normal: Vector3<S>) -> Plane<S> {
+        let normal = normal.normalize();
+        Plane::new(normal, -point.dot(normal))
+    }
+
+    /// Construct the plane passing through three points, or `None` if the
+    /// points are collinear.
+    ///
+    /// The normal points towards the side from which the points appear in
+    /// counter-clockwise order.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        let (ab, ac) = (b - a, c - a);
+        let normal = ab.cross(ac);
+        // |ab x ac|^2 = |ab|^2 |ac|^2 sin^2(angle), so this compares the sine
+        // of the angle between the edges, independently of their lengths.
+        if normal.magnitude2() <= S::epsilon() * ab.magnitude2() * ac.magnitude2() {
+            None
+        } else {
+            Some(Plane::from_point_normal(a, normal))
+        }
+    }
+
+    /// Construct a plane from the coefficients `(a, b, c, d)` of the plane
+    /// equation `a*x + b*y + c*z + d = 0`, normalizing them so that `(a, b, c)`
+    /// has unit length.
+    #[inline]
+    pub fn from_vector4(v: Vector4<S>) -> Plane<S> {
+        let v = v / v.truncate().magnitude();
+        Plane::new(v.truncate(), v.w)
+    }
+
+    /// The coefficients `(a, b, c, d)` of the plane equation.
+    #[inline]
+    pub fn to_vector4(&self) -> Vector4<S> {
+        self.normal.extend(self.d)
+    }
+
+    /// The signed distance from the plane to `point`, which is positive if the
+    /// point lies on the side that the normal points towards.
+    #[inline]
+    pub fn signed_distance(&self, point: Point3<S>) -> S {
+        point.dot(self.normal) + self.d
+    }
+
+    /// The point on the plane closest to the origin.
+    #[inline]
+    pub fn origin(&self) -> Point3<S> {
+        Point3::from_vec(self.normal * -self.d)
+    }
+
+    /// Project a point onto the plane, returning the closest point on the plane.
+    #[inline]
+    pub fn project_point(&self, point: Point3<S>) -> Point3<S> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Project a vector onto the plane, removing its component along the normal.
+    #[inline]
+    pub fn project_vector(&self, vector: Vector3<S>) -> Vector3<S> {
+        vector - self.normal * vector.dot(self.normal)
+    }
+
+    /// Reflect a point across the plane.
+    #[inline]
+    pub fn reflect_point(&self, point: Point3<S>) -> Point3<S> {
+        let two: S = cast(2).unwrap();
+        point - self.normal * (two * self.signed_distance(point))
+    }
+
+    /// Reflect a vector across the plane.
+    #[inline]
+    pub fn reflect_vector(&self, vector: Vector3<S>) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+        vector - self.normal * (two * vector.dot(self.normal))
+    }
+
+    /// Create the matrix that reflects points and vectors across the plane, as
+    /// used to render planar reflections.
+    pub fn reflection_matrix(&self) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let n = self.normal;
+        let reflect = |v: Vector3<S>| v - n * (two * v.dot(n));
+
+        Matrix4::from_cols(
+            reflect(Vector3::unit_x()).extend(S::zero()),
+            reflect(Vector3::unit_y()).extend(S::zero()),
+            reflect(Vector3::unit_z()).extend(S::zero()),
+            (n * (-two * self.d)).extend(S::one()),
+        )
+    }
+
+    /// Transform the plane by a matrix, using the inverse-transpose of the
+    /// matrix to transform the plane equation. Returns `None` if the matrix is
+    /// not invertible.
+    ///
+    /// Unlike `Plane::transform`, this also supports projective matrices, for
+    /// example to move a plane into clip space.
+    pub fn transform_matrix(&self, matrix: &Matrix4<S>) -> Option<Plane<S>> {
+        matrix
+            .invert()
+            .map(|inverse| Plane::from_vector4(inverse.transpose() * self.to_vector4()))
+    }
+
+    /// Apply an affine transformation to the plane.
+    ///
+    /// The normal is kept pointing to the side of the plane that the points on
+    /// its positive side are transformed to, even if the transformation
+    /// mirrors space.
+    pub fn transform<T: Transform<Point3<S>>>(&self, transform: &T) -> Plane<S> {
+        // Pick a vector that is not parallel to the normal to span the plane
+        let n = self.normal;
+        let other = if n.x.abs() < cast(0.9).unwrap() {
+            Vector3::unit_x()
+        } else {
+            Vector3::unit_y()
+        };
+        let u = n.cross(other);
+        let v = n.cross(u);
+
+        let origin = self.origin();
+        let point = transform.transform_point(origin);
+        let normal = transform
+            .transform_vector(u)
+            .cross(transform.transform_vector(v));
+        let positive = transform.transform_point(origin + n) - point;
+
+        if normal.dot(positive) < S::zero() {
+            Plane::from_point_normal(point, -normal)
+        } else {
+            Plane::from_point_normal(point, normal)
+        }
+    }
+}
+
+/// A flat, circular disc in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Disc<S> {
+    /// The center of the disc.
+    pub center: Point3<S>,
+    /// The unit normal of the plane containing the disc.
+    pub normal: Vector3<S>,
+    /// The radius of the disc.
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Disc<S> {
+    /// Construct a new disc. The normal does not need to be normalized.
+    #[inline]
+    pub fn new(center: Point3<S>, normal: Vector3<S>, radius: S) -> Disc<S> {
+        Disc {
+            center,
+            normal: normal.normalize(),
+            radius,
+        }
+    }
+
+    /// The plane containing the disc.
+    #[inline]
+    pub fn plane(&self) -> Plane<S> {
+        Plane::new(self.normal, -self.center.dot(self.normal))
+    }
+}
+
+impl<S: BaseFloat> Neg for Plane<S> {
+    type Output = Plane<S>;
+
+    /// Flip the plane, so that its normal points to the other side.
+    #[inline]
+    fn neg(self) -> Plane<S> {
+        Plane::new(-self.normal, -self.d)
+    }
+}
+
+impl<S: BaseFloat> From<Vector4<S>> for Plane<S> {
+    #[inline]
+    fn from(v: Vector4<S>) -> Plane<S> {
+        Plane::from_vector4(v)
+    }
+}
+
+impl<S: BaseFloat> From<Plane<S>> for Vector4<S> {
+    #[inline]
+    fn from(plane: Plane<S>) -> Vector4<S> {
+        plane.to_vector4()
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Plane<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.normal, &other.normal, epsilon)
+            && S::abs_diff_eq(&self.d, &other.d, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Plane<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.normal, &other.normal, epsilon, max_relative)
+            && S::relative_eq(&self.d, &other.d, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Plane<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps)
+            && S::ulps_eq(&self.d, &other.d, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,337 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions for working with simple polygons in the plane.
+//!
+//! Polygons are given as slices of their vertices in order, with the last
+//! vertex implicitly connected back to the first.
+//!
+//! ```rust
+//! use cgmath::Point2;
+//! use cgmath::polygon::{self, Winding};
+//!
+//! let square = [
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(2.0, 0.0),
+//!     Point2::new(2.0, 2.0),
+//!     Point2::new(0.0, 2.0),
+//! ];
+//!
+//! assert_eq!(polygon::signed_area(&square), 4.0);
+//! assert_eq!(polygon::winding(&square), Some(Winding::CounterClockwise));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+use num_traits::cast;
+
+use structure::*;
+
+use num::BaseFloat;
+use point::Point2;
+use vector::Vector2;
+
+/// The order in which the vertices of a polygon are visited.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Winding {
+    /// The vertices turn counter-clockwise, when the `y` axis points up.
+    CounterClockwise,
+    /// The vertices turn clockwise, when the `y` axis points up.
+    Clockwise,
+}
+
+/// The rule deciding which points are inside a polygon with overlapping or
+/// self-intersecting parts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FillRule {
+    /// Points are inside if a ray from the point crosses the outline an odd
+    /// number of times.
+    EvenOdd,
+    /// Points are inside if the outline winds around the point at least once.
+    NonZero,
+}
+
+/// Twice the signed area of the triangle `abc`, which is positive if the
+/// vertices turn counter-clockwise.
+#[inline]
+fn cross<S: BaseFloat>(a: Point2<S>, b: Point2<S>, c: Point2<S>) -> S {
+    (b - a).perp_dot(c - a)
+}
+
+/// The signed area of a polygon, which is positive if the vertices are in
+/// counter-clockwise order, and negative if they are in clockwise order.
+pub fn signed_area<S: BaseFloat>(points: &[Point2<S>]) -> S {
+    let n = points.len();
+    let twice_area = (0..n).fold(S::zero(), |sum, i| {
+        let (p, q) = (points[i], points[(i + 1) % n]);
+        sum + (p.x * q.y - q.x * p.y)
+    });
+    twice_area / cast(2).unwrap()
+}
+
+/// The winding order of a polygon, or `None` if it has no area.
+#[inline]
+pub fn winding<S: BaseFloat>(points: &[Point2<S>]) -> Option<Winding> {
+    let area = signed_area(points);
+    if area > S::zero() {
+        Some(Winding::CounterClockwise)
+    } else if area < S::zero() {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// The number of times the outline of a polygon winds counter-clockwise
+/// around `point`, which is negative if it winds clockwise.
+pub fn winding_number<S: BaseFloat>(points: &[Point2<S>], point: Point2<S>) -> i32 {
+    let n = points.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        if a.y <= point.y {
+            // An upward crossing with the point on the left of the edge
+            if b.y > point.y && cross(a, b, point) > S::zero() {
+                winding += 1;
+            }
+        } else if b.y <= point.y && cross(a, b, point) < S::zero() {
+            // A downward crossing with the point on the right of the edge
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Check if `point` is inside a polygon, according to the fill rule. Whether
+/// points on the outline are inside is unspecified.
+#[inline]
+pub fn contains<S: BaseFloat>(points: &[Point2<S>], point: Point2<S>, rule: FillRule) -> bool {
+    let winding = winding_number(points, point);
+    match rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0,
+    }
+}
+
+/// Check if a polygon is convex. Collinear vertices are allowed, but polygons
+/// with no area or self-intersections are not convex.
+pub fn is_convex<S: BaseFloat>(points: &[Point2<S>]) -> bool {
+    let n = points.len();
+    if n < 3 {
+        return false;
+    }
+
+    let mut sign = S::zero();
+    let mut direction_changes = 0;
+    let mut previous_dx = S::zero();
+    for i in 0..n {
+        let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+        let turn = cross(a, b, c);
+        if turn != S::zero() {
+            if sign == S::zero() {
+                sign = turn.signum();
+            } else if turn.signum() != sign {
+                return false;
+            }
+        }
+
+        // The edges of a convex polygon only change horizontal direction
+        // twice, which rules out self-intersecting polygons like stars
+        let dx = b.x - a.x;
+        if dx != S::zero() {
+            if previous_dx != S::zero() && dx.signum() != previous_dx.signum() {
+                direction_changes += 1;
+            }
+            previous_dx = dx;
+        }
+    }
+
+    // Count the change between the last and first edges with a horizontal
+    // component
+    let first_dx = (0..n)
+        .map(|i| points[(i + 1) % n].x - points[i].x)
+        .find(|dx| *dx != S::zero());
+    if let Some(first_dx) = first_dx {
+        if first_dx.signum() != previous_dx.signum() {
+            direction_changes += 1;
+        }
+    }
+
+    sign != S::zero() && direction_changes <= 2
+}
+
+/// The centroid of the area of a polygon, or `None` if it has no area.
+pub fn centroid<S: BaseFloat>(points: &[Point2<S>]) -> Option<Point2<S>> {
+    let area = signed_area(points);
+    if area == S::zero() {
+        return None;
+    }
+
+    // Sum the centroids of the triangles fanning out from the first vertex,
+    // weighted by their signed areas
+    let origin = points[0];
+    let sum = (1..points.len() - 1).fold(Vector2::zero(), |sum, i| {
+        let (b, c) = (points[i] - origin, points[i + 1] - origin);
+        sum + (b + c) * b.perp_dot(c)
+    });
+    let six: S = cast(6).unwrap();
+    Some(origin + sum / (six * area))
+}
+
+/// The convex hull of a set of points, computed with Andrew's monotone chain
+/// algorithm.
+///
+/// The hull is returned in counter-clockwise order, starting from the point
+/// with the smallest `x` coordinate, without any collinear points.
+#[cfg(feature = "alloc")]
+pub fn convex_hull<S: BaseFloat>(points: &[Point2<S>]) -> Vec<Point2<S>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Point2<S>> = Vec::with_capacity(sorted.len() * 2);
+
+    // The lower hull, from left to right
+    for &p in &sorted {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= S::zero() {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // The upper hull, from right to left
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= S::zero()
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // The last point is the first point again
+    hull.pop();
+    hull
+}
+
+/// Split a simple polygon into triangles by ear clipping, returning the
+/// indices of the vertices of each triangle.
+///
+/// The triangles have the same winding order as the polygon. The running
+/// time is quadratic in the number of vertices, so this is meant for the
+/// small polygons of user interfaces and editors rather than large meshes.
+/// Self-intersecting polygons produce overlapping triangles.
+///
+/// ```rust
+/// use cgmath::Point2;
+/// use cgmath::polygon;
+///
+/// let square = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(2.0, 0.0),
+///     Point2::new(2.0, 2.0),
+///     Point2::new(0.0, 2.0),
+/// ];
+///
+/// assert_eq!(polygon::triangulate(&square), vec![[3, 0, 1], [1, 2, 3]]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn triangulate<S: BaseFloat>(points: &[Point2<S>]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let mut triangles = Vec::with_capacity(n.saturating_sub(2));
+    if n < 3 {
+        return triangles;
+    }
+
+    // Clip ears from a counter-clockwise copy of the polygon
+    let clockwise = signed_area(points) < S::zero();
+    let mut remaining: Vec<usize> = if clockwise {
+        (0..n).rev().collect()
+    } else {
+        (0..n).collect()
+    };
+
+    let mut i = 0;
+    let mut attempts = 0;
+    while remaining.len() > 3 {
+        let m = remaining.len();
+        let (prev, curr, next) = (
+            remaining[(i + m - 1) % m],
+            remaining[i % m],
+            remaining[(i + 1) % m],
+        );
+
+        // Give up on finding ears when none are left, which only happens for
+        // degenerate or self-intersecting polygons
+        if is_ear(points, &remaining, prev, curr, next) || attempts >= m {
+            triangles.push([prev, curr, next]);
+            remaining.remove(i % m);
+            attempts = 0;
+        } else {
+            i += 1;
+            attempts += 1;
+        }
+        i %= remaining.len();
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+    if clockwise {
+        for triangle in &mut triangles {
+            triangle.swap(1, 2);
+        }
+    }
+    triangles
+}
+
+/// Check if the vertex `curr` is an ear of the counter-clockwise polygon made
+/// up of the `remaining` vertices.
+#[cfg(feature = "alloc")]
+fn is_ear<S: BaseFloat>(
+    points: &[Point2<S>],
+    remaining: &[usize],
+    prev: usize,
+    curr: usize,
+    next: usize,
+) -> bool {
+    let (a, b, c) = (points[prev], points[curr], points[next]);
+    if cross(a, b, c) <= S::zero() {
+        return false;
+    }
+
+    // No other vertex may be inside the triangle, or on its edges
+    remaining.iter().all(|&j| {
+        if j == prev || j == curr || j == next {
+            return true;
+        }
+        let p = points[j];
+        if p == a || p == b || p == c {
+            return true;
+        }
+        cross(a, b, p) < S::zero() || cross(b, c, p) < S::zero() || cross(c, a, p) < S::zero()
+    })
+}
@@ -0,0 +1,452 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Robust geometric predicates.
+//!
+//! Orientation and in-circle tests computed directly with floating point
+//! arithmetic can return the wrong sign when the input is nearly degenerate,
+//! for example when three points are almost collinear, which can make
+//! algorithms like triangulation fail. The predicates in this module always
+//! return the correct sign.
+//!
+//! They follow Jonathan Richard Shewchuk's "Adaptive Precision Floating-Point
+//! Arithmetic and Fast Robust Geometric Predicates": each determinant is first
+//! evaluated in ordinary floating point arithmetic together with a bound on
+//! its rounding error, and only when the result is too close to zero for its
+//! sign to be trusted is it evaluated again with exact arithmetic.
+//!
+//! The computations are done in `f64`, and other scalars such as `f32` are
+//! converted to `f64` first, which is exact.
+//!
+//! ```rust
+//! use cgmath::Point2;
+//! use cgmath::predicates::orient2d;
+//!
+//! let a = Point2::new(0.5, 0.5);
+//! let b = Point2::new(12.0, 12.0);
+//! let c = Point2::new(24.0, 24.0);
+//!
+//! // The points are exactly collinear
+//! assert_eq!(orient2d(a, b, c), 0.0);
+//!
+//! // Moving the last point by the smallest possible amount is detected
+//! let c = Point2::new(24.0, 24.000000000000004);
+//! assert!(orient2d(a, b, c) > 0.0);
+//! ```
+
+use alloc::vec::Vec;
+
+use point::{Point2, Point3};
+
+/// Half the distance between 1 and the next `f64`, which bounds the relative
+/// error of each rounded operation.
+const EPSILON: f64 = f64::EPSILON * 0.5;
+
+/// The constant used to split an `f64` into two halves with 26 significant
+/// bits each.
+const SPLITTER: f64 = 134_217_729.0;
+
+const CCW_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const O3D_ERROR_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const ICC_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const ISP_ERROR_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+
+/// Check the orientation of three points in the plane.
+///
+/// Returns a positive value if `a`, `b` and `c` are in counter-clockwise
+/// order, a negative value if they are in clockwise order, and zero if they
+/// are collinear. The value approximates twice the signed area of the
+/// triangle `abc`.
+pub fn orient2d<S: Copy + Into<f64>>(a: Point2<S>, b: Point2<S>, c: Point2<S>) -> f64 {
+    let (ax, ay) = (a.x.into(), a.y.into());
+    let (bx, by) = (b.x.into(), b.y.into());
+    let (cx, cy) = (c.x.into(), c.y.into());
+
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let det = left - right;
+
+    // When the two products have different signs, their difference can not
+    // cancel and the result is exact enough
+    let sum = if left > 0.0 {
+        if right <= 0.0 {
+            return det;
+        }
+        left + right
+    } else if left < 0.0 {
+        if right >= 0.0 {
+            return det;
+        }
+        -left - right
+    } else {
+        return det;
+    };
+
+    let bound = CCW_ERROR_BOUND * sum;
+    if det >= bound || -det >= bound {
+        return det;
+    }
+
+    let acx = Expansion::diff(ax, cx);
+    let acy = Expansion::diff(ay, cy);
+    let bcx = Expansion::diff(bx, cx);
+    let bcy = Expansion::diff(by, cy);
+    acx.mul(&bcy).sub(&acy.mul(&bcx)).estimate()
+}
+
+/// Check the orientation of four points in space.
+///
+/// Returns a positive value if `d` lies below the plane through `a`, `b` and
+/// `c`, where below is the side from which `a`, `b` and `c` appear in
+/// clockwise order. Returns a negative value if `d` lies above the plane, and
+/// zero if the points are coplanar. The value approximates six times the
+/// signed volume of the tetrahedron `abcd`.
+pub fn orient3d<S: Copy + Into<f64>>(
+    a: Point3<S>,
+    b: Point3<S>,
+    c: Point3<S>,
+    d: Point3<S>,
+) -> f64 {
+    let [a, b, c, d] = [to_f64(a), to_f64(b), to_f64(c), to_f64(d)];
+    let (adx, ady, adz) = (a[0] - d[0], a[1] - d[1], a[2] - d[2]);
+    let (bdx, bdy, bdz) = (b[0] - d[0], b[1] - d[1], b[2] - d[2]);
+    let (cdx, cdy, cdz) = (c[0] - d[0], c[1] - d[1], c[2] - d[2]);
+
+    let bdxcdy = bdx * cdy;
+    let cdxbdy = cdx * bdy;
+    let cdxady = cdx * ady;
+    let adxcdy = adx * cdy;
+    let adxbdy = adx * bdy;
+    let bdxady = bdx * ady;
+
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+        + (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+        + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+
+    let bound = O3D_ERROR_BOUND * permanent;
+    if det > bound || -det > bound {
+        return det;
+    }
+
+    let [adx, ady, adz] = diff3(a, d);
+    let [bdx, bdy, bdz] = diff3(b, d);
+    let [cdx, cdy, cdz] = diff3(c, d);
+    let bc = bdx.mul(&cdy).sub(&cdx.mul(&bdy));
+    let ca = cdx.mul(&ady).sub(&adx.mul(&cdy));
+    let ab = adx.mul(&bdy).sub(&bdx.mul(&ady));
+    adz.mul(&bc)
+        .add(&bdz.mul(&ca))
+        .add(&cdz.mul(&ab))
+        .estimate()
+}
+
+/// Check if a point lies inside the circle through three points.
+///
+/// Returns a positive value if `d` lies inside the circle through `a`, `b`
+/// and `c`, a negative value if it lies outside, and zero if the four points
+/// are cocircular. The points `a`, `b` and `c` must be in counter-clockwise
+/// order, or the sign of the result is reversed.
+pub fn incircle<S: Copy + Into<f64>>(
+    a: Point2<S>,
+    b: Point2<S>,
+    c: Point2<S>,
+    d: Point2<S>,
+) -> f64 {
+    let (ax, ay) = (a.x.into(), a.y.into());
+    let (bx, by) = (b.x.into(), b.y.into());
+    let (cx, cy) = (c.x.into(), c.y.into());
+    let (dx, dy) = (d.x.into(), d.y.into());
+
+    let (adx, ady) = (ax - dx, ay - dy);
+    let (bdx, bdy) = (bx - dx, by - dy);
+    let (cdx, cdy) = (cx - dx, cy - dy);
+
+    let bdxcdy = bdx * cdy;
+    let cdxbdy = cdx * bdy;
+    let alift = adx * adx + ady * ady;
+    let cdxady = cdx * ady;
+    let adxcdy = adx * cdy;
+    let blift = bdx * bdx + bdy * bdy;
+    let adxbdy = adx * bdy;
+    let bdxady = bdx * ady;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+
+    let bound = ICC_ERROR_BOUND * permanent;
+    if det > bound || -det > bound {
+        return det;
+    }
+
+    let (adx, ady) = (Expansion::diff(ax, dx), Expansion::diff(ay, dy));
+    let (bdx, bdy) = (Expansion::diff(bx, dx), Expansion::diff(by, dy));
+    let (cdx, cdy) = (Expansion::diff(cx, dx), Expansion::diff(cy, dy));
+    let alift = adx.mul(&adx).add(&ady.mul(&ady));
+    let blift = bdx.mul(&bdx).add(&bdy.mul(&bdy));
+    let clift = cdx.mul(&cdx).add(&cdy.mul(&cdy));
+    let bc = bdx.mul(&cdy).sub(&cdx.mul(&bdy));
+    let ca = cdx.mul(&ady).sub(&adx.mul(&cdy));
+    let ab = adx.mul(&bdy).sub(&bdx.mul(&ady));
+    alift
+        .mul(&bc)
+        .add(&blift.mul(&ca))
+        .add(&clift.mul(&ab))
+        .estimate()
+}
+
+/// Check if a point lies inside the sphere through four points.
+///
+/// Returns a positive value if `e` lies inside the sphere through `a`, `b`,
+/// `c` and `d`, a negative value if it lies outside, and zero if the five
+/// points are cospherical. The points `a`, `b`, `c` and `d` must be oriented
+/// so that `orient3d(a, b, c, d)` is positive, or the sign of the result is
+/// reversed.
+pub fn insphere<S: Copy + Into<f64>>(
+    a: Point3<S>,
+    b: Point3<S>,
+    c: Point3<S>,
+    d: Point3<S>,
+    e: Point3<S>,
+) -> f64 {
+    let [a, b, c, d, e] = [to_f64(a), to_f64(b), to_f64(c), to_f64(d), to_f64(e)];
+    let (aex, aey, aez) = (a[0] - e[0], a[1] - e[1], a[2] - e[2]);
+    let (bex, bey, bez) = (b[0] - e[0], b[1] - e[1], b[2] - e[2]);
+    let (cex, cey, cez) = (c[0] - e[0], c[1] - e[1], c[2] - e[2]);
+    let (dex, dey, dez) = (d[0] - e[0], d[1] - e[1], d[2] - e[2]);
+
+    let aexbey = aex * bey;
+    let bexaey = bex * aey;
+    let bexcey = bex * cey;
+    let cexbey = cex * bey;
+    let cexdey = cex * dey;
+    let dexcey = dex * cey;
+    let dexaey = dex * aey;
+    let aexdey = aex * dey;
+    let aexcey = aex * cey;
+    let cexaey = cex * aey;
+    let bexdey = bex * dey;
+    let dexbey = dex * bey;
+
+    let ab = aexbey - bexaey;
+    let bc = bexcey - cexbey;
+    let cd = cexdey - dexcey;
+    let da = dexaey - aexdey;
+    let ac = aexcey - cexaey;
+    let bd = bexdey - dexbey;
+
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (aez.abs(), bez.abs(), cez.abs(), dez.abs());
+    let (aexbey, bexaey) = (aexbey.abs(), bexaey.abs());
+    let (bexcey, cexbey) = (bexcey.abs(), cexbey.abs());
+    let (cexdey, dexcey) = (cexdey.abs(), dexcey.abs());
+    let (dexaey, aexdey) = (dexaey.abs(), aexdey.abs());
+    let (aexcey, cexaey) = (aexcey.abs(), cexaey.abs());
+    let (bexdey, dexbey) = (bexdey.abs(), dexbey.abs());
+    let permanent = ((cexdey + dexcey) * bez + (dexbey + bexdey) * cez + (bexcey + cexbey) * dez)
+        * alift
+        + ((dexaey + aexdey) * cez + (aexcey + cexaey) * dez + (cexdey + dexcey) * aez) * blift
+        + ((aexbey + bexaey) * dez + (bexdey + dexbey) * aez + (dexaey + aexdey) * bez) * clift
+        + ((bexcey + cexbey) * aez + (cexaey + aexcey) * bez + (aexbey + bexaey) * cez) * dlift;
+
+    let bound = ISP_ERROR_BOUND * permanent;
+    if det > bound || -det > bound {
+        return det;
+    }
+
+    let [aex, aey, aez] = diff3(a, e);
+    let [bex, bey, bez] = diff3(b, e);
+    let [cex, cey, cez] = diff3(c, e);
+    let [dex, dey, dez] = diff3(d, e);
+
+    let ab = aex.mul(&bey).sub(&bex.mul(&aey));
+    let bc = bex.mul(&cey).sub(&cex.mul(&bey));
+    let cd = cex.mul(&dey).sub(&dex.mul(&cey));
+    let da = dex.mul(&aey).sub(&aex.mul(&dey));
+    let ac = aex.mul(&cey).sub(&cex.mul(&aey));
+    let bd = bex.mul(&dey).sub(&dex.mul(&bey));
+
+    let abc = aez.mul(&bc).sub(&bez.mul(&ac)).add(&cez.mul(&ab));
+    let bcd = bez.mul(&cd).sub(&cez.mul(&bd)).add(&dez.mul(&bc));
+    let cda = cez.mul(&da).add(&dez.mul(&ac)).add(&aez.mul(&cd));
+    let dab = dez.mul(&ab).add(&aez.mul(&bd)).add(&bez.mul(&da));
+
+    let lift = |x: &Expansion, y: &Expansion, z: &Expansion| x.mul(x).add(&y.mul(y)).add(&z.mul(z));
+    let alift = lift(&aex, &aey, &aez);
+    let blift = lift(&bex, &bey, &bez);
+    let clift = lift(&cex, &cey, &cez);
+    let dlift = lift(&dex, &dey, &dez);
+
+    dlift
+        .mul(&abc)
+        .sub(&clift.mul(&dab))
+        .add(&blift.mul(&cda).sub(&alift.mul(&bcd)))
+        .estimate()
+}
+
+#[inline]
+fn to_f64<S: Copy + Into<f64>>(p: Point3<S>) -> [f64; 3] {
+    [p.x.into(), p.y.into(), p.z.into()]
+}
+
+#[inline]
+fn diff3(a: [f64; 3], b: [f64; 3]) -> [Expansion; 3] {
+    [
+        Expansion::diff(a[0], b[0]),
+        Expansion::diff(a[1], b[1]),
+        Expansion::diff(a[2], b[2]),
+    ]
+}
+
+/// Compute `a + b` exactly, as the rounded sum and its rounding error.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    let b_roundoff = b - b_virtual;
+    let a_roundoff = a - a_virtual;
+    (x, a_roundoff + b_roundoff)
+}
+
+/// Compute `a + b` exactly, assuming that `|a| >= |b|`.
+#[inline]
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    (x, b - b_virtual)
+}
+
+/// Split `a` into two non-overlapping halves with at most 26 significant bits
+/// each.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let a_big = c - a;
+    let hi = c - a_big;
+    (hi, a - hi)
+}
+
+/// Compute `a * b` exactly, as the rounded product and its rounding error.
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err1 = x - a_hi * b_hi;
+    let err2 = err1 - a_lo * b_hi;
+    let err3 = err2 - a_hi * b_lo;
+    (x, a_lo * b_lo - err3)
+}
+
+/// An exact number, represented as a sum of non-overlapping `f64` components
+/// in order of increasing magnitude, with zero components removed.
+#[derive(Clone, Debug)]
+struct Expansion(Vec<f64>);
+
+impl Expansion {
+    /// The exact difference `a - b`.
+    #[inline]
+    fn diff(a: f64, b: f64) -> Expansion {
+        let (x, y) = two_sum(a, -b);
+        Expansion([y, x].iter().cloned().filter(|c| *c != 0.0).collect())
+    }
+
+    /// Add a single `f64` to the expansion.
+    fn grow(&self, b: f64) -> Expansion {
+        let mut components = Vec::with_capacity(self.0.len() + 1);
+        let mut q = b;
+        for &e in &self.0 {
+            let (sum, error) = two_sum(q, e);
+            if error != 0.0 {
+                components.push(error);
+            }
+            q = sum;
+        }
+        if q != 0.0 {
+            components.push(q);
+        }
+        Expansion(components)
+    }
+
+    /// Multiply the expansion by a single `f64`.
+    fn scale(&self, b: f64) -> Expansion {
+        let mut components = Vec::with_capacity(self.0.len() * 2);
+        let mut iter = self.0.iter();
+        let mut q = match iter.next() {
+            Some(&e) => {
+                let (product, error) = two_product(e, b);
+                if error != 0.0 {
+                    components.push(error);
+                }
+                product
+            }
+            None => return Expansion(components),
+        };
+        for &e in iter {
+            let (product_hi, product_lo) = two_product(e, b);
+            let (sum, error) = two_sum(q, product_lo);
+            if error != 0.0 {
+                components.push(error);
+            }
+            let (sum, error) = fast_two_sum(product_hi, sum);
+            if error != 0.0 {
+                components.push(error);
+            }
+            q = sum;
+        }
+        if q != 0.0 {
+            components.push(q);
+        }
+        Expansion(components)
+    }
+
+    fn add(&self, other: &Expansion) -> Expansion {
+        other.0.iter().fold(self.clone(), |sum, &c| sum.grow(c))
+    }
+
+    fn sub(&self, other: &Expansion) -> Expansion {
+        other.0.iter().fold(self.clone(), |sum, &c| sum.grow(-c))
+    }
+
+    fn mul(&self, other: &Expansion) -> Expansion {
+        other
+            .0
+            .iter()
+            .fold(Expansion(Vec::new()), |sum, &c| sum.add(&self.scale(c)))
+    }
+
+    /// An approximation of the value, which has the same sign as the exact
+    /// value. This is the most significant component, which is larger than
+    /// the sum of all the others.
+    #[inline]
+    fn estimate(&self) -> f64 {
+        self.0.last().cloned().unwrap_or(0.0)
+    }
+}
@@ -4,6 +4,8 @@
 
 pub use structure::*;
 
+pub use batch::BatchTransform;
+
 pub use rotation::Rotation;
 pub use rotation::Rotation2;
 pub use rotation::Rotation3;
@@ -13,14 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use num_traits::cast;
 use num_traits::Zero;
 
-use structure::Angle;
+use structure::{Angle, InnerSpace, Matrix};
 
 use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
+use vector::{Vector2, Vector4};
 
 /// Create a perspective projection matrix.
 ///
@@ -76,6 +80,150 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     .into()
 }
 
+/// Modify a perspective projection matrix so that its near plane coincides
+/// with an arbitrary clip plane, using Eric Lengyel's [oblique near-plane
+/// clipping] technique. This is useful for rendering planar reflections and
+/// portals, where geometry behind the mirror or portal must be clipped away.
+///
+/// `proj` must be a perspective projection whose depth values are mapped to
+/// `depth`. `clip_plane` is given in view space as the coefficients `(a, b, c,
+/// d)` of the plane equation `a*x + b*y + c*z + d = 0`, with the plane facing
+/// away from the camera, ie. with the eye on its negative side (`d < 0`).
+/// Points on the plane are mapped to the near depth of `depth`, and only
+/// points on its positive side remain visible.
+///
+/// Note that the far plane is moved as a side effect, and depth precision is
+/// reduced as the clip plane becomes less parallel to the original near plane.
+///
+/// [oblique near-plane clipping]: http://www.terathon.com/lengyel/Lengyel-Oblique.pdf
+pub fn oblique_near_plane<S: BaseFloat>(
+    proj: Matrix4<S>,
+    clip_plane: Vector4<S>,
+    depth: DepthRange,
+) -> Matrix4<S> {
+    // The corner of the view frustum opposite to the clip plane, in view space.
+    let q = Vector4::new(
+        (clip_plane.x.signum() + proj.z.x) / proj.x.x,
+        (clip_plane.y.signum() + proj.z.y) / proj.y.y,
+        -S::one(),
+        (S::one() + proj.z.z) / proj.w.z,
+    );
+
+    let row = match depth {
+        DepthRange::NegativeOneToOne => {
+            let two: S = cast(2).unwrap();
+            clip_plane * (two / clip_plane.dot(q)) - proj.row(3)
+        }
+        DepthRange::ZeroToOne => clip_plane / clip_plane.dot(q),
+    };
+
+    let mut result = proj;
+    result.x.z = row.x;
+    result.y.z = row.y;
+    result.z.z = row.z;
+    result.w.z = row.w;
+    result
+}
+
+/// The range of normalized device depth that the near and far planes of a
+/// projection are mapped to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// The near plane is mapped to `-1` and the far plane to `1`. This is the
+    /// OpenGL convention, and the one used by the projections in this module.
+    NegativeOneToOne,
+    /// The near plane is mapped to `0` and the far plane to `1`. This is the
+    /// Direct3D, Metal and Vulkan convention.
+    ZeroToOne,
+}
+
+impl DepthRange {
+    /// Convert a projection matrix that maps depth to `[-1, 1]`, like the ones
+    /// created by this module, into one that maps depth to this range.
+    pub fn remap<S: BaseFloat>(self, proj: Matrix4<S>) -> Matrix4<S> {
+        match self {
+            DepthRange::NegativeOneToOne => proj,
+            DepthRange::ZeroToOne => {
+                let half: S = cast(0.5).unwrap();
+                let mut result = proj;
+                result.x.z = (proj.x.z + proj.x.w) * half;
+                result.y.z = (proj.y.z + proj.y.w) * half;
+                result.z.z = (proj.z.z + proj.z.w) * half;
+                result.w.z = (proj.w.z + proj.w.w) * half;
+                result
+            }
+        }
+    }
+}
+
+/// Compute the element at `index` of the [Halton sequence] with the given
+/// `base`, a low-discrepancy sequence in the range `[0, 1)`.
+///
+/// The sequence starts at `index = 1`, as the element at `index = 0` is always
+/// zero.
+///
+/// ```rust
+/// use cgmath::halton;
+///
+/// assert_eq!(halton::<f32>(1, 2), 0.5);
+/// assert_eq!(halton::<f32>(2, 2), 0.25);
+/// assert_eq!(halton::<f32>(3, 2), 0.75);
+/// ```
+///
+/// [Halton sequence]: https://en.wikipedia.org/wiki/Halton_sequence
+pub fn halton<S: BaseFloat>(index: u32, base: u32) -> S {
+    assert!(base >= 2, "The base must be at least 2, found: {:?}", base);
+
+    let inv_base = S::one() / cast(base).unwrap();
+    let mut fraction = S::one();
+    let mut result = S::zero();
+    let mut i = index;
+    while i > 0 {
+        fraction *= inv_base;
+        result += fraction * cast(i % base).unwrap();
+        i /= base;
+    }
+    result
+}
+
+/// A low-discrepancy sequence of sub-pixel offsets, used to jitter a
+/// projection for temporal anti-aliasing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JitterSequence {
+    /// The Halton sequence, using base 2 for `x` and base 3 for `y`.
+    Halton23,
+    /// Martin Roberts' [R2 sequence], based on the plastic number.
+    ///
+    /// [R2 sequence]: http://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences/
+    R2,
+}
+
+impl JitterSequence {
+    /// Get the offset at `index` in the sequence, in pixels. Each component is
+    /// in the range `[-0.5, 0.5)`, so it can be passed directly to
+    /// `Perspective::jitter`.
+    pub fn sample<S: BaseFloat>(self, index: u32) -> Vector2<S> {
+        let half: S = cast(0.5).unwrap();
+        match self {
+            JitterSequence::Halton23 => {
+                // Skip the first Halton point, which is always zero
+                let i = index.wrapping_add(1);
+                Vector2::new(halton::<S>(i, 2) - half, halton::<S>(i, 3) - half)
+            }
+            JitterSequence::R2 => {
+                // The plastic number, the unique real root of x^3 = x + 1
+                let g: S = cast(1.324_717_957_244_746_f64).unwrap();
+                let n: S = cast(index).unwrap();
+                let x = (half + n / g).fract();
+                let y = (half + n / (g * g)).fract();
+                Vector2::new(x - half, y - half)
+            }
+        }
+    }
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
@@ -103,6 +251,24 @@ impl<S: BaseFloat> PerspectiveFov<S> {
             far: self.far,
         }
     }
+
+    /// Create a projection matrix for the given depth range, with the near
+    /// plane replaced by the view space `clip_plane`.
+    ///
+    /// See [`oblique_near_plane`] for the requirements on `clip_plane`.
+    ///
+    /// [`oblique_near_plane`]: fn.oblique_near_plane.html
+    pub fn to_oblique(&self, clip_plane: Vector4<S>, depth: DepthRange) -> Matrix4<S> {
+        oblique_near_plane(depth.remap((*self).into()), clip_plane, depth)
+    }
+
+    /// Shift the projected image by a sub-pixel `offset`, for a viewport of
+    /// `viewport` pixels.
+    ///
+    /// See `Perspective::jitter` for details.
+    pub fn jitter(&self, offset: Vector2<S>, viewport: Vector2<S>) -> Perspective<S> {
+        self.to_perspective().jitter(offset, viewport)
+    }
 }
 
 impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
@@ -185,6 +351,105 @@ pub struct Perspective<S> {
     pub far: S,
 }
 
+impl<S: BaseFloat> Perspective<S> {
+    /// Shift the projected image by a sub-pixel `offset`, for a viewport of
+    /// `viewport` pixels.
+    ///
+    /// The offset is given in pixels, with `x` pointing right and `y` pointing
+    /// up, as in normalized device coordinates. The frustum is sheared rather
+    /// than rotated, so the near and far planes stay the same.
+    ///
+    /// ```rust
+    /// use cgmath::{JitterSequence, PerspectiveFov, Deg, Vector2};
+    ///
+    /// let persp = PerspectiveFov { fovy: Deg(60.0).into(), aspect: 1.5, near: 0.1, far: 100.0 };
+    /// let viewport = Vector2::new(1920.0, 1280.0);
+    ///
+    /// for frame in 0..8 {
+    ///     let offset = JitterSequence::Halton23.sample(frame);
+    ///     let jittered = persp.jitter(offset, viewport);
+    /// }
+    /// ```
+    pub fn jitter(&self, offset: Vector2<S>, viewport: Vector2<S>) -> Perspective<S> {
+        let dx = offset.x * (self.right - self.left) / viewport.x;
+        let dy = offset.y * (self.top - self.bottom) / viewport.y;
+
+        Perspective {
+            left: self.left - dx,
+            right: self.right - dx,
+            bottom: self.bottom - dy,
+            top: self.top - dy,
+            near: self.near,
+            far: self.far,
+        }
+    }
+
+    /// Get the sub-frustum covering a single tile of the image, when it is split
+    /// into a grid of `columns` by `rows` equally sized tiles.
+    ///
+    /// Tiles are indexed from the top left corner of the image, so that they
+    /// can be rendered separately and stitched together into a larger image
+    /// in scanline order.
+    pub fn tile(&self, columns: usize, rows: usize, column: usize, row: usize) -> Perspective<S> {
+        assert!(
+            column < columns,
+            "The tile column is out of range, found: {:?} of {:?}",
+            column,
+            columns
+        );
+        assert!(
+            row < rows,
+            "The tile row is out of range, found: {:?} of {:?}",
+            row,
+            rows
+        );
+
+        let columns: S = cast(columns).unwrap();
+        let rows: S = cast(rows).unwrap();
+        let column: S = cast(column).unwrap();
+        let row: S = cast(row).unwrap();
+
+        let width = (self.right - self.left) / columns;
+        let height = (self.top - self.bottom) / rows;
+        let left = self.left + width * column;
+        let top = self.top - height * row;
+
+        Perspective {
+            left,
+            right: left + width,
+            bottom: top - height,
+            top,
+            near: self.near,
+            far: self.far,
+        }
+    }
+
+    /// Split the frustum into a grid of `columns` by `rows` sub-frustums.
+    ///
+    /// The tiles are returned in row-major order, starting at the top left
+    /// corner of the image. See `Perspective::tile` for details.
+    #[cfg(feature = "alloc")]
+    pub fn tiles(&self, columns: usize, rows: usize) -> Vec<Perspective<S>> {
+        let mut tiles = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for column in 0..columns {
+                tiles.push(self.tile(columns, rows, column, row));
+            }
+        }
+        tiles
+    }
+
+    /// Create a projection matrix for the given depth range, with the near
+    /// plane replaced by the view space `clip_plane`.
+    ///
+    /// See [`oblique_near_plane`] for the requirements on `clip_plane`.
+    ///
+    /// [`oblique_near_plane`]: fn.oblique_near_plane.html
+    pub fn to_oblique(&self, clip_plane: Vector4<S>, depth: DepthRange) -> Matrix4<S> {
+        oblique_near_plane(depth.remap((*self).into()), clip_plane, depth)
+    }
+}
+
 impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
     fn from(persp: Perspective<S>) -> Matrix4<S> {
         assert!(
@@ -13,8 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::iter;
-use std::ops::*;
+use core::iter;
+use core::ops::*;
 
 use num_traits::{cast, NumCast};
 #[cfg(feature = "rand")]
@@ -33,6 +33,7 @@ use num::BaseFloat;
 use point::Point3;
 use quaternion;
 use rotation::{Basis3, Rotation, Rotation3};
+use simd;
 use vector::Vector3;
 
 #[cfg(feature = "mint")]
@@ -239,9 +240,9 @@ impl<S: NumCast + Copy> Quaternion<S> {
 
 impl<S: BaseFloat> InnerSpace for Quaternion<S> {
     #[inline]
-    default_fn!( dot(self, other: Quaternion<S>) -> S {
+    fn dot(self, other: Quaternion<S>) -> S {
         self.s * other.s + self.v.dot(other.v)
-    } );
+    }
 }
 
 impl<A> From<Euler<A>> for Quaternion<A::Unitless>
@@ -335,12 +336,14 @@ impl_assignment_operator!(<S: BaseFloat> SubAssign<Quaternion<S> > for Quaternio
 
 impl_operator!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
     fn mul(lhs, rhs) -> Quaternion<S> {
-        Quaternion::new(
-            lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
-            lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
-            lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
-            lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x,
-        )
+        simd::binary(&lhs, &rhs, simd::quaternion_mul).unwrap_or_else(|| {
+            Quaternion::new(
+                lhs.s * rhs.s - lhs.v.x * rhs.v.x - lhs.v.y * rhs.v.y - lhs.v.z * rhs.v.z,
+                lhs.s * rhs.v.x + lhs.v.x * rhs.s + lhs.v.y * rhs.v.z - lhs.v.z * rhs.v.y,
+                lhs.s * rhs.v.y + lhs.v.y * rhs.s + lhs.v.z * rhs.v.x - lhs.v.x * rhs.v.z,
+                lhs.s * rhs.v.z + lhs.v.z * rhs.s + lhs.v.x * rhs.v.y - lhs.v.y * rhs.v.x,
+            )
+        })
     }
 });
 
@@ -520,7 +523,8 @@ impl<S: BaseFloat> Rotation for Quaternion<S> {
 
     #[inline]
     fn invert(&self) -> Quaternion<S> {
-        self.conjugate() / self.magnitude2()
+        simd::unary(self, simd::quaternion_invert)
+            .unwrap_or_else(|| self.conjugate() / self.magnitude2())
     }
 }
 
@@ -685,6 +689,9 @@ impl<S: Clone> From<Quaternion<S>> for mint::Quaternion<S> {
     }
 }
 
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Quaternion);
+
 #[cfg(test)]
 mod tests {
     use quaternion::*;
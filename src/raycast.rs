@@ -0,0 +1,237 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ray intersection queries.
+
+use structure::*;
+
+use aabb::Aabb3;
+use line::Ray3;
+use num::BaseFloat;
+use obb::Obb3;
+use plane::{Disc, Plane};
+use point::Point3;
+use rotation::Rotation;
+use sphere::Sphere;
+use triangle::Triangle;
+use vector::Vector3;
+
+/// The point where a ray hits a shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RayHit<S> {
+    /// The parameter of the hit along the ray, such that `ray.at(distance)` is
+    /// the hit point. This is the distance from the origin of the ray if its
+    /// direction is normalized.
+    pub distance: S,
+    /// The point where the ray hits the surface.
+    pub point: Point3<S>,
+    /// The unit normal of the surface at the hit point, pointing out of the
+    /// shape, or towards the positive side of planes and triangles.
+    pub normal: Vector3<S>,
+    /// The barycentric coordinates of the hit point, for hits on triangles.
+    pub barycentric: Option<Vector3<S>>,
+}
+
+impl<S: BaseFloat> RayHit<S> {
+    #[inline]
+    fn new(ray: &Ray3<S>, distance: S, normal: Vector3<S>) -> RayHit<S> {
+        RayHit {
+            distance,
+            point: ray.at(distance),
+            normal,
+            barycentric: None,
+        }
+    }
+}
+
+/// Shapes that can be intersected with a ray.
+pub trait RayIntersect<S: BaseFloat> {
+    /// Find the first point where the ray hits the surface of the shape, if
+    /// any. Hits behind the origin of the ray are ignored, so a ray starting
+    /// inside a solid shape hits the surface where it leaves the shape.
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>>;
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Plane<S> {
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        let denominator = self.normal.dot(ray.direction);
+        if denominator == S::zero() {
+            return None;
+        }
+
+        let t = -self.signed_distance(ray.origin) / denominator;
+        if t >= S::zero() {
+            Some(RayHit::new(ray, t, self.normal))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Disc<S> {
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        self.plane()
+            .intersect_ray(ray)
+            .filter(|hit| hit.point.distance2(self.center) <= self.radius * self.radius)
+    }
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Sphere<S> {
+    /// Find the first point where the ray hits the sphere, using
+    /// `Sphere::ray_distance`.
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        self.ray_distance(ray).map(|t| {
+            let point = ray.at(t);
+            RayHit::new(ray, t, (point - self.center).normalize())
+        })
+    }
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Aabb3<S> {
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        let mut t_enter = S::neg_infinity();
+        let mut t_exit = S::infinity();
+        let mut enter_normal = Vector3::zero();
+        let mut exit_normal = Vector3::zero();
+
+        for i in 0..3 {
+            let (origin, direction) = (ray.origin[i], ray.direction[i]);
+            if direction == S::zero() {
+                // The ray is parallel to the slab, and misses it if it starts
+                // outside of it
+                if origin < self.min[i] || origin > self.max[i] {
+                    return None;
+                }
+                continue;
+            }
+
+            let mut axis = Vector3::zero();
+            axis[i] = S::one();
+            let inverse = S::one() / direction;
+            let (mut near, mut far) = (
+                (self.min[i] - origin) * inverse,
+                (self.max[i] - origin) * inverse,
+            );
+            let (mut near_normal, mut far_normal) = (-axis, axis);
+            if near > far {
+                ::core::mem::swap(&mut near, &mut far);
+                ::core::mem::swap(&mut near_normal, &mut far_normal);
+            }
+
+            if near > t_enter {
+                t_enter = near;
+                enter_normal = near_normal;
+            }
+            if far < t_exit {
+                t_exit = far;
+                exit_normal = far_normal;
+            }
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        if t_enter >= S::zero() {
+            Some(RayHit::new(ray, t_enter, enter_normal))
+        } else if t_exit >= S::zero() {
+            Some(RayHit::new(ray, t_exit, exit_normal))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Obb3<S> {
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        // Intersect in the local space of the box, where it is axis-aligned
+        let inverse = self.orientation.invert();
+        let local_ray = Ray3::new(
+            Point3::from_vec(inverse.rotate_vector(ray.origin - self.center)),
+            inverse.rotate_vector(ray.direction),
+        );
+        let local_box = Aabb3::new(
+            Point3::from_vec(-self.half_extents),
+            Point3::from_vec(self.half_extents),
+        );
+
+        local_box.intersect_ray(&local_ray).map(|hit| {
+            RayHit::new(
+                ray,
+                hit.distance,
+                self.orientation.rotate_vector(hit.normal),
+            )
+        })
+    }
+}
+
+impl<S: BaseFloat> RayIntersect<S> for Triangle<Point3<S>> {
+    /// Find where a ray hits the triangle from either side. Use
+    /// `intersect_ray_triangle` to ignore hits on back faces.
+    #[inline]
+    fn intersect_ray(&self, ray: &Ray3<S>) -> Option<RayHit<S>> {
+        intersect_ray_triangle(ray, self.a, self.b, self.c, false)
+    }
+}
+
+/// Find where a ray hits the triangle `abc`, using the Möller–Trumbore
+/// algorithm.
+///
+/// The normal of the hit points towards the side from which the vertices
+/// appear in counter-clockwise order. If `cull_back_faces` is set, rays
+/// hitting the other side of the triangle are ignored. The barycentric
+/// coordinates of the hit are the weights of `a`, `b` and `c` respectively.
+pub fn intersect_ray_triangle<S: BaseFloat>(
+    ray: &Ray3<S>,
+    a: Point3<S>,
+    b: Point3<S>,
+    c: Point3<S>,
+    cull_back_faces: bool,
+) -> Option<RayHit<S>> {
+    let e1 = b - a;
+    let e2 = c - a;
+    let p = ray.direction.cross(e2);
+    let determinant = e1.dot(p);
+
+    // The ray is parallel to the triangle, or hits its back face
+    if determinant == S::zero() || (cull_back_faces && determinant < S::zero()) {
+        return None;
+    }
+
+    let inverse = S::one() / determinant;
+    let s = ray.origin - a;
+    let u = s.dot(p) * inverse;
+    if u < S::zero() || u > S::one() {
+        return None;
+    }
+
+    let q = s.cross(e1);
+    let v = ray.direction.dot(q) * inverse;
+    if v < S::zero() || u + v > S::one() {
+        return None;
+    }
+
+    let t = e2.dot(q) * inverse;
+    if t < S::zero() {
+        return None;
+    }
+
+    Some(RayHit {
+        distance: t,
+        point: ray.at(t),
+        normal: e1.cross(e2).normalize(),
+        barycentric: Some(Vector3::new(S::one() - u - v, u, v)),
+    })
+}
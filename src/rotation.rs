@@ -13,9 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::iter;
-use std::ops::*;
+use core::fmt;
+use core::iter;
+use core::ops::*;
 
 use structure::*;
 
@@ -0,0 +1,299 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for cascaded shadow maps.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use angle::Rad;
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use projection::{Ortho, PerspectiveFov};
+use transform::Transform;
+
+/// How `PerspectiveFov::cascades` fits a light space projection to each
+/// cascade.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CascadeFit<S> {
+    /// Fit a square projection around the bounding sphere of the cascade,
+    /// snapped to the texels of a shadow map with the given resolution, using
+    /// `Ortho::fit_stable`. The shadow does not shimmer as the camera moves or
+    /// rotates.
+    Stable(S),
+    /// Fit the tightest projection around the cascade, using `Ortho::fit`.
+    /// This makes better use of the shadow map, but the shadow shimmers as
+    /// the camera rotates.
+    Tight,
+}
+
+/// A single cascade of a cascaded shadow map, covering the part of the view
+/// frustum between the `near` and `far` distances.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cascade<S> {
+    /// The distance from the camera to the start of the cascade.
+    pub near: S,
+    /// The distance from the camera to the end of the cascade.
+    pub far: S,
+    /// The world space corners of the cascade's slice of the view frustum, in
+    /// the order returned by `PerspectiveFov::frustum_corners`.
+    pub corners: [Point3<S>; 8],
+    /// The light space projection that encloses the cascade.
+    pub projection: Ortho<S>,
+}
+
+impl<S: BaseFloat> PerspectiveFov<S> {
+    /// Split the depth range of the frustum into `count` cascades, using the
+    /// [practical split scheme] that blends logarithmic and uniform splits.
+    ///
+    /// A `lambda` of `0` yields uniformly spaced splits, and a `lambda` of `1`
+    /// yields logarithmically spaced splits. The returned vector holds the
+    /// `count + 1` distances separating the cascades, starting with `near` and
+    /// ending with `far`.
+    ///
+    /// ```rust
+    /// use cgmath::{Deg, PerspectiveFov};
+    ///
+    /// let persp = PerspectiveFov { fovy: Deg(60.0).into(), aspect: 1.5, near: 0.1, far: 100.0 };
+    /// let splits = persp.cascade_splits(4, 0.75);
+    ///
+    /// assert_eq!(splits.len(), 5);
+    /// assert_eq!(splits[0], 0.1);
+    /// assert_eq!(splits[4], 100.0);
+    /// ```
+    ///
+    /// [practical split scheme]: https://developer.nvidia.com/gpugems/gpugems3/part-ii-light-and-shadows/chapter-10-parallel-split-shadow-maps-programmable-gpus
+    #[cfg(feature = "alloc")]
+    pub fn cascade_splits(&self, count: usize, lambda: S) -> Vec<S> {
+        assert!(count > 0, "The cascade count cannot be zero");
+
+        let n: S = cast(count).unwrap();
+        let ratio = self.far / self.near;
+        let mut splits = Vec::with_capacity(count + 1);
+        splits.push(self.near);
+        for i in 1..count {
+            let t = cast::<_, S>(i).unwrap() / n;
+            let log = self.near * ratio.powf(t);
+            let uniform = self.near + (self.far - self.near) * t;
+            splits.push(lambda * log + (S::one() - lambda) * uniform);
+        }
+        splits.push(self.far);
+        splits
+    }
+
+    /// Compute the corners of the slice of the frustum between the `near` and
+    /// `far` distances, transformed into world space by `camera`, the
+    /// camera's view space to world space transformation (ie. the inverse of
+    /// its view matrix).
+    ///
+    /// The four corners at the `near` distance are returned first, followed by
+    /// the four corners at the `far` distance. Each set of four is ordered
+    /// bottom left, bottom right, top right, top left.
+    pub fn frustum_corners<T: Transform<Point3<S>>>(
+        &self,
+        near: S,
+        far: S,
+        camera: &T,
+    ) -> [Point3<S>; 8] {
+        let two: S = cast(2).unwrap();
+        let tan_y = Rad::tan(self.fovy / two);
+        let tan_x = tan_y * self.aspect;
+
+        let corner = |x: S, y: S, d: S| camera.transform_point(Point3::new(x * d, y * d, -d));
+
+        [
+            corner(-tan_x, -tan_y, near),
+            corner(tan_x, -tan_y, near),
+            corner(tan_x, tan_y, near),
+            corner(-tan_x, tan_y, near),
+            corner(-tan_x, -tan_y, far),
+            corner(tan_x, -tan_y, far),
+            corner(tan_x, tan_y, far),
+            corner(-tan_x, tan_y, far),
+        ]
+    }
+
+    /// Split the frustum into `count` cascades using `cascade_splits`, and fit
+    /// a light space projection to each of them.
+    ///
+    /// `camera` is the camera's view space to world space transformation, and
+    /// `light_view` is the world space to light space transformation of the
+    /// directional light, for example created with `Matrix4::look_to_rh`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::{CascadeFit, Deg, Matrix4, PerspectiveFov, Point3, Vector3};
+    ///
+    /// let persp = PerspectiveFov { fovy: Deg(60.0).into(), aspect: 1.5, near: 0.1, far: 100.0 };
+    /// let view = Matrix4::look_at_rh(Point3::new(0.0, 2.0, 5.0), Point3::origin(), Vector3::unit_y());
+    /// let light_view = Matrix4::look_to_rh(Point3::origin(), Vector3::new(-1.0, -2.0, 0.5), Vector3::unit_y());
+    ///
+    /// let cascades = persp.cascades(4, 0.75, &view.invert().unwrap(), &light_view, CascadeFit::Stable(2048.0));
+    /// assert_eq!(cascades.len(), 4);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn cascades<T: Transform<Point3<S>>>(
+        &self,
+        count: usize,
+        lambda: S,
+        camera: &T,
+        light_view: &Matrix4<S>,
+        fit: CascadeFit<S>,
+    ) -> Vec<Cascade<S>> {
+        let splits = self.cascade_splits(count, lambda);
+        splits
+            .windows(2)
+            .map(|split| {
+                let corners = self.frustum_corners(split[0], split[1], camera);
+                let projection = match fit {
+                    CascadeFit::Stable(resolution) => {
+                        Ortho::fit_stable(&corners, light_view, resolution)
+                    }
+                    CascadeFit::Tight => Ortho::fit(&corners, light_view),
+                };
+                Cascade {
+                    near: split[0],
+                    far: split[1],
+                    corners,
+                    projection,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<S: BaseFloat> Ortho<S> {
+    /// Create the tightest orthographic projection that encloses `points`,
+    /// after transforming them from world space into the light space of
+    /// `light_view`.
+    ///
+    /// The near and far planes are fitted to the points too. Shadow casters
+    /// between the light and the points will be clipped, so the near plane may
+    /// need to be pulled back towards the light to include them.
+    pub fn fit(points: &[Point3<S>], light_view: &Matrix4<S>) -> Ortho<S> {
+        assert!(!points.is_empty(), "Cannot fit a projection to zero points");
+
+        let first = light_view.transform_point(points[0]);
+        let (min, max) = points[1..]
+            .iter()
+            .map(|&p| light_view.transform_point(p))
+            .fold((first, first), |(min, max), p| {
+                (min.zip(p, Float::min), max.zip(p, Float::max))
+            });
+
+        // The view direction is along the negative z axis.
+        Ortho {
+            left: min.x,
+            right: max.x,
+            bottom: min.y,
+            top: max.y,
+            near: -max.z,
+            far: -min.z,
+        }
+    }
+
+    /// Create a square orthographic projection that encloses the bounding
+    /// sphere of `points`, and whose edges fall on texel boundaries of a
+    /// `resolution` by `resolution` shadow map, in the light space of
+    /// `light_view`.
+    ///
+    /// The size of the projection only depends on the distances between the
+    /// points, and its position moves in whole texels, so a shadow fitted to
+    /// the corners of a cascade does not shimmer as the camera moves or
+    /// rotates. The radius of the sphere is rounded up to a step of about one
+    /// texel, to keep floating point error in the corners from changing the
+    /// size from frame to frame. The projection is one texel wider than the
+    /// sphere, so that it still encloses the sphere after its edges are moved
+    /// onto texel boundaries.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::{Matrix4, Ortho, Point3};
+    ///
+    /// let points = [Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+    /// let ortho = Ortho::fit_stable(&points, &Matrix4::identity(), 1024.0);
+    ///
+    /// assert_eq!(ortho.right - ortho.left, ortho.top - ortho.bottom);
+    /// assert!(ortho.left <= -1.0 && ortho.right >= 1.0);
+    /// assert!(ortho.bottom <= -1.0 && ortho.top >= 1.0);
+    /// ```
+    pub fn fit_stable(points: &[Point3<S>], light_view: &Matrix4<S>, resolution: S) -> Ortho<S> {
+        assert!(!points.is_empty(), "Cannot fit a projection to zero points");
+
+        let center = Point3::centroid(points);
+        let radius = points
+            .iter()
+            .map(|&p| p.distance2(center))
+            .fold(S::zero(), Float::max)
+            .sqrt();
+
+        // Round up to a multiple of the largest power of two not above the
+        // radius, divided by the resolution. Both neighbouring steps divide
+        // the power of two itself, so the rounding agrees across the switch.
+        let step = radius.log2().floor().exp2() / resolution;
+        let radius = if step > S::zero() {
+            (radius / step).ceil() * step
+        } else {
+            radius
+        };
+
+        let two: S = cast(2).unwrap();
+        let texel = two * radius / (resolution - S::one());
+        let size = texel * resolution;
+        let center = light_view.transform_point(center);
+        let left = ((center.x - radius) / texel).floor() * texel;
+        let bottom = ((center.y - radius) / texel).floor() * texel;
+
+        // The view direction is along the negative z axis.
+        Ortho {
+            left,
+            right: left + size,
+            bottom,
+            top: bottom + size,
+            near: -center.z - radius,
+            far: -center.z + radius,
+        }
+    }
+
+    /// Move the projection so that its edges fall on texel boundaries of a
+    /// shadow map of `width` by `height` texels, keeping its size intact.
+    ///
+    /// This prevents the shadow from shimmering as the camera moves, as long as
+    /// the size of the projection stays the same from frame to frame. Use
+    /// `Ortho::fit_stable` for a projection whose size doesn't change as the
+    /// camera rotates.
+    pub fn snap_to_texels(&self, width: S, height: S) -> Ortho<S> {
+        let texel_x = (self.right - self.left) / width;
+        let texel_y = (self.top - self.bottom) / height;
+        let left = (self.left / texel_x).floor() * texel_x;
+        let bottom = (self.bottom / texel_y).floor() * texel_y;
+
+        Ortho {
+            left,
+            right: left + (self.right - self.left),
+            bottom,
+            top: bottom + (self.top - self.bottom),
+            near: self.near,
+            far: self.far,
+        }
+    }
+}
@@ -0,0 +1,585 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SIMD implementations of `Vector4<f32>`, `Quaternion<f32>` and
+//! `Matrix4<f32>` operations, enabled with the `simd` feature.
+//!
+//! The generic operator implementations call `unary` and `binary`, which
+//! check whether their arguments have the `f32` types. The checks compare type
+//! ids that are known after monomorphization, so they compile down to either a
+//! direct call of the SIMD code or nothing at all.
+//!
+//! The lanes are evaluated in the same order as the generic code, so the
+//! results are bit-identical, except for `Matrix4::invert` which uses a
+//! different algorithm.
+
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+
+use matrix::Matrix4;
+use quaternion::Quaternion;
+use vector::Vector4;
+
+/// The `TypeId` of `T`, ignoring its lifetimes, so that `T` does not need to
+/// be `'static`.
+///
+/// Comparing this against the id of a type without lifetimes, such as
+/// `Vector4<f32>`, tells whether `T` is that type.
+#[inline(always)]
+fn type_id<T: ?Sized>() -> TypeId {
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = PhantomData::<T>;
+    let any: &dyn NonStaticAny = &phantom;
+    // Type ids don't depend on lifetimes, so erasing them doesn't change the id
+    let any: &(dyn NonStaticAny + 'static) = unsafe { mem::transmute(any) };
+    any.type_id()
+}
+
+/// Read `a` as a `U`, if `T` is `U` or a reference to `U`.
+///
+/// Accepting references lets the by-reference operators pass their operands
+/// without copying them first.
+#[inline(always)]
+fn cast<T, U: Copy + 'static>(a: &T) -> Option<U> {
+    if type_id::<T>() == TypeId::of::<U>() {
+        Some(unsafe { ptr::read(a as *const T as *const U) })
+    } else if type_id::<T>() == TypeId::of::<&U>() {
+        Some(unsafe { *ptr::read(a as *const T as *const &U) })
+    } else {
+        None
+    }
+}
+
+/// Apply `f` to `a` if its type is the one `f` expects, returning `None`
+/// otherwise, or when the `simd` feature is disabled.
+#[inline(always)]
+pub fn unary<A, R, A32, R32>(a: &A, f: fn(A32) -> R32) -> Option<R>
+where
+    A32: Copy + 'static,
+    R32: Copy + 'static,
+{
+    if !cfg!(feature = "simd") || type_id::<R>() != TypeId::of::<R32>() {
+        return None;
+    }
+    let a = cast::<A, A32>(a)?;
+    Some(unsafe { mem::transmute_copy::<R32, R>(&f(a)) })
+}
+
+/// Apply `f` to `a` and `b` if their types are the ones `f` expects, returning
+/// `None` otherwise, or when the `simd` feature is disabled.
+#[inline(always)]
+pub fn binary<A, B, R, A32, B32, R32>(a: &A, b: &B, f: fn(A32, B32) -> R32) -> Option<R>
+where
+    A32: Copy + 'static,
+    B32: Copy + 'static,
+    R32: Copy + 'static,
+{
+    if !cfg!(feature = "simd") || type_id::<R>() != TypeId::of::<R32>() {
+        return None;
+    }
+    let (a, b) = (cast::<A, A32>(a)?, cast::<B, B32>(b)?);
+    Some(unsafe { mem::transmute_copy::<R32, R>(&f(a, b)) })
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[macro_use]
+mod imp {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    #[derive(Copy, Clone)]
+    pub struct F32x4(pub __m128);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn load(a: [f32; 4]) -> F32x4 {
+            unsafe { F32x4(_mm_loadu_ps(a.as_ptr())) }
+        }
+
+        #[inline(always)]
+        pub fn store(self) -> [f32; 4] {
+            let mut out = [0.0; 4];
+            unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) };
+            out
+        }
+
+        #[inline(always)]
+        pub fn splat(x: f32) -> F32x4 {
+            unsafe { F32x4(_mm_set1_ps(x)) }
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(_mm_add_ps(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(_mm_sub_ps(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(_mm_mul_ps(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn div(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(_mm_div_ps(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn neg(self) -> F32x4 {
+            unsafe { F32x4(_mm_xor_ps(self.0, _mm_set1_ps(-0.0))) }
+        }
+    }
+
+    /// Select lanes `i` and `j` of `a`, followed by lanes `k` and `l` of `b`.
+    macro_rules! shuffle {
+        ($a:expr, $b:expr, [$i:expr, $j:expr, $k:expr, $l:expr]) => {
+            imp::F32x4(imp::shuffle::<{ $i | ($j << 2) | ($k << 4) | ($l << 6) }>(
+                $a.0, $b.0,
+            ))
+        };
+    }
+
+    #[inline(always)]
+    pub fn shuffle<const MASK: i32>(a: __m128, b: __m128) -> __m128 {
+        unsafe { _mm_shuffle_ps::<MASK>(a, b) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[macro_use]
+mod imp {
+    use core::arch::aarch64::*;
+
+    #[derive(Copy, Clone)]
+    pub struct F32x4(pub float32x4_t);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn load(a: [f32; 4]) -> F32x4 {
+            unsafe { F32x4(vld1q_f32(a.as_ptr())) }
+        }
+
+        #[inline(always)]
+        pub fn store(self) -> [f32; 4] {
+            let mut out = [0.0; 4];
+            unsafe { vst1q_f32(out.as_mut_ptr(), self.0) };
+            out
+        }
+
+        #[inline(always)]
+        pub fn splat(x: f32) -> F32x4 {
+            unsafe { F32x4(vdupq_n_f32(x)) }
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(vaddq_f32(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(vsubq_f32(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(vmulq_f32(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn div(self, other: F32x4) -> F32x4 {
+            unsafe { F32x4(vdivq_f32(self.0, other.0)) }
+        }
+
+        #[inline(always)]
+        pub fn neg(self) -> F32x4 {
+            unsafe { F32x4(vnegq_f32(self.0)) }
+        }
+    }
+
+    /// Select lanes `i` and `j` of `a`, followed by lanes `k` and `l` of `b`.
+    macro_rules! shuffle {
+        ($a:expr, $b:expr, [$i:expr, $j:expr, $k:expr, $l:expr]) => {
+            imp::F32x4(imp::shuffle($a.0, $b.0, [$i, $j, $k + 4, $l + 4]))
+        };
+    }
+
+    #[inline(always)]
+    pub fn shuffle(a: float32x4_t, b: float32x4_t, lanes: [u8; 4]) -> float32x4_t {
+        let mut index = [0u8; 16];
+        for (i, &lane) in lanes.iter().enumerate() {
+            for byte in 0..4 {
+                index[i * 4 + byte] = lane * 4 + byte as u8;
+            }
+        }
+        unsafe {
+            let table = uint8x16x2_t(vreinterpretq_u8_f32(a), vreinterpretq_u8_f32(b));
+            vreinterpretq_f32_u8(vqtbl2q_u8(table, vld1q_u8(index.as_ptr())))
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+#[macro_use]
+mod imp {
+    #[derive(Copy, Clone)]
+    pub struct F32x4(pub [f32; 4]);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn load(a: [f32; 4]) -> F32x4 {
+            F32x4(a)
+        }
+
+        #[inline(always)]
+        pub fn store(self) -> [f32; 4] {
+            self.0
+        }
+
+        #[inline(always)]
+        pub fn splat(x: f32) -> F32x4 {
+            F32x4([x; 4])
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: F32x4) -> F32x4 {
+            self.zip(other, |a, b| a + b)
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: F32x4) -> F32x4 {
+            self.zip(other, |a, b| a - b)
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: F32x4) -> F32x4 {
+            self.zip(other, |a, b| a * b)
+        }
+
+        #[inline(always)]
+        pub fn div(self, other: F32x4) -> F32x4 {
+            self.zip(other, |a, b| a / b)
+        }
+
+        #[inline(always)]
+        pub fn neg(self) -> F32x4 {
+            F32x4([-self.0[0], -self.0[1], -self.0[2], -self.0[3]])
+        }
+
+        #[inline(always)]
+        fn zip<F: Fn(f32, f32) -> f32>(self, other: F32x4, f: F) -> F32x4 {
+            let (a, b) = (self.0, other.0);
+            F32x4([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])])
+        }
+    }
+
+    /// Select lanes `i` and `j` of `a`, followed by lanes `k` and `l` of `b`.
+    macro_rules! shuffle {
+        ($a:expr, $b:expr, [$i:expr, $j:expr, $k:expr, $l:expr]) => {{
+            let (a, b) = ($a.0, $b.0);
+            imp::F32x4([a[$i], a[$j], b[$k], b[$l]])
+        }};
+    }
+}
+
+use self::imp::F32x4;
+
+/// Select lanes of a single vector.
+macro_rules! swizzle {
+    ($a:expr, [$i:expr, $j:expr, $k:expr, $l:expr]) => {{
+        let a = $a;
+        shuffle!(a, a, [$i, $j, $k, $l])
+    }};
+}
+
+#[inline(always)]
+fn load4(v: Vector4<f32>) -> F32x4 {
+    F32x4::load(v.into())
+}
+
+#[inline(always)]
+fn store4(v: F32x4) -> Vector4<f32> {
+    v.store().into()
+}
+
+#[inline(always)]
+fn load_quaternion(q: Quaternion<f32>) -> F32x4 {
+    F32x4::load([q.v.x, q.v.y, q.v.z, q.s])
+}
+
+#[inline(always)]
+fn store_quaternion(q: F32x4) -> Quaternion<f32> {
+    let [x, y, z, s] = q.store();
+    Quaternion::new(s, x, y, z)
+}
+
+pub fn vector4_add(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+    store4(load4(a).add(load4(b)))
+}
+
+pub fn vector4_sub(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+    store4(load4(a).sub(load4(b)))
+}
+
+pub fn vector4_mul(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+    store4(load4(a).mul(load4(b)))
+}
+
+pub fn vector4_div(a: Vector4<f32>, b: Vector4<f32>) -> Vector4<f32> {
+    store4(load4(a).div(load4(b)))
+}
+
+pub fn vector4_mul_scalar(a: Vector4<f32>, b: f32) -> Vector4<f32> {
+    store4(load4(a).mul(F32x4::splat(b)))
+}
+
+pub fn vector4_div_scalar(a: Vector4<f32>, b: f32) -> Vector4<f32> {
+    store4(load4(a).div(F32x4::splat(b)))
+}
+
+/// The Hamilton product, with each lane summing its terms in the same order as
+/// the generic implementation.
+pub fn quaternion_mul(a: Quaternion<f32>, b: Quaternion<f32>) -> Quaternion<f32> {
+    let (qa, qb) = (load_quaternion(a), load_quaternion(b));
+    let sign = F32x4::load([1.0, 1.0, 1.0, -1.0]);
+
+    let t0 = F32x4::splat(a.s).mul(qb);
+    let t1 = swizzle!(qa, [0, 1, 2, 0])
+        .mul(swizzle!(qb, [3, 3, 3, 0]))
+        .mul(sign);
+    let t2 = swizzle!(qa, [1, 2, 0, 1])
+        .mul(swizzle!(qb, [2, 0, 1, 1]))
+        .mul(sign);
+    let t3 = swizzle!(qa, [2, 0, 1, 2])
+        .mul(swizzle!(qb, [1, 2, 0, 2]))
+        .neg();
+
+    store_quaternion(t0.add(t1).add(t2).add(t3))
+}
+
+pub fn quaternion_invert(q: Quaternion<f32>) -> Quaternion<f32> {
+    let magnitude2 = q.s * q.s + (q.v.x * q.v.x + (q.v.y * q.v.y + q.v.z * q.v.z));
+    let conjugate = load_quaternion(q).mul(F32x4::load([-1.0, -1.0, -1.0, 1.0]));
+    store_quaternion(conjugate.div(F32x4::splat(magnitude2)))
+}
+
+/// Combine the columns of `m` using the components of `v` as weights.
+#[inline(always)]
+fn combine_columns(m: &[F32x4; 4], v: Vector4<f32>) -> F32x4 {
+    m[0].mul(F32x4::splat(v.x))
+        .add(m[1].mul(F32x4::splat(v.y)))
+        .add(m[2].mul(F32x4::splat(v.z)))
+        .add(m[3].mul(F32x4::splat(v.w)))
+}
+
+#[inline(always)]
+fn load_columns(m: Matrix4<f32>) -> [F32x4; 4] {
+    [load4(m.x), load4(m.y), load4(m.z), load4(m.w)]
+}
+
+#[inline(always)]
+fn store_columns(c: [F32x4; 4]) -> Matrix4<f32> {
+    Matrix4::from_cols(store4(c[0]), store4(c[1]), store4(c[2]), store4(c[3]))
+}
+
+/// Sums the weighted columns from the right, like the dot products of the
+/// rows in the generic implementation.
+pub fn matrix4_mul_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+    let m = load_columns(m);
+    let z = m[2].mul(F32x4::splat(v.z)).add(m[3].mul(F32x4::splat(v.w)));
+    let y = m[1].mul(F32x4::splat(v.y)).add(z);
+    store4(m[0].mul(F32x4::splat(v.x)).add(y))
+}
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx"
+)))]
+pub fn matrix4_mul(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+    let a = load_columns(a);
+    store_columns([
+        combine_columns(&a, b.x),
+        combine_columns(&a, b.y),
+        combine_columns(&a, b.z),
+        combine_columns(&a, b.w),
+    ])
+}
+
+/// Computes two columns of the result at a time, by repeating the columns of
+/// `a` in both halves of the 256-bit registers.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx"
+))]
+pub fn matrix4_mul(a: Matrix4<f32>, b: Matrix4<f32>) -> Matrix4<f32> {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let a: [[f32; 4]; 4] = a.into();
+    let b: [[f32; 4]; 4] = b.into();
+    let mut out = [[0.0f32; 4]; 4];
+    // Two columns are loaded and stored at a time, so the pointers are
+    // derived from the whole matrices rather than from single columns
+    let (b_ptr, out_ptr) = (b.as_ptr() as *const f32, out.as_mut_ptr() as *mut f32);
+
+    unsafe {
+        let repeat = |column: &[f32; 4]| {
+            let c = _mm_loadu_ps(column.as_ptr());
+            _mm256_insertf128_ps::<1>(_mm256_castps128_ps256(c), c)
+        };
+        let (a0, a1, a2, a3) = (repeat(&a[0]), repeat(&a[1]), repeat(&a[2]), repeat(&a[3]));
+
+        for i in 0..2 {
+            let b = _mm256_loadu_ps(b_ptr.add(8 * i));
+            let c = _mm256_add_ps(
+                _mm256_add_ps(
+                    _mm256_add_ps(
+                        _mm256_mul_ps(a0, _mm256_shuffle_ps::<0x00>(b, b)),
+                        _mm256_mul_ps(a1, _mm256_shuffle_ps::<0x55>(b, b)),
+                    ),
+                    _mm256_mul_ps(a2, _mm256_shuffle_ps::<0xAA>(b, b)),
+                ),
+                _mm256_mul_ps(a3, _mm256_shuffle_ps::<0xFF>(b, b)),
+            );
+            _mm256_storeu_ps(out_ptr.add(8 * i), c);
+        }
+    }
+
+    out.into()
+}
+
+pub fn matrix4_transpose(m: Matrix4<f32>) -> Matrix4<f32> {
+    let [c0, c1, c2, c3] = load_columns(m);
+    let t0 = shuffle!(c0, c1, [0, 1, 0, 1]);
+    let t1 = shuffle!(c0, c1, [2, 3, 2, 3]);
+    let t2 = shuffle!(c2, c3, [0, 1, 0, 1]);
+    let t3 = shuffle!(c2, c3, [2, 3, 2, 3]);
+    store_columns([
+        shuffle!(t0, t2, [0, 2, 0, 2]),
+        shuffle!(t0, t2, [1, 3, 1, 3]),
+        shuffle!(t1, t3, [0, 2, 0, 2]),
+        shuffle!(t1, t3, [1, 3, 1, 3]),
+    ])
+}
+
+/// The product `a * b` of 2x2 matrices stored in row-major order.
+#[inline(always)]
+fn mat2_mul(a: F32x4, b: F32x4) -> F32x4 {
+    a.mul(swizzle!(b, [0, 3, 0, 3]))
+        .add(swizzle!(a, [1, 0, 3, 2]).mul(swizzle!(b, [2, 1, 2, 1])))
+}
+
+/// The product `adj(a) * b` of 2x2 matrices stored in row-major order.
+#[inline(always)]
+fn mat2_adj_mul(a: F32x4, b: F32x4) -> F32x4 {
+    swizzle!(a, [3, 3, 0, 0])
+        .mul(b)
+        .sub(swizzle!(a, [1, 1, 2, 2]).mul(swizzle!(b, [2, 3, 0, 1])))
+}
+
+/// The product `a * adj(b)` of 2x2 matrices stored in row-major order.
+#[inline(always)]
+fn mat2_mul_adj(a: F32x4, b: F32x4) -> F32x4 {
+    a.mul(swizzle!(b, [3, 0, 3, 0]))
+        .sub(swizzle!(a, [1, 0, 3, 2]).mul(swizzle!(b, [2, 1, 2, 1])))
+}
+
+/// Invert the matrix blockwise, as four 2x2 matrices.
+///
+/// The algorithm is written for row-major matrices. Feeding it the columns
+/// inverts the transpose, and since the inverse of the transpose is the
+/// transpose of the inverse, the rows it returns are the columns we want.
+pub fn matrix4_invert(m: Matrix4<f32>) -> Option<Matrix4<f32>> {
+    let [r0, r1, r2, r3] = load_columns(m);
+
+    let a = shuffle!(r0, r1, [0, 1, 0, 1]);
+    let b = shuffle!(r0, r1, [2, 3, 2, 3]);
+    let c = shuffle!(r2, r3, [0, 1, 0, 1]);
+    let d = shuffle!(r2, r3, [2, 3, 2, 3]);
+
+    // The determinants of the blocks, as `(|A|, |B|, |C|, |D|)`
+    let det_sub = shuffle!(r0, r2, [0, 2, 0, 2])
+        .mul(shuffle!(r1, r3, [1, 3, 1, 3]))
+        .sub(shuffle!(r0, r2, [1, 3, 1, 3]).mul(shuffle!(r1, r3, [0, 2, 0, 2])));
+    let det_a = swizzle!(det_sub, [0, 0, 0, 0]);
+    let det_b = swizzle!(det_sub, [1, 1, 1, 1]);
+    let det_c = swizzle!(det_sub, [2, 2, 2, 2]);
+    let det_d = swizzle!(det_sub, [3, 3, 3, 3]);
+
+    let d_c = mat2_adj_mul(d, c);
+    let a_b = mat2_adj_mul(a, b);
+    let x = det_d.mul(a).sub(mat2_mul(b, d_c));
+    let w = det_a.mul(d).sub(mat2_mul(c, a_b));
+    let y = det_b.mul(c).sub(mat2_mul_adj(d, a_b));
+    let z = det_c.mul(b).sub(mat2_mul_adj(a, d_c));
+
+    // |M| = |A||D| + |B||C| - tr(adj(A) B adj(D) C)
+    let tr = a_b.mul(swizzle!(d_c, [0, 2, 1, 3]));
+    let tr = tr.add(swizzle!(tr, [1, 0, 3, 2]));
+    let tr = tr.add(swizzle!(tr, [2, 3, 0, 1]));
+    let det = det_a.mul(det_d).add(det_b.mul(det_c)).sub(tr);
+
+    if det.store()[0] == 0.0 {
+        return None;
+    }
+
+    let inv_det = F32x4::load([1.0, -1.0, -1.0, 1.0]).div(det);
+    let (x, y, z, w) = (
+        x.mul(inv_det),
+        y.mul(inv_det),
+        z.mul(inv_det),
+        w.mul(inv_det),
+    );
+
+    Some(store_columns([
+        shuffle!(x, y, [3, 1, 3, 1]),
+        shuffle!(x, y, [2, 0, 2, 0]),
+        shuffle!(z, w, [3, 1, 3, 1]),
+        shuffle!(z, w, [2, 0, 2, 0]),
+    ]))
+}
@@ -0,0 +1,312 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounding spheres.
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use line::Ray3;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use rotation::Rotation3;
+use transform::{Decomposed, Transform};
+use vector::Vector3;
+
+/// A sphere in 3-dimensional space, made up of the points at most `radius`
+/// away from its center.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere<S> {
+    /// The center of the sphere.
+    pub center: Point3<S>,
+    /// The radius of the sphere.
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// Construct a new sphere.
+    #[inline]
+    pub fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center, radius }
+    }
+
+    /// Construct the smallest sphere containing two points, which is centered
+    /// between them.
+    #[inline]
+    pub fn from_diameter(a: Point3<S>, b: Point3<S>) -> Sphere<S> {
+        let center = a.midpoint(b);
+        Sphere::new(center, center.distance(a))
+    }
+
+    /// Check if `point` is inside the sphere or on its surface.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Check if `other` lies completely inside this sphere.
+    #[inline]
+    pub fn contains_sphere(&self, other: &Sphere<S>) -> bool {
+        self.center.distance(other.center) + other.radius <= self.radius
+    }
+
+    /// The smallest sphere containing both this sphere and `other`.
+    pub fn merge(&self, other: &Sphere<S>) -> Sphere<S> {
+        if self.contains_sphere(other) {
+            return *self;
+        }
+        if other.contains_sphere(self) {
+            return *other;
+        }
+
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        let two: S = cast(2).unwrap();
+        let radius = (distance + self.radius + other.radius) / two;
+        let center = self.center + offset * ((radius - self.radius) / distance);
+        Sphere::new(center, radius)
+    }
+
+    /// Apply a similarity transformation to the sphere. The radius is scaled
+    /// by the scale of the transformation.
+    #[inline]
+    pub fn transform<R>(&self, transform: &Decomposed<Vector3<S>, R>) -> Sphere<S>
+    where
+        R: Rotation3<Scalar = S>,
+    {
+        Sphere::new(
+            transform.transform_point(self.center),
+            self.radius * transform.scale.abs(),
+        )
+    }
+
+    /// Find where a ray crosses the surface of the sphere, returning the
+    /// parameter of the first crossing along the ray. If the ray starts inside
+    /// the sphere, this is where it leaves the sphere.
+    pub fn ray_distance(&self, ray: &Ray3<S>) -> Option<S> {
+        let m = ray.origin - self.center;
+        let a = ray.direction.magnitude2();
+        let b = m.dot(ray.direction);
+        let c = m.magnitude2() - self.radius * self.radius;
+
+        // The ray starts outside the sphere and points away from it
+        if c > S::zero() && b > S::zero() {
+            return None;
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < S::zero() || a == S::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let t = (-b - root) / a;
+        if t >= S::zero() {
+            Some(t)
+        } else {
+            Some((-b + root) / a)
+        }
+    }
+
+    /// Construct the smallest sphere containing all of `points`, using
+    /// Welzl's algorithm. Returns `None` if there are no points.
+    ///
+    /// The expected running time is linear when the points are in random
+    /// order, so shuffling sorted input beforehand can be worthwhile.
+    pub fn minimal_enclosing(points: &[Point3<S>]) -> Option<Sphere<S>> {
+        let mut sphere = Sphere::new(*points.first()?, S::zero());
+
+        for i in 1..points.len() {
+            if contains_approx(&sphere, points[i]) {
+                continue;
+            }
+            sphere = Sphere::new(points[i], S::zero());
+            for j in 0..i {
+                if contains_approx(&sphere, points[j]) {
+                    continue;
+                }
+                sphere = Sphere::from_diameter(points[i], points[j]);
+                for k in 0..j {
+                    if contains_approx(&sphere, points[k]) {
+                        continue;
+                    }
+                    sphere = circumsphere3(points[i], points[j], points[k]);
+                    for l in 0..k {
+                        if contains_approx(&sphere, points[l]) {
+                            continue;
+                        }
+                        sphere = circumsphere4(points[i], points[j], points[k], points[l]);
+                    }
+                }
+            }
+        }
+
+        Some(sphere)
+    }
+
+    /// Construct a sphere containing all of `points`, using Ritter's
+    /// algorithm. Returns `None` if there are no points.
+    ///
+    /// This is faster than `Sphere::minimal_enclosing`, but the sphere is
+    /// typically a few percent larger than the smallest one.
+    pub fn ritter(points: &[Point3<S>]) -> Option<Sphere<S>> {
+        let first = *points.first()?;
+        let farthest_from = |p: Point3<S>| {
+            points.iter().cloned().fold(p, |best, q| {
+                if p.distance2(q) > p.distance2(best) {
+                    q
+                } else {
+                    best
+                }
+            })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+
+        let two: S = cast(2).unwrap();
+        let mut sphere = Sphere::from_diameter(a, b);
+        for &p in points {
+            let distance = sphere.center.distance(p);
+            if distance > sphere.radius {
+                let radius = (sphere.radius + distance) / two;
+                sphere.center += (p - sphere.center) * ((radius - sphere.radius) / distance);
+                sphere.radius = radius;
+            }
+        }
+
+        Some(sphere)
+    }
+}
+
+/// Check containment, allowing for the rounding error of the circumsphere
+/// constructions.
+#[inline]
+fn contains_approx<S: BaseFloat>(sphere: &Sphere<S>, point: Point3<S>) -> bool {
+    let tolerance = S::one() + S::epsilon() * cast(128).unwrap();
+    sphere.center.distance(point) <= sphere.radius * tolerance
+}
+
+/// The smallest sphere with three points on its surface.
+fn circumsphere3<S: BaseFloat>(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Sphere<S> {
+    let ab = b - a;
+    let ac = c - a;
+    let n = ab.cross(ac);
+    let denominator = n.magnitude2() * cast(2).unwrap();
+
+    if denominator <= S::epsilon() * ab.magnitude2() * ac.magnitude2() {
+        // The points are collinear, so the outermost two span the sphere
+        return [
+            Sphere::from_diameter(a, b),
+            Sphere::from_diameter(a, c),
+            Sphere::from_diameter(b, c),
+        ]
+        .iter()
+        .cloned()
+        .fold(Sphere::new(a, S::zero()), |best, s| {
+            if s.radius > best.radius {
+                s
+            } else {
+                best
+            }
+        });
+    }
+
+    let offset = (n.cross(ab) * ac.magnitude2() + ac.cross(n) * ab.magnitude2()) / denominator;
+    Sphere::new(a + offset, offset.magnitude())
+}
+
+/// The sphere with four points on its surface.
+fn circumsphere4<S: BaseFloat>(
+    a: Point3<S>,
+    b: Point3<S>,
+    c: Point3<S>,
+    d: Point3<S>,
+) -> Sphere<S> {
+    let (ab, ac, ad) = (b - a, c - a, d - a);
+    let two: S = cast(2).unwrap();
+    let rows = Matrix3::from_cols(ab, ac, ad).transpose() * two;
+    let rhs = Vector3::new(ab.magnitude2(), ac.magnitude2(), ad.magnitude2());
+
+    match rows.invert() {
+        Some(inverse) => {
+            let offset = inverse * rhs;
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        None => {
+            // The points are coplanar, so one of the triangles is enough
+            let candidates = [
+                circumsphere3(a, b, c),
+                circumsphere3(a, b, d),
+                circumsphere3(a, c, d),
+                circumsphere3(b, c, d),
+            ];
+            let points = [a, b, c, d];
+            candidates
+                .iter()
+                .filter(|s| points.iter().all(|&p| contains_approx(s, p)))
+                .cloned()
+                .fold(None, |best: Option<Sphere<S>>, s| match best {
+                    Some(best) if best.radius <= s.radius => Some(best),
+                    _ => Some(s),
+                })
+                .unwrap_or(candidates[0])
+        }
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Sphere<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Point3::abs_diff_eq(&self.center, &other.center, epsilon)
+            && S::abs_diff_eq(&self.radius, &other.radius, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Sphere<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Point3::relative_eq(&self.center, &other.center, epsilon, max_relative)
+            && S::relative_eq(&self.radius, &other.radius, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Sphere<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Point3::ulps_eq(&self.center, &other.center, epsilon, max_ulps)
+            && S::ulps_eq(&self.radius, &other.radius, epsilon, max_ulps)
+    }
+}
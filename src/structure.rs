@@ -15,10 +15,10 @@
 
 //! Generic algebraic structures
 
-use num_traits::{cast, Float};
-use std::cmp;
-use std::iter;
-use std::ops::*;
+use num_traits::{cast, Float, Signed};
+use core::cmp;
+use core::iter;
+use core::ops::*;
 
 use approx;
 
@@ -72,7 +72,7 @@ where
     /// Swap the elements at indices `i` and `j` in-place.
     #[inline]
     fn swap_elements(&mut self, i: usize, j: usize) {
-        use std::ptr;
+        use core::ptr;
 
         // Yeah, ok borrow checker – I know what I'm doing here
         unsafe { ptr::swap(&mut self[i], &mut self[j]) };
@@ -92,6 +92,289 @@ where
     fn is_finite(&self) -> bool
     where
         Self::Element: Float;
+
+    /// Apply `f` to each element of the array.
+    #[inline]
+    fn map_elements<F>(mut self, mut f: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(Self::Element) -> Self::Element,
+    {
+        for i in 0..Self::len() {
+            self[i] = f(self[i]);
+        }
+        self
+    }
+
+    /// Apply `f` to each pair of elements of the two arrays.
+    #[inline]
+    fn zip_elements<F>(mut self, other: Self, mut f: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(Self::Element, Self::Element) -> Self::Element,
+    {
+        for i in 0..Self::len() {
+            self[i] = f(self[i], other[i]);
+        }
+        self
+    }
+
+    /// The element-wise minimum of two arrays.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(1, 5, 3).min(Vector3::new(4, 2, 3)),
+    ///            Vector3::new(1, 2, 3));
+    /// ```
+    #[inline]
+    fn min(self, other: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: PartialOrd,
+    {
+        self.zip_elements(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// The element-wise maximum of two arrays.
+    #[inline]
+    fn max(self, other: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: PartialOrd,
+    {
+        self.zip_elements(other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Clamp each element between the corresponding elements of `min` and
+    /// `max`.
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: PartialOrd,
+    {
+        self.max(min).min(max)
+    }
+
+    /// The absolute value of each element.
+    #[inline]
+    fn abs(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Signed,
+    {
+        self.map_elements(|x| x.abs())
+    }
+
+    /// The sign of each element, as in `Signed::signum`.
+    #[inline]
+    fn signum(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Signed,
+    {
+        self.map_elements(|x| x.signum())
+    }
+
+    /// Round each element down to an integer.
+    #[inline]
+    fn floor(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::floor)
+    }
+
+    /// Round each element up to an integer.
+    #[inline]
+    fn ceil(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::ceil)
+    }
+
+    /// Round each element to the nearest integer, with ties away from zero.
+    #[inline]
+    fn round(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::round)
+    }
+
+    /// Round each element towards zero.
+    #[inline]
+    fn trunc(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::trunc)
+    }
+
+    /// The fractional part of each element, computed as `x - x.floor()` like
+    /// GLSL's `fract`, so that it is always in `[0, 1)`.
+    ///
+    /// Note that this differs from `Float::fract`, which rounds towards zero
+    /// and is negative for negative numbers.
+    #[inline]
+    fn fract(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(|x| x - x.floor())
+    }
+
+    /// Raise each element to the power `n`.
+    #[inline]
+    fn powf(self, n: Self::Element) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(|x| x.powf(n))
+    }
+
+    /// The exponential function of each element.
+    #[inline]
+    fn exp(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::exp)
+    }
+
+    /// The square root of each element.
+    #[inline]
+    fn sqrt(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::sqrt)
+    }
+
+    /// The reciprocal of each element.
+    #[inline]
+    fn recip(self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.map_elements(Float::recip)
+    }
+
+    /// The index and value of the smallest element. When several elements
+    /// are equally small, the first one is returned. `NaN` elements are
+    /// ignored, unless the first element is `NaN`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector4;
+    ///
+    /// assert_eq!(Vector4::new(3, 1, 4, 1).min_element(), (1, 1));
+    /// ```
+    fn min_element(&self) -> (usize, Self::Element)
+    where
+        Self::Element: PartialOrd,
+    {
+        let mut min = (0, self[0]);
+        for i in 1..Self::len() {
+            if self[i] < min.1 {
+                min = (i, self[i]);
+            }
+        }
+        min
+    }
+
+    /// The index and value of the largest element. When several elements
+    /// are equally large, the first one is returned. `NaN` elements are
+    /// ignored, unless the first element is `NaN`.
+    fn max_element(&self) -> (usize, Self::Element)
+    where
+        Self::Element: PartialOrd,
+    {
+        let mut max = (0, self[0]);
+        for i in 1..Self::len() {
+            if self[i] > max.1 {
+                max = (i, self[i]);
+            }
+        }
+        max
+    }
+
+    /// Linearly interpolate each element between `self` and `other`, using
+    /// the corresponding element of `amount`, like GLSL's `mix`.
+    ///
+    /// Use `VectorSpace::lerp` to interpolate all elements by the same
+    /// amount.
+    #[inline]
+    fn mix(mut self, other: Self, amount: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        for i in 0..Self::len() {
+            self[i] = self[i] + (other[i] - self[i]) * amount[i];
+        }
+        self
+    }
+
+    /// `0` for each element that is less than the corresponding element of
+    /// `edge`, and `1` otherwise, like GLSL's `step(edge, x)`.
+    #[inline]
+    fn step(self, edge: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        self.zip_elements(edge, |x, edge| {
+            if x < edge {
+                Self::Element::zero()
+            } else {
+                Self::Element::one()
+            }
+        })
+    }
+
+    /// Smooth Hermite interpolation from `0` to `1` of each element as it
+    /// goes from the corresponding element of `edge0` to that of `edge1`,
+    /// like GLSL's `smoothstep(edge0, edge1, x)`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector3;
+    ///
+    /// let edge0 = Vector3::from_value(0.0);
+    /// let edge1 = Vector3::from_value(2.0);
+    /// assert_eq!(Vector3::new(-1.0, 1.0, 3.0).smoothstep(edge0, edge1),
+    ///            Vector3::new(0.0, 0.5, 1.0));
+    /// ```
+    #[inline]
+    fn smoothstep(mut self, edge0: Self, edge1: Self) -> Self
+    where
+        Self: Sized,
+        Self::Element: Float,
+    {
+        let (zero, one) = (Self::Element::zero(), Self::Element::one());
+        let two = one + one;
+        let three = two + one;
+        for i in 0..Self::len() {
+            let t = ((self[i] - edge0[i]) / (edge1[i] - edge0[i]))
+                .max(zero)
+                .min(one);
+            self[i] = t * t * (three - two * t);
+        }
+        self
+    }
 }
 
 /// Element-wise arithmetic operations. These are supplied for pragmatic
@@ -110,6 +393,33 @@ pub trait ElementWise<Rhs = Self> {
     fn rem_assign_element_wise(&mut self, rhs: Rhs);
 }
 
+/// Element-wise comparisons, returning a mask with the result for each
+/// element. These are the equivalents of GLSL's `lessThan`, `equal`, and
+/// related functions.
+///
+/// ```rust
+/// use cgmath::prelude::*;
+/// use cgmath::{BVec3, Vector3};
+///
+/// let a = Vector3::new(1.0, 5.0, 3.0);
+/// let b = Vector3::new(2.0, 4.0, 3.0);
+///
+/// assert_eq!(a.lt_element_wise(b), BVec3::new(true, false, false));
+/// assert_eq!(a.ge_element_wise(3.0), BVec3::new(false, true, true));
+/// assert_eq!(a.le_element_wise(b).select(a, b), Vector3::new(1.0, 4.0, 3.0));
+/// ```
+pub trait ElementWiseCmp<Rhs = Self> {
+    /// The mask type, with one `bool` for each element.
+    type Mask;
+
+    fn lt_element_wise(self, rhs: Rhs) -> Self::Mask;
+    fn le_element_wise(self, rhs: Rhs) -> Self::Mask;
+    fn gt_element_wise(self, rhs: Rhs) -> Self::Mask;
+    fn ge_element_wise(self, rhs: Rhs) -> Self::Mask;
+    fn eq_element_wise(self, rhs: Rhs) -> Self::Mask;
+    fn ne_element_wise(self, rhs: Rhs) -> Self::Mask;
+}
+
 /// Vectors that can be [added](http://mathworld.wolfram.com/VectorAddition.html)
 /// together and [multiplied](https://en.wikipedia.org/wiki/Scalar_multiplication)
 /// by scalars.
@@ -289,6 +599,112 @@ where
     {
         self * (magnitude / self.magnitude())
     }
+
+    /// Returns the
+    /// [vector rejection](https://en.wikipedia.org/wiki/Vector_projection#Vector_rejection_2)
+    /// of the current inner space from the supplied argument, the component
+    /// perpendicular to it. This is the complement of `project_on`.
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self - self.project_on(other)
+    }
+
+    /// Reflect the vector off a surface with the given `normal`, like GLSL's
+    /// `reflect`. The normal should be normalized.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, -1.0);
+    /// assert_eq!(v.reflect(Vector2::unit_y()), Vector2::new(1.0, 1.0));
+    /// ```
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        let two = Self::Scalar::one() + Self::Scalar::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Refract the vector through a surface with the given `normal`, where
+    /// `eta` is the ratio of the indices of refraction on the incident side
+    /// and the far side, like GLSL's `refract`. Both the vector and the
+    /// normal should be normalized, and the normal should face against the
+    /// vector.
+    ///
+    /// Returns `None` on total internal reflection.
+    fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self>
+    where
+        Self::Scalar: Float,
+    {
+        let one = Self::Scalar::one();
+        let cos_i = self.dot(normal);
+        let k = one - eta * eta * (one - cos_i * cos_i);
+        if k < Self::Scalar::zero() {
+            None
+        } else {
+            Some(self * eta - normal * (eta * cos_i + k.sqrt()))
+        }
+    }
+
+    /// Returns `normal` if it faces against `incident`, when compared
+    /// against `reference`, and the negated normal otherwise, like GLSL's
+    /// `faceforward(normal, incident, reference)`.
+    #[inline]
+    fn faceforward(self, incident: Self, reference: Self) -> Self {
+        if reference.dot(incident) < Self::Scalar::zero() {
+            self
+        } else {
+            Self::zero() - self
+        }
+    }
+
+    /// Returns a vector with the same direction, and a magnitude no greater
+    /// than `max`.
+    #[inline]
+    fn clamp_magnitude(self, max: Self::Scalar) -> Self
+    where
+        Self::Scalar: Float,
+    {
+        if self.magnitude2() > max * max {
+            self.normalize_to(max)
+        } else {
+            self
+        }
+    }
+
+    /// Spherical linear interpolation between two directions, rotating at a
+    /// constant angular speed. Both vectors should be normalized.
+    ///
+    /// For nearly parallel vectors, this falls back to a normalized `lerp`.
+    /// Returns `None` if the vectors point in opposite directions, as there
+    /// is no unique arc between them.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::{assert_ulps_eq, Vector2};
+    ///
+    /// let (x, y) = (Vector2::unit_x(), Vector2::unit_y());
+    /// let half = 0.5f64.sqrt();
+    /// assert_ulps_eq!(x.slerp(y, 0.5).unwrap(), Vector2::new(half, half));
+    /// assert_eq!(x.slerp(-x, 0.5), None);
+    /// ```
+    fn slerp(self, other: Self, amount: Self::Scalar) -> Option<Self>
+    where
+        Self::Scalar: BaseFloat,
+    {
+        let theta = self.angle(other);
+        let sin_theta = Rad::sin(theta);
+        if sin_theta < Float::epsilon() {
+            if self.dot(other) < Self::Scalar::zero() {
+                return None;
+            }
+            return Some(self.lerp(other, amount).normalize());
+        }
+
+        let scale1 = Rad::sin(theta * (Self::Scalar::one() - amount));
+        let scale2 = Rad::sin(theta * amount);
+        Some((self * scale1 + other * scale2) / sin_theta)
+    }
 }
 
 /// Points in a [Euclidean space](https://en.wikipedia.org/wiki/Euclidean_space)
@@ -471,7 +887,7 @@ where
     /// Replace a column in the array.
     #[inline]
     fn replace_col(&mut self, c: usize, src: Self::Column) -> Self::Column {
-        use std::mem;
+        use core::mem;
 
         mem::replace(&mut self[c], src)
     }
@@ -22,7 +22,7 @@ use point::{Point2, Point3};
 use rotation::*;
 use vector::{Vector2, Vector3};
 
-use std::ops::Mul;
+use core::ops::Mul;
 
 /// A trait representing an [affine
 /// transformation](https://en.wikipedia.org/wiki/Affine_transformation) that
@@ -315,8 +315,8 @@ mod serde_ser {
 mod serde_de {
     use super::Decomposed;
     use serde::{self, Deserialize};
-    use std::fmt;
-    use std::marker::PhantomData;
+    use core::fmt;
+    use core::marker::PhantomData;
     use structure::VectorSpace;
 
     enum DecomposedField {
@@ -0,0 +1,282 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Triangles.
+
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use transform::Transform;
+use vector::Vector3;
+
+/// A triangle with vertices `a`, `b` and `c`.
+///
+/// Barycentric coordinates are given as a `Vector3` holding the weights of
+/// `a`, `b` and `c` respectively, which sum to one for points in the plane of
+/// the triangle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Triangle<P> {
+    /// The first vertex.
+    pub a: P,
+    /// The second vertex.
+    pub b: P,
+    /// The third vertex.
+    pub c: P,
+}
+
+impl<P: EuclideanSpace> Triangle<P> {
+    /// Construct a new triangle.
+    #[inline]
+    pub fn new(a: P, b: P, c: P) -> Triangle<P> {
+        Triangle { a, b, c }
+    }
+
+    /// The vertices of the triangle.
+    #[inline]
+    pub fn vertices(&self) -> [P; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Apply a transformation to the vertices of the triangle.
+    #[inline]
+    pub fn transform<T: Transform<P>>(&self, transform: &T) -> Triangle<P> {
+        Triangle::new(
+            transform.transform_point(self.a),
+            transform.transform_point(self.b),
+            transform.transform_point(self.c),
+        )
+    }
+
+    /// The point at the average position of the vertices.
+    #[inline]
+    pub fn centroid(&self) -> P {
+        P::centroid(&self.vertices())
+    }
+
+    /// Get the point with the given barycentric coordinates.
+    #[inline]
+    pub fn from_barycentric(&self, weights: Vector3<P::Scalar>) -> P {
+        self.a + (self.b - self.a) * weights.y + (self.c - self.a) * weights.z
+    }
+
+    /// Interpolate attributes given at the vertices, such as colors or texture
+    /// coordinates, to the point with the given barycentric coordinates.
+    #[inline]
+    pub fn interpolate<V>(weights: Vector3<P::Scalar>, values: [V; 3]) -> V
+    where
+        V: VectorSpace<Scalar = P::Scalar>,
+    {
+        values[0] * weights.x + values[1] * weights.y + values[2] * weights.z
+    }
+}
+
+impl<P: EuclideanSpace> Triangle<P>
+where
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    /// The area of the triangle.
+    pub fn area(&self) -> P::Scalar {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let dot = ab.dot(ac);
+        let two: P::Scalar = cast(2).unwrap();
+        (ab.magnitude2() * ac.magnitude2() - dot * dot)
+            .max(P::Scalar::zero())
+            .sqrt()
+            / two
+    }
+
+    /// The barycentric coordinates of `point`. Points outside the plane of
+    /// the triangle are projected onto it first.
+    ///
+    /// The coordinates are not finite if the triangle is degenerate.
+    pub fn barycentric(&self, point: P) -> Vector3<P::Scalar> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = point - self.a;
+        let d00 = ab.magnitude2();
+        let d01 = ab.dot(ac);
+        let d11 = ac.magnitude2();
+        let d20 = ap.dot(ab);
+        let d21 = ap.dot(ac);
+        let denominator = d00 * d11 - d01 * d01;
+
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Vector3::new(P::Scalar::one() - v - w, v, w)
+    }
+
+    /// Interpolate attributes given at the vertices to `point`, using its
+    /// barycentric coordinates.
+    #[inline]
+    pub fn interpolate_at<V>(&self, point: P, values: [V; 3]) -> V
+    where
+        V: VectorSpace<Scalar = P::Scalar>,
+    {
+        Triangle::<P>::interpolate(self.barycentric(point), values)
+    }
+
+    /// Check if `point` is inside the triangle or on its edges. Points outside
+    /// the plane of the triangle are projected onto it first.
+    #[inline]
+    pub fn contains(&self, point: P) -> bool {
+        let weights = self.barycentric(point);
+        let zero = P::Scalar::zero();
+        weights.x >= zero && weights.y >= zero && weights.z >= zero
+    }
+
+    /// The center of the circle passing through the vertices of the triangle,
+    /// or `None` if the triangle is degenerate.
+    pub fn circumcenter(&self) -> Option<P> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let (bb, bc, cc) = (ab.magnitude2(), ab.dot(ac), ac.magnitude2());
+        let two: P::Scalar = cast(2).unwrap();
+        let denominator = two * (bb * cc - bc * bc);
+        if denominator == P::Scalar::zero() {
+            return None;
+        }
+
+        let s = cc * (bb - bc) / denominator;
+        let t = bb * (cc - bc) / denominator;
+        Some(self.a + ab * s + ac * t)
+    }
+
+    /// The center of the largest circle that fits inside the triangle.
+    pub fn incenter(&self) -> P {
+        let la = (self.c - self.b).magnitude();
+        let lb = (self.a - self.c).magnitude();
+        let lc = (self.b - self.a).magnitude();
+        let perimeter = la + lb + lc;
+        if perimeter == P::Scalar::zero() {
+            return self.a;
+        }
+        self.from_barycentric(Vector3::new(la, lb, lc) / perimeter)
+    }
+
+    /// Find the point on the triangle closest to `point`, including the
+    /// interior of the triangle.
+    ///
+    /// This follows `ClosestPtPointTriangle` from Real-Time Collision
+    /// Detection by Christer Ericson, checking which feature of the triangle
+    /// the point is closest to.
+    pub fn closest_point(&self, point: P) -> P {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let zero = P::Scalar::zero();
+        let ab = b - a;
+        let ac = c - a;
+
+        // The vertex region of `a`
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+
+        // The vertex region of `b`
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+
+        // The edge region of `ab`
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        // The vertex region of `c`
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+
+        // The edge region of `ac`
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        // The edge region of `bc`
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && (d4 - d3) >= zero && (d5 - d6) >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // The interior of the triangle
+        let denominator = va + vb + vc;
+        a + ab * (vb / denominator) + ac * (vc / denominator)
+    }
+
+    /// The squared distance from the triangle to `point`.
+    #[inline]
+    pub fn distance2(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).magnitude2()
+    }
+}
+
+impl<S: BaseFloat> Triangle<Point3<S>> {
+    /// The unit normal of the triangle, pointing towards the side from which
+    /// the vertices appear in counter-clockwise order.
+    #[inline]
+    pub fn normal(&self) -> Vector3<S> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// The plane containing the triangle, or `None` if the triangle is
+    /// degenerate.
+    #[inline]
+    pub fn plane(&self) -> Option<Plane<S>> {
+        Plane::from_points(self.a, self.b, self.c)
+    }
+}
+
+/// Find the point on the triangle `abc` closest to `point`, including the
+/// interior of the triangle.
+///
+/// This is the same as `Triangle::new(a, b, c).closest_point(point)`.
+#[inline]
+pub fn closest_point_triangle<P>(point: P, a: P, b: P, c: P) -> P
+where
+    P: EuclideanSpace,
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    Triangle::new(a, b, c).closest_point(point)
+}
+
+/// The squared distance from the triangle `abc` to `point`.
+///
+/// This is the same as `Triangle::new(a, b, c).distance2(point)`.
+#[inline]
+pub fn distance2_triangle<P>(point: P, a: P, b: P, c: P) -> P::Scalar
+where
+    P: EuclideanSpace,
+    P::Diff: InnerSpace,
+    P::Scalar: Float,
+{
+    Triangle::new(a, b, c).distance2(point)
+}
@@ -19,16 +19,17 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::fmt;
-use std::iter;
-use std::mem;
-use std::ops::*;
+use core::fmt;
+use core::iter;
+use core::mem;
+use core::ops::*;
 
 use structure::*;
 
 use angle::Rad;
 use approx;
 use num::{BaseFloat, BaseNum};
+use simd;
 
 #[cfg(feature = "mint")]
 use mint;
@@ -139,6 +140,94 @@ macro_rules! impl_vector {
             }
         }
 
+        impl $VectorN<f32> {
+            /// Convert each component to the bits of a half-precision float,
+            /// rounding to the nearest representable value, with ties to even.
+            ///
+            /// Values too large for a half-precision float become infinite,
+            /// and values too small become zero or subnormal.
+            #[inline]
+            pub fn to_f16_bits(&self) -> $VectorN<u16> {
+                self.map(f32_to_f16_bits)
+            }
+
+            /// Convert each component from the bits of a half-precision
+            /// float. This conversion is exact.
+            #[inline]
+            pub fn from_f16_bits(v: $VectorN<u16>) -> $VectorN<f32> {
+                v.map(f16_bits_to_f32)
+            }
+
+            /// Pack each component into a signed normalized 8-bit integer.
+            ///
+            /// The components are clamped to `[-1, 1]`, multiplied by `127`
+            /// and rounded to the nearest integer, with ties away from zero.
+            /// `NaN` becomes `0`.
+            #[inline]
+            pub fn to_snorm8(&self) -> $VectorN<i8> {
+                self.map(|x| pack_snorm(x, 127.0) as i8)
+            }
+
+            /// Unpack each component from a signed normalized 8-bit integer.
+            /// Both `-128` and `-127` become `-1`.
+            #[inline]
+            pub fn from_snorm8(v: $VectorN<i8>) -> $VectorN<f32> {
+                v.map(|x| unpack_snorm(x as f32, 127.0))
+            }
+
+            /// Pack each component into an unsigned normalized 8-bit integer.
+            ///
+            /// The components are clamped to `[0, 1]`, multiplied by `255`
+            /// and rounded to the nearest integer, with ties away from zero.
+            /// `NaN` becomes `0`.
+            #[inline]
+            pub fn to_unorm8(&self) -> $VectorN<u8> {
+                self.map(|x| pack_unorm(x, 255.0) as u8)
+            }
+
+            /// Unpack each component from an unsigned normalized 8-bit
+            /// integer.
+            #[inline]
+            pub fn from_unorm8(v: $VectorN<u8>) -> $VectorN<f32> {
+                v.map(|x| x as f32 / 255.0)
+            }
+
+            /// Pack each component into a signed normalized 16-bit integer.
+            ///
+            /// The components are clamped to `[-1, 1]`, multiplied by
+            /// `32767` and rounded to the nearest integer, with ties away
+            /// from zero. `NaN` becomes `0`.
+            #[inline]
+            pub fn to_snorm16(&self) -> $VectorN<i16> {
+                self.map(|x| pack_snorm(x, 32767.0) as i16)
+            }
+
+            /// Unpack each component from a signed normalized 16-bit integer.
+            /// Both `-32768` and `-32767` become `-1`.
+            #[inline]
+            pub fn from_snorm16(v: $VectorN<i16>) -> $VectorN<f32> {
+                v.map(|x| unpack_snorm(x as f32, 32767.0))
+            }
+
+            /// Pack each component into an unsigned normalized 16-bit
+            /// integer.
+            ///
+            /// The components are clamped to `[0, 1]`, multiplied by `65535`
+            /// and rounded to the nearest integer, with ties away from zero.
+            /// `NaN` becomes `0`.
+            #[inline]
+            pub fn to_unorm16(&self) -> $VectorN<u16> {
+                self.map(|x| pack_unorm(x, 65535.0) as u16)
+            }
+
+            /// Unpack each component from an unsigned normalized 16-bit
+            /// integer.
+            #[inline]
+            pub fn from_unorm16(v: $VectorN<u16>) -> $VectorN<f32> {
+                v.map(|x| x as f32 / 65535.0)
+            }
+        }
+
         impl<S: BaseNum> MetricSpace for $VectorN<S> {
             type Metric = S;
 
@@ -210,7 +299,7 @@ macro_rules! impl_vector {
             type Output = $VectorN<S>;
 
             #[inline]
-            default_fn!( neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) } );
+            fn neg(self) -> $VectorN<S> { $VectorN::new($(-self.$field),+) }
         }
 
         impl<S: BaseFloat> approx::AbsDiffEq for $VectorN<S> {
@@ -274,28 +363,40 @@ macro_rules! impl_vector {
         }
 
         impl_operator!(<S: BaseNum> Add<$VectorN<S> > for $VectorN<S> {
-            fn add(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field + rhs.$field),+) }
+            fn add(lhs, rhs) -> $VectorN<S> {
+                simd::binary(&lhs, &rhs, simd::vector4_add)
+                    .unwrap_or_else(|| $VectorN::new($(lhs.$field + rhs.$field),+))
+            }
         });
         impl_assignment_operator!(<S: BaseNum> AddAssign<$VectorN<S> > for $VectorN<S> {
             fn add_assign(&mut self, other) { $(self.$field += other.$field);+ }
         });
 
         impl_operator!(<S: BaseNum> Sub<$VectorN<S> > for $VectorN<S> {
-            fn sub(lhs, rhs) -> $VectorN<S> { $VectorN::new($(lhs.$field - rhs.$field),+) }
+            fn sub(lhs, rhs) -> $VectorN<S> {
+                simd::binary(&lhs, &rhs, simd::vector4_sub)
+                    .unwrap_or_else(|| $VectorN::new($(lhs.$field - rhs.$field),+))
+            }
         });
         impl_assignment_operator!(<S: BaseNum> SubAssign<$VectorN<S> > for $VectorN<S> {
             fn sub_assign(&mut self, other) { $(self.$field -= other.$field);+ }
         });
 
         impl_operator!(<S: BaseNum> Mul<S> for $VectorN<S> {
-            fn mul(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field * scalar),+) }
+            fn mul(vector, scalar) -> $VectorN<S> {
+                simd::binary(&vector, &scalar, simd::vector4_mul_scalar)
+                    .unwrap_or_else(|| $VectorN::new($(vector.$field * scalar),+))
+            }
         });
         impl_assignment_operator!(<S: BaseNum> MulAssign<S> for $VectorN<S> {
             fn mul_assign(&mut self, scalar) { $(self.$field *= scalar);+ }
         });
 
         impl_operator!(<S: BaseNum> Div<S> for $VectorN<S> {
-            fn div(vector, scalar) -> $VectorN<S> { $VectorN::new($(vector.$field / scalar),+) }
+            fn div(vector, scalar) -> $VectorN<S> {
+                simd::binary(&vector, &scalar, simd::vector4_div_scalar)
+                    .unwrap_or_else(|| $VectorN::new($(vector.$field / scalar),+))
+            }
         });
         impl_assignment_operator!(<S: BaseNum> DivAssign<S> for $VectorN<S> {
             fn div_assign(&mut self, scalar) { $(self.$field /= scalar);+ }
@@ -309,30 +410,34 @@ macro_rules! impl_vector {
         });
 
         impl<S: BaseNum> ElementWise for $VectorN<S> {
-            #[inline] default_fn!( add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) } );
-            #[inline] default_fn!( sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) } );
-            #[inline] default_fn!( mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field * rhs.$field),+) } );
-            #[inline] default_fn!( div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field / rhs.$field),+) } );
+            #[inline] fn add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field + rhs.$field),+) }
+            #[inline] fn sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field - rhs.$field),+) }
+            #[inline] fn mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> {
+                simd::binary(&self, &rhs, simd::vector4_mul).unwrap_or_else(|| $VectorN::new($(self.$field * rhs.$field),+))
+            }
+            #[inline] fn div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> {
+                simd::binary(&self, &rhs, simd::vector4_div).unwrap_or_else(|| $VectorN::new($(self.$field / rhs.$field),+))
+            }
             #[inline] fn rem_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { $VectorN::new($(self.$field % rhs.$field),+) }
 
-            #[inline] default_fn!( add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ } );
-            #[inline] default_fn!( sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ } );
-            #[inline] default_fn!( mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ } );
-            #[inline] default_fn!( div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ } );
+            #[inline] fn add_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field += rhs.$field);+ }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field -= rhs.$field);+ }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field *= rhs.$field);+ }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field /= rhs.$field);+ }
             #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field %= rhs.$field);+ }
         }
 
         impl<S: BaseNum> ElementWise<S> for $VectorN<S> {
-            #[inline] default_fn!( add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) } );
-            #[inline] default_fn!( sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) } );
-            #[inline] default_fn!( mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) } );
-            #[inline] default_fn!( div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) } );
+            #[inline] fn add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) }
+            #[inline] fn sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) }
+            #[inline] fn mul_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field * rhs),+) }
+            #[inline] fn div_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field / rhs),+) }
             #[inline] fn rem_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field % rhs),+) }
 
-            #[inline] default_fn!( add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ } );
-            #[inline] default_fn!( sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ } );
-            #[inline] default_fn!( mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ } );
-            #[inline] default_fn!( div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ } );
+            #[inline] fn add_assign_element_wise(&mut self, rhs: S) { $(self.$field += rhs);+ }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: S) { $(self.$field -= rhs);+ }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: S) { $(self.$field *= rhs);+ }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: S) { $(self.$field /= rhs);+ }
             #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
         }
 
@@ -470,6 +575,79 @@ impl<S: BaseNum> Vector3<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyz);
 }
 
+impl<S: BaseFloat> Vector3<S> {
+    /// Returns the angle from the vector to `other`, measured
+    /// counter-clockwise around `axis`. The result is in `(-π, π]`; it is
+    /// negative when the rotation from the vector to `other` is clockwise
+    /// around `axis`.
+    ///
+    /// The axis does not need to be normalized.
+    ///
+    /// ```rust
+    /// use cgmath::{Deg, Vector3};
+    ///
+    /// let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+    /// assert_eq!(Deg::from(x.angle_signed(y, z)), Deg(90.0));
+    /// assert_eq!(Deg::from(x.angle_signed(y, -z)), Deg(-90.0));
+    /// ```
+    #[inline]
+    pub fn angle_signed(self, other: Vector3<S>, axis: Vector3<S>) -> Rad<S> {
+        let cross = self.cross(other);
+        let sin = if cross.dot(axis) < S::zero() {
+            -cross.magnitude()
+        } else {
+            cross.magnitude()
+        };
+        Rad::atan2(sin, self.dot(other))
+    }
+}
+
+impl Vector3<f32> {
+    /// Encode a direction with the octahedral mapping, which projects the
+    /// unit sphere onto an octahedron and unfolds it into the square
+    /// `[-1, 1] x [-1, 1]`.
+    ///
+    /// The vector does not need to be normalized, but it must not be zero;
+    /// the zero vector is encoded as `(0, 0)`, which decodes to the `z` axis.
+    /// The result can be packed further with `to_snorm16` or `to_snorm8`.
+    ///
+    /// ```rust
+    /// use cgmath::{assert_relative_eq, Vector2, Vector3};
+    ///
+    /// let n = Vector3::new(0.0f32, 0.6, -0.8);
+    /// let packed = n.to_octahedral().to_snorm16();
+    /// let unpacked = Vector3::from_octahedral(Vector2::from_snorm16(packed));
+    /// assert_relative_eq!(unpacked, n, epsilon = 1e-4);
+    /// ```
+    pub fn to_octahedral(&self) -> Vector2<f32> {
+        let l1 = self.x.abs() + self.y.abs() + self.z.abs();
+        if l1 == 0.0 {
+            return Vector2::new(0.0, 0.0);
+        }
+        let (x, y) = (self.x / l1, self.y / l1);
+        if self.z >= 0.0 {
+            Vector2::new(x, y)
+        } else {
+            Vector2::new(
+                (1.0 - y.abs()) * sign_not_zero(x),
+                (1.0 - x.abs()) * sign_not_zero(y),
+            )
+        }
+    }
+
+    /// Decode a unit vector from the octahedral mapping. See `to_octahedral`.
+    ///
+    /// The components of `v` are clamped to `[-1, 1]`.
+    pub fn from_octahedral(v: Vector2<f32>) -> Vector3<f32> {
+        let (x, y) = (v.x.clamp(-1.0, 1.0), v.y.clamp(-1.0, 1.0));
+        let z = 1.0 - x.abs() - y.abs();
+        let t = (-z).max(0.0);
+        let x = if x >= 0.0 { x - t } else { x + t };
+        let y = if y >= 0.0 { y - t } else { y + t };
+        Vector3::new(x, y, z).normalize()
+    }
+}
+
 impl<S: BaseNum> Vector4<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -516,6 +694,59 @@ impl<S: BaseNum> Vector4<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyzw);
 }
 
+impl Vector4<f32> {
+    /// Pack the vector into 10 bits each for `x`, `y` and `z` and 2 bits for
+    /// `w`, as unsigned normalized integers. `x` is stored in the least
+    /// significant bits, matching `GL_UNSIGNED_INT_2_10_10_10_REV` and
+    /// `DXGI_FORMAT_R10G10B10A2_UNORM`.
+    ///
+    /// The components are clamped to `[0, 1]`, multiplied by `1023`, or `3`
+    /// for `w`, and rounded to the nearest integer, with ties away from zero.
+    /// `NaN` becomes `0`.
+    pub fn to_unorm_10_10_10_2(&self) -> u32 {
+        pack_unorm(self.x, 1023.0) as u32
+            | (pack_unorm(self.y, 1023.0) as u32) << 10
+            | (pack_unorm(self.z, 1023.0) as u32) << 20
+            | (pack_unorm(self.w, 3.0) as u32) << 30
+    }
+
+    /// Unpack a vector packed with `to_unorm_10_10_10_2`.
+    pub fn from_unorm_10_10_10_2(bits: u32) -> Vector4<f32> {
+        Vector4::new(
+            (bits & 0x3ff) as f32 / 1023.0,
+            (bits >> 10 & 0x3ff) as f32 / 1023.0,
+            (bits >> 20 & 0x3ff) as f32 / 1023.0,
+            (bits >> 30) as f32 / 3.0,
+        )
+    }
+
+    /// Pack the vector into 10 bits each for `x`, `y` and `z` and 2 bits for
+    /// `w`, as signed normalized integers in two's complement. `x` is stored
+    /// in the least significant bits, matching `GL_INT_2_10_10_10_REV`.
+    ///
+    /// The components are clamped to `[-1, 1]`, multiplied by `511`, or `1`
+    /// for `w`, and rounded to the nearest integer, with ties away from zero.
+    /// `NaN` becomes `0`.
+    pub fn to_snorm_10_10_10_2(&self) -> u32 {
+        (pack_snorm(self.x, 511.0) as i32 as u32 & 0x3ff)
+            | (pack_snorm(self.y, 511.0) as i32 as u32 & 0x3ff) << 10
+            | (pack_snorm(self.z, 511.0) as i32 as u32 & 0x3ff) << 20
+            | (pack_snorm(self.w, 1.0) as i32 as u32) << 30
+    }
+
+    /// Unpack a vector packed with `to_snorm_10_10_10_2`. The most negative
+    /// value of each component becomes `-1`, like the one above it.
+    pub fn from_snorm_10_10_10_2(bits: u32) -> Vector4<f32> {
+        let field = |shift: u32, width: u32| ((bits << (32 - shift - width)) as i32) >> (32 - width);
+        Vector4::new(
+            unpack_snorm(field(0, 10) as f32, 511.0),
+            unpack_snorm(field(10, 10) as f32, 511.0),
+            unpack_snorm(field(20, 10) as f32, 511.0),
+            unpack_snorm(field(30, 2) as f32, 1.0),
+        )
+    }
+}
+
 /// Dot product of two vectors.
 #[inline]
 pub fn dot<V: InnerSpace>(a: V, b: V) -> V::Scalar
@@ -525,6 +756,95 @@ where
     V::dot(a, b)
 }
 
+/// Clamp `x` to `[0, 1]`, scale it by `max`, and round it. `NaN` stays `NaN`,
+/// and becomes zero when cast to an integer.
+#[inline]
+fn pack_unorm(x: f32, max: f32) -> f32 {
+    Float::round(x.clamp(0.0, 1.0) * max)
+}
+
+/// Clamp `x` to `[-1, 1]`, scale it by `max`, and round it.
+#[inline]
+fn pack_snorm(x: f32, max: f32) -> f32 {
+    Float::round(x.clamp(-1.0, 1.0) * max)
+}
+
+/// Scale `x` down by `max`, clamping the most negative integer to `-1`.
+#[inline]
+fn unpack_snorm(x: f32, max: f32) -> f32 {
+    (x / max).max(-1.0)
+}
+
+/// `1` for positive numbers and positive zero, `-1` for negative numbers.
+#[inline]
+fn sign_not_zero(x: f32) -> f32 {
+    if x >= 0.0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Convert to the bits of a half-precision float, rounding to nearest even.
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16 & 0x8000) as u16;
+    let exponent = (bits >> 23 & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // Infinity, or NaN with the quiet bit set
+        let nan = if mantissa != 0 { 0x200 } else { 0 };
+        return sign | 0x7c00 | nan | (mantissa >> 13) as u16;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if exponent <= 0 {
+        // Subnormal, with the implicit leading bit made explicit
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exponent) as u32;
+        let half = 1 << (shift - 1);
+        let remainder = mantissa & ((1 << shift) - 1);
+        let mut half_bits = mantissa >> shift;
+        if remainder > half || (remainder == half && half_bits & 1 == 1) {
+            half_bits += 1;
+        }
+        return sign | half_bits as u16;
+    }
+
+    // A carry out of the mantissa correctly increments the exponent, up to
+    // infinity
+    let mut half_bits = (exponent as u32) << 10 | mantissa >> 13;
+    let remainder = mantissa & 0x1fff;
+    if remainder > 0x1000 || (remainder == 0x1000 && half_bits & 1 == 1) {
+        half_bits += 1;
+    }
+    sign | half_bits as u16
+}
+
+/// Convert from the bits of a half-precision float.
+fn f16_bits_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = (half >> 10 & 0x1f) as u32;
+    let mantissa = (half & 0x3ff) as u32;
+
+    let bits = match exponent {
+        0 => {
+            let value = mantissa as f32 / (1 << 24) as f32;
+            return if sign != 0 { -value } else { value };
+        }
+        0x1f => sign | 0x7f80_0000 | mantissa << 13,
+        _ => sign | (exponent + 127 - 15) << 23 | mantissa << 13,
+    };
+    f32::from_bits(bits)
+}
+
 impl<S: BaseNum> InnerSpace for Vector1<S> {
     #[inline]
     fn dot(self, other: Vector1<S>) -> S {
@@ -604,6 +924,15 @@ impl_mint_conversions!(Vector3 { x, y, z }, Vector3);
 #[cfg(feature = "mint")]
 impl_mint_conversions!(Vector4 { x, y, z, w }, Vector4);
 
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Vector1);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Vector2);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Vector3);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Vector4);
+
 #[cfg(test)]
 mod tests {
     mod vector2 {
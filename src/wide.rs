@@ -0,0 +1,1133 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wide vector types, which store several vectors in structure-of-arrays
+//! layout so that the same operation can be applied to all of them at once.
+
+use core::array;
+use core::iter;
+use core::ops::*;
+use num_traits::{cast, Float};
+
+use structure::*;
+
+use angle::Rad;
+use approx;
+use mask::BVec4;
+use matrix::{Matrix3, Matrix4};
+use num::{BaseFloat, BaseNum};
+use quaternion::Quaternion;
+use vector::Vector3;
+
+#[inline]
+fn zip<S: Copy, T, F: Fn(S, S) -> T, const N: usize>(a: [S; N], b: [S; N], f: F) -> [T; N] {
+    array::from_fn(|i| f(a[i], b[i]))
+}
+
+/// A mask with one boolean for each of 8 lanes, for the eight-lane wide types.
+///
+/// This has the same methods as the `BVec4` mask of the four-lane wide types.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mask8(pub [bool; 8]);
+
+impl Mask8 {
+    /// Construct a mask with every lane set to `value`.
+    #[inline]
+    pub const fn splat(value: bool) -> Mask8 {
+        Mask8([value; 8])
+    }
+
+    /// Whether any lane is set.
+    #[inline]
+    pub fn any(self) -> bool {
+        self.0.iter().any(|&lane| lane)
+    }
+
+    /// Whether every lane is set.
+    #[inline]
+    pub fn all(self) -> bool {
+        self.0.iter().all(|&lane| lane)
+    }
+
+    /// Whether no lane is set.
+    #[inline]
+    pub fn none(self) -> bool {
+        !self.any()
+    }
+
+    /// Pick the lanes of `a` where the mask is set, and of `b` elsewhere.
+    #[inline]
+    pub fn select<T: Copy, V>(self, a: V, b: V) -> V
+    where
+        V: Into<[T; 8]> + From<[T; 8]>,
+    {
+        let (a, b): ([T; 8], [T; 8]) = (a.into(), b.into());
+        array::from_fn(|i| if self.0[i] { a[i] } else { b[i] }).into()
+    }
+}
+
+impl From<[bool; 8]> for Mask8 {
+    #[inline]
+    fn from(v: [bool; 8]) -> Mask8 {
+        Mask8(v)
+    }
+}
+
+impl From<Mask8> for [bool; 8] {
+    #[inline]
+    fn from(v: Mask8) -> [bool; 8] {
+        v.0
+    }
+}
+
+impl Not for Mask8 {
+    type Output = Mask8;
+
+    #[inline]
+    fn not(self) -> Mask8 {
+        Mask8(self.0.map(|lane| !lane))
+    }
+}
+
+impl BitAnd for Mask8 {
+    type Output = Mask8;
+
+    #[inline]
+    fn bitand(self, rhs: Mask8) -> Mask8 {
+        Mask8(zip(self.0, rhs.0, |a, b| a & b))
+    }
+}
+
+impl BitOr for Mask8 {
+    type Output = Mask8;
+
+    #[inline]
+    fn bitor(self, rhs: Mask8) -> Mask8 {
+        Mask8(zip(self.0, rhs.0, |a, b| a | b))
+    }
+}
+
+impl BitXor for Mask8 {
+    type Output = Mask8;
+
+    #[inline]
+    fn bitxor(self, rhs: Mask8) -> Mask8 {
+        Mask8(zip(self.0, rhs.0, |a, b| a ^ b))
+    }
+}
+
+macro_rules! impl_wide {
+    ($ScalarN:ident, $VectorN:ident, $QuaternionN:ident, $MaskN:ident, $n:expr) => {
+        #[doc = concat!(
+            "A wide scalar, holding ", $n, " scalars that are operated on ",
+            "lane-wise.\n\n",
+            "This is the scalar type of the wide vectors and quaternions, so ",
+            "`dot` and `magnitude` produce one value per lane. It has no ordering ",
+            "and is not a `BaseFloat`, as a single `bool` can't describe every ",
+            "lane, so compare wide scalars lane-wise with `ElementWiseCmp` and ",
+            "pick between results with the `select` method of the mask."
+        )]
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $ScalarN<S>(pub [S; $n]);
+
+        impl<S: Copy> $ScalarN<S> {
+            /// Construct a wide scalar with every lane set to `value`.
+            #[inline]
+            pub fn splat(value: S) -> $ScalarN<S> {
+                $ScalarN([value; $n])
+            }
+
+            /// Apply `f` to every lane.
+            #[inline]
+            pub fn map<U, F: Fn(S) -> U>(self, f: F) -> $ScalarN<U> {
+                $ScalarN(self.0.map(f))
+            }
+
+            /// Apply `f` to the matching lanes of `self` and `other`.
+            #[inline]
+            pub fn zip<U, F: Fn(S, S) -> U>(self, other: $ScalarN<S>, f: F) -> $ScalarN<U> {
+                $ScalarN(zip(self.0, other.0, f))
+            }
+
+        }
+
+        impl<S: Copy> From<[S; $n]> for $ScalarN<S> {
+            #[inline]
+            fn from(lanes: [S; $n]) -> $ScalarN<S> {
+                $ScalarN(lanes)
+            }
+        }
+
+        impl<S: Copy> From<$ScalarN<S>> for [S; $n] {
+            #[inline]
+            fn from(s: $ScalarN<S>) -> [S; $n] {
+                s.0
+            }
+        }
+
+        impl<S> Index<usize> for $ScalarN<S> {
+            type Output = S;
+
+            #[inline]
+            fn index(&self, i: usize) -> &S {
+                &self.0[i]
+            }
+        }
+
+        impl<S> IndexMut<usize> for $ScalarN<S> {
+            #[inline]
+            fn index_mut(&mut self, i: usize) -> &mut S {
+                &mut self.0[i]
+            }
+        }
+
+        impl_operator!(<S: BaseNum> Add<$ScalarN<S> > for $ScalarN<S> {
+            fn add(lhs, rhs) -> $ScalarN<S> { $ScalarN(zip(lhs.0, rhs.0, |a, b| a + b)) }
+        });
+        impl_assignment_operator!(<S: BaseNum> AddAssign<$ScalarN<S> > for $ScalarN<S> {
+            fn add_assign(&mut self, other) { *self = *self + other; }
+        });
+
+        impl_operator!(<S: BaseNum> Sub<$ScalarN<S> > for $ScalarN<S> {
+            fn sub(lhs, rhs) -> $ScalarN<S> { $ScalarN(zip(lhs.0, rhs.0, |a, b| a - b)) }
+        });
+        impl_assignment_operator!(<S: BaseNum> SubAssign<$ScalarN<S> > for $ScalarN<S> {
+            fn sub_assign(&mut self, other) { *self = *self - other; }
+        });
+
+        impl_operator!(<S: BaseNum> Mul<$ScalarN<S> > for $ScalarN<S> {
+            fn mul(lhs, rhs) -> $ScalarN<S> { $ScalarN(zip(lhs.0, rhs.0, |a, b| a * b)) }
+        });
+        impl_assignment_operator!(<S: BaseNum> MulAssign<$ScalarN<S> > for $ScalarN<S> {
+            fn mul_assign(&mut self, other) { *self = *self * other; }
+        });
+
+        impl_operator!(<S: BaseNum> Div<$ScalarN<S> > for $ScalarN<S> {
+            fn div(lhs, rhs) -> $ScalarN<S> { $ScalarN(zip(lhs.0, rhs.0, |a, b| a / b)) }
+        });
+        impl_assignment_operator!(<S: BaseNum> DivAssign<$ScalarN<S> > for $ScalarN<S> {
+            fn div_assign(&mut self, other) { *self = *self / other; }
+        });
+
+        impl_operator!(<S: BaseNum> Rem<$ScalarN<S> > for $ScalarN<S> {
+            fn rem(lhs, rhs) -> $ScalarN<S> { $ScalarN(zip(lhs.0, rhs.0, |a, b| a % b)) }
+        });
+        impl_assignment_operator!(<S: BaseNum> RemAssign<$ScalarN<S> > for $ScalarN<S> {
+            fn rem_assign(&mut self, other) { *self = *self % other; }
+        });
+
+        impl<S: Neg<Output = S> + Copy> Neg for $ScalarN<S> {
+            type Output = $ScalarN<S>;
+
+            #[inline]
+            fn neg(self) -> $ScalarN<S> {
+                self.map(|a| -a)
+            }
+        }
+
+        impl<S: BaseNum> Zero for $ScalarN<S> {
+            #[inline]
+            fn zero() -> $ScalarN<S> {
+                $ScalarN::splat(S::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0.iter().all(S::is_zero)
+            }
+        }
+
+        impl<S: BaseNum> One for $ScalarN<S> {
+            #[inline]
+            fn one() -> $ScalarN<S> {
+                $ScalarN::splat(S::one())
+            }
+        }
+
+        impl<S: BaseFloat> $ScalarN<S> {
+            /// The absolute value of every lane.
+            #[inline]
+            pub fn abs(self) -> $ScalarN<S> {
+                self.map(Float::abs)
+            }
+
+            /// The square root of every lane.
+            #[inline]
+            pub fn sqrt(self) -> $ScalarN<S> {
+                self.map(Float::sqrt)
+            }
+
+            /// The reciprocal of every lane.
+            #[inline]
+            pub fn recip(self) -> $ScalarN<S> {
+                self.map(Float::recip)
+            }
+
+            /// The smaller of the matching lanes of `self` and `other`.
+            #[inline]
+            pub fn min(self, other: $ScalarN<S>) -> $ScalarN<S> {
+                self.zip(other, Float::min)
+            }
+
+            /// The larger of the matching lanes of `self` and `other`.
+            #[inline]
+            pub fn max(self, other: $ScalarN<S>) -> $ScalarN<S> {
+                self.zip(other, Float::max)
+            }
+
+            /// Compute `self * a + b` in every lane with a single rounding.
+            #[inline]
+            pub fn mul_add(self, a: $ScalarN<S>, b: $ScalarN<S>) -> $ScalarN<S> {
+                $ScalarN(array::from_fn(|i| self.0[i].mul_add(a.0[i], b.0[i])))
+            }
+
+            /// The arc cosine of every lane, in radians.
+            #[inline]
+            pub fn acos(self) -> $ScalarN<S> {
+                self.map(Float::acos)
+            }
+
+            /// The four quadrant arc tangent of the matching lanes of `self`
+            /// and `other`, in radians.
+            #[inline]
+            pub fn atan2(self, other: $ScalarN<S>) -> $ScalarN<S> {
+                self.zip(other, Float::atan2)
+            }
+        }
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        impl<S: PartialOrd + Copy> ElementWiseCmp for $ScalarN<S> {
+            type Mask = $MaskN;
+
+            #[inline] fn lt_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a < b)) }
+            #[inline] fn le_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a <= b)) }
+            #[inline] fn gt_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a > b)) }
+            #[inline] fn ge_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a >= b)) }
+            #[inline] fn eq_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a == b)) }
+            #[inline] fn ne_element_wise(self, rhs: $ScalarN<S>) -> $MaskN { $MaskN::from(zip(self.0, rhs.0, |a, b| a != b)) }
+        }
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        impl<S: PartialOrd + Copy> ElementWiseCmp<S> for $ScalarN<S> {
+            type Mask = $MaskN;
+
+            #[inline] fn lt_element_wise(self, rhs: S) -> $MaskN { self.lt_element_wise($ScalarN::splat(rhs)) }
+            #[inline] fn le_element_wise(self, rhs: S) -> $MaskN { self.le_element_wise($ScalarN::splat(rhs)) }
+            #[inline] fn gt_element_wise(self, rhs: S) -> $MaskN { self.gt_element_wise($ScalarN::splat(rhs)) }
+            #[inline] fn ge_element_wise(self, rhs: S) -> $MaskN { self.ge_element_wise($ScalarN::splat(rhs)) }
+            #[inline] fn eq_element_wise(self, rhs: S) -> $MaskN { self.eq_element_wise($ScalarN::splat(rhs)) }
+            #[inline] fn ne_element_wise(self, rhs: S) -> $MaskN { self.ne_element_wise($ScalarN::splat(rhs)) }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $ScalarN<S> {
+            type Epsilon = $ScalarN<S>;
+
+            #[inline]
+            fn default_epsilon() -> $ScalarN<S> {
+                $ScalarN::splat(S::default_epsilon())
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: $ScalarN<S>) -> bool {
+                (0..$n).all(|i| S::abs_diff_eq(&self.0[i], &other.0[i], epsilon.0[i]))
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $ScalarN<S> {
+            #[inline]
+            fn default_max_relative() -> $ScalarN<S> {
+                $ScalarN::splat(S::default_max_relative())
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: $ScalarN<S>, max_relative: $ScalarN<S>) -> bool {
+                (0..$n).all(|i| {
+                    S::relative_eq(&self.0[i], &other.0[i], epsilon.0[i], max_relative.0[i])
+                })
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $ScalarN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: $ScalarN<S>, max_ulps: u32) -> bool {
+                (0..$n).all(|i| S::ulps_eq(&self.0[i], &other.0[i], epsilon.0[i], max_ulps))
+            }
+        }
+
+        #[doc = concat!(
+            "A wide vector, holding ", $n, " three-dimensional vectors in ",
+            "structure-of-arrays layout.\n\n",
+            "Each field holds the corresponding component of every lane, and the ",
+            "operations are applied to all lanes at once. Operations that produce ",
+            "a scalar for `Vector3` produce a wide scalar with one value per lane."
+        )]
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $VectorN<S> {
+            /// The x components of the lanes.
+            pub x: [S; $n],
+            /// The y components of the lanes.
+            pub y: [S; $n],
+            /// The z components of the lanes.
+            pub z: [S; $n],
+        }
+
+        impl<S: Copy> $VectorN<S> {
+            /// Construct a wide vector from the components of its lanes.
+            #[inline]
+            pub const fn new(x: [S; $n], y: [S; $n], z: [S; $n]) -> $VectorN<S> {
+                $VectorN { x, y, z }
+            }
+
+            /// Construct a wide vector with every lane set to `v`.
+            #[inline]
+            pub fn splat(v: Vector3<S>) -> $VectorN<S> {
+                $VectorN::new([v.x; $n], [v.y; $n], [v.z; $n])
+            }
+
+            /// Construct a wide vector from its lanes.
+            #[inline]
+            pub fn from_vectors(vectors: [Vector3<S>; $n]) -> $VectorN<S> {
+                $VectorN::new(
+                    vectors.map(|v| v.x),
+                    vectors.map(|v| v.y),
+                    vectors.map(|v| v.z),
+                )
+            }
+
+            /// Split the wide vector into its lanes.
+            #[inline]
+            pub fn to_vectors(self) -> [Vector3<S>; $n] {
+                array::from_fn(|i| self.lane(i))
+            }
+
+            /// The vector in lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> Vector3<S> {
+                Vector3::new(self.x[i], self.y[i], self.z[i])
+            }
+
+            /// Replace the vector in lane `i`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, v: Vector3<S>) {
+                self.x[i] = v.x;
+                self.y[i] = v.y;
+                self.z[i] = v.z;
+            }
+
+            /// Pick the lanes of `a` where `mask` is set, and of `b` elsewhere.
+            #[inline]
+            pub fn select(mask: $MaskN, a: $VectorN<S>, b: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new(
+                    mask.select(a.x, b.x),
+                    mask.select(a.y, b.y),
+                    mask.select(a.z, b.z),
+                )
+            }
+
+            /// Apply `f` to every component of every lane.
+            #[inline]
+            pub fn map<U, F: Fn(S) -> U>(self, f: F) -> $VectorN<U> {
+                $VectorN {
+                    x: self.x.map(&f),
+                    y: self.y.map(&f),
+                    z: self.z.map(&f),
+                }
+            }
+
+            /// Apply `f` to the matching components of every lane of `self` and
+            /// `other`.
+            #[inline]
+            pub fn zip<U, F: Fn(S, S) -> U>(self, other: $VectorN<S>, f: F) -> $VectorN<U> {
+                $VectorN {
+                    x: zip(self.x, other.x, &f),
+                    y: zip(self.y, other.y, &f),
+                    z: zip(self.z, other.z, &f),
+                }
+            }
+
+            /// Set the lanes of the mask where `f` holds for every component of
+            /// `self` and `other`.
+            #[inline]
+            fn lanes_all<F: Fn(S, S) -> bool>(self, other: $VectorN<S>, f: F) -> $MaskN {
+                $MaskN::from(array::from_fn(|i| {
+                    f(self.x[i], other.x[i]) && f(self.y[i], other.y[i]) && f(self.z[i], other.z[i])
+                }))
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where every component is equal.
+            #[inline]
+            pub fn lanes_eq(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialEq,
+            {
+                self.lanes_all(other, |a, b| a == b)
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where any component differs.
+            #[inline]
+            pub fn lanes_ne(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialEq,
+            {
+                !self.lanes_eq(other)
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where every component of `self` is less than the matching
+            /// component of `other`.
+            #[inline]
+            pub fn lt(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialOrd,
+            {
+                self.lanes_all(other, |a, b| a < b)
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where every component of `self` is less than or equal to the matching
+            /// component of `other`.
+            #[inline]
+            pub fn le(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialOrd,
+            {
+                self.lanes_all(other, |a, b| a <= b)
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where every component of `self` is greater than the matching
+            /// component of `other`.
+            #[inline]
+            pub fn gt(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialOrd,
+            {
+                self.lanes_all(other, |a, b| a > b)
+            }
+
+            /// Compare the lanes of `self` and `other`, setting the lanes of the
+            /// mask where every component of `self` is greater than or equal to the matching
+            /// component of `other`.
+            #[inline]
+            pub fn ge(self, other: $VectorN<S>) -> $MaskN
+            where
+                S: PartialOrd,
+            {
+                self.lanes_all(other, |a, b| a >= b)
+            }
+        }
+
+        impl<S: BaseNum> $VectorN<S> {
+            /// The cross products of the lanes.
+            #[inline]
+            pub fn cross(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new(
+                    array::from_fn(|i| (self.y[i] * other.z[i]) - (self.z[i] * other.y[i])),
+                    array::from_fn(|i| (self.z[i] * other.x[i]) - (self.x[i] * other.z[i])),
+                    array::from_fn(|i| (self.x[i] * other.y[i]) - (self.y[i] * other.x[i])),
+                )
+            }
+
+            /// The dot products of the lanes.
+            #[inline]
+            pub fn dot(self, other: $VectorN<S>) -> $ScalarN<S> {
+                let p = self.mul_element_wise(other);
+                $ScalarN(p.x) + ($ScalarN(p.y) + $ScalarN(p.z))
+            }
+
+            /// The squared magnitudes of the lanes.
+            #[inline]
+            pub fn magnitude2(self) -> $ScalarN<S> {
+                self.dot(self)
+            }
+
+            /// The squared distances between the lanes of `self` and `other`.
+            #[inline]
+            pub fn distance2(self, other: $VectorN<S>) -> $ScalarN<S> {
+                (other - self).magnitude2()
+            }
+
+            /// Interpolate linearly between every lane of `self` and `other`,
+            /// by the matching lane of `amount`.
+            #[inline]
+            pub fn lerp(self, other: $VectorN<S>, amount: $ScalarN<S>) -> $VectorN<S> {
+                self + ((other - self) * amount)
+            }
+
+            /// Project every lane onto the matching lane of `other`.
+            #[inline]
+            pub fn project_on(self, other: $VectorN<S>) -> $VectorN<S> {
+                other * (self.dot(other) / other.magnitude2())
+            }
+
+            /// The parts of the lanes perpendicular to the matching lanes of
+            /// `other`.
+            #[inline]
+            pub fn reject_from(self, other: $VectorN<S>) -> $VectorN<S> {
+                self - self.project_on(other)
+            }
+
+            /// Reflect every lane about the matching lane of `normal`, which
+            /// should be normalized.
+            #[inline]
+            pub fn reflect(self, normal: $VectorN<S>) -> $VectorN<S> {
+                let two = $ScalarN::one() + $ScalarN::one();
+                self - normal * (two * self.dot(normal))
+            }
+
+            /// Flip the lanes that don't face away from the matching lanes of
+            /// `reference`, like `InnerSpace::faceforward`.
+            #[inline]
+            pub fn faceforward(self, incident: $VectorN<S>, reference: $VectorN<S>) -> $VectorN<S> {
+                let facing = reference.dot(incident).lt_element_wise(S::zero());
+                $VectorN::select(facing, self, $VectorN::zero() - self)
+            }
+        }
+
+        impl<S: BaseFloat> $VectorN<S> {
+            /// The magnitudes of the lanes.
+            #[inline]
+            pub fn magnitude(self) -> $ScalarN<S> {
+                self.magnitude2().sqrt()
+            }
+
+            /// The distances between the lanes of `self` and `other`.
+            #[inline]
+            pub fn distance(self, other: $VectorN<S>) -> $ScalarN<S> {
+                self.distance2(other).sqrt()
+            }
+
+            /// The angles between the lanes of `self` and `other`.
+            #[inline]
+            pub fn angle(self, other: $VectorN<S>) -> Rad<$ScalarN<S>> {
+                Rad((self.dot(other) / (self.magnitude() * other.magnitude())).acos())
+            }
+
+            /// Scale every lane to a magnitude of one.
+            #[inline]
+            pub fn normalize(self) -> $VectorN<S> {
+                self.normalize_to($ScalarN::one())
+            }
+
+            /// Scale every lane to the matching lane of `magnitude`.
+            #[inline]
+            pub fn normalize_to(self, magnitude: $ScalarN<S>) -> $VectorN<S> {
+                self * (magnitude / self.magnitude())
+            }
+
+            /// Scale down the lanes that are longer than the matching lane of
+            /// `max`.
+            #[inline]
+            pub fn clamp_magnitude(self, max: $ScalarN<S>) -> $VectorN<S> {
+                let longer = self.magnitude2().gt_element_wise(max * max);
+                $VectorN::select(longer, self.normalize_to(max), self)
+            }
+
+            /// Refract every lane through the matching lane of `normal`, like
+            /// `InnerSpace::refract`. Lanes with total internal reflection are
+            /// set to zero, like GLSL's `refract`.
+            pub fn refract(self, normal: $VectorN<S>, eta: $ScalarN<S>) -> $VectorN<S> {
+                let one = $ScalarN::one();
+                let cos_i = self.dot(normal);
+                let k = one - eta * eta * (one - cos_i * cos_i);
+                let reflected = k.lt_element_wise(S::zero());
+                let refracted = self * eta - normal * (eta * cos_i + k.sqrt());
+                $VectorN::select(reflected, $VectorN::zero(), refracted)
+            }
+
+            /// Transform every lane as a point by a matrix, dividing by the
+            /// resulting `w` component like `Transform::transform_point`.
+            pub fn transform_point(self, matrix: &Matrix4<S>) -> $VectorN<S> {
+                let m = matrix;
+                let row = |r: usize, v: $VectorN<S>| -> [S; $n] {
+                    array::from_fn(|i| m[0][r] * v.x[i] + (m[1][r] * v.y[i] + (m[2][r] * v.z[i] + m[3][r])))
+                };
+                let w = row(3, self);
+                let inv_w = w.map(|w| S::one() / w);
+                $VectorN::new(row(0, self), row(1, self), row(2, self)) * inv_w
+            }
+
+            /// Transform every lane as a vector by a matrix, ignoring its
+            /// translation.
+            pub fn transform_vector(self, matrix: &Matrix4<S>) -> $VectorN<S> {
+                let m = Matrix3::from_cols(matrix.x.truncate(), matrix.y.truncate(), matrix.z.truncate());
+                m * self
+            }
+        }
+
+        impl<S: Copy> From<[Vector3<S>; $n]> for $VectorN<S> {
+            #[inline]
+            fn from(vectors: [Vector3<S>; $n]) -> $VectorN<S> {
+                $VectorN::from_vectors(vectors)
+            }
+        }
+
+        impl<S: Copy> From<$VectorN<S>> for [Vector3<S>; $n] {
+            #[inline]
+            fn from(v: $VectorN<S>) -> [Vector3<S>; $n] {
+                v.to_vectors()
+            }
+        }
+
+        impl_operator!(<S: BaseNum> Add<$VectorN<S> > for $VectorN<S> {
+            fn add(lhs, rhs) -> $VectorN<S> { lhs.zip(rhs.clone(), |a, b| a + b) }
+        });
+        impl_assignment_operator!(<S: BaseNum> AddAssign<$VectorN<S> > for $VectorN<S> {
+            fn add_assign(&mut self, other) { *self = *self + other; }
+        });
+
+        impl_operator!(<S: BaseNum> Sub<$VectorN<S> > for $VectorN<S> {
+            fn sub(lhs, rhs) -> $VectorN<S> { lhs.zip(rhs.clone(), |a, b| a - b) }
+        });
+        impl_assignment_operator!(<S: BaseNum> SubAssign<$VectorN<S> > for $VectorN<S> {
+            fn sub_assign(&mut self, other) { *self = *self - other; }
+        });
+
+        impl_operator!(<S: BaseNum> Mul<S> for $VectorN<S> {
+            fn mul(vector, scalar) -> $VectorN<S> { vector.map(|a| a * scalar) }
+        });
+        impl_assignment_operator!(<S: BaseNum> MulAssign<S> for $VectorN<S> {
+            fn mul_assign(&mut self, scalar) { *self = *self * scalar; }
+        });
+
+        impl_operator!(<S: BaseNum> Div<S> for $VectorN<S> {
+            fn div(vector, scalar) -> $VectorN<S> { vector.map(|a| a / scalar) }
+        });
+        impl_assignment_operator!(<S: BaseNum> DivAssign<S> for $VectorN<S> {
+            fn div_assign(&mut self, scalar) { *self = *self / scalar; }
+        });
+
+        impl_operator!(<S: BaseNum> Mul<[S; $n]> for $VectorN<S> {
+            fn mul(vector, scalars) -> $VectorN<S> {
+                $VectorN::new(
+                    zip(vector.x, scalars.clone(), |a, b| a * b),
+                    zip(vector.y, scalars.clone(), |a, b| a * b),
+                    zip(vector.z, scalars.clone(), |a, b| a * b),
+                )
+            }
+        });
+
+        impl_operator!(<S: BaseNum> Div<[S; $n]> for $VectorN<S> {
+            fn div(vector, scalars) -> $VectorN<S> {
+                $VectorN::new(
+                    zip(vector.x, scalars.clone(), |a, b| a / b),
+                    zip(vector.y, scalars.clone(), |a, b| a / b),
+                    zip(vector.z, scalars.clone(), |a, b| a / b),
+                )
+            }
+        });
+
+        impl_operator!(<S: BaseNum> Mul<$ScalarN<S> > for $VectorN<S> {
+            fn mul(vector, scalar) -> $VectorN<S> { vector * scalar.0 }
+        });
+        impl_assignment_operator!(<S: BaseNum> MulAssign<$ScalarN<S> > for $VectorN<S> {
+            fn mul_assign(&mut self, scalar) { *self = *self * scalar; }
+        });
+
+        impl_operator!(<S: BaseNum> Div<$ScalarN<S> > for $VectorN<S> {
+            fn div(vector, scalar) -> $VectorN<S> { vector / scalar.0 }
+        });
+        impl_assignment_operator!(<S: BaseNum> DivAssign<$ScalarN<S> > for $VectorN<S> {
+            fn div_assign(&mut self, scalar) { *self = *self / scalar; }
+        });
+
+        impl_operator!(<S: BaseNum> Rem<$ScalarN<S> > for $VectorN<S> {
+            fn rem(vector, scalar) -> $VectorN<S> {
+                $VectorN::new(
+                    zip(vector.x, scalar.0, |a, b| a % b),
+                    zip(vector.y, scalar.0, |a, b| a % b),
+                    zip(vector.z, scalar.0, |a, b| a % b),
+                )
+            }
+        });
+        impl_assignment_operator!(<S: BaseNum> RemAssign<$ScalarN<S> > for $VectorN<S> {
+            fn rem_assign(&mut self, scalar) { *self = *self % scalar; }
+        });
+
+        impl<S: Neg<Output = S> + Copy> Neg for $VectorN<S> {
+            type Output = $VectorN<S>;
+
+            #[inline]
+            fn neg(self) -> $VectorN<S> {
+                self.map(|a| -a)
+            }
+        }
+
+        impl<S: BaseNum> Zero for $VectorN<S> {
+            #[inline]
+            fn zero() -> $VectorN<S> {
+                $VectorN::splat(Vector3::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == $VectorN::zero()
+            }
+        }
+
+        impl<S: BaseNum> iter::Sum<$VectorN<S>> for $VectorN<S> {
+            #[inline]
+            fn sum<I: Iterator<Item = $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), Add::add)
+            }
+        }
+
+        impl<'a, S: 'a + BaseNum> iter::Sum<&'a $VectorN<S>> for $VectorN<S> {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a $VectorN<S>>>(iter: I) -> $VectorN<S> {
+                iter.fold($VectorN::zero(), Add::add)
+            }
+        }
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        impl<S: BaseNum> ElementWise for $VectorN<S> {
+            #[inline] fn add_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self.zip(rhs, |a, b| a + b) }
+            #[inline] fn sub_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self.zip(rhs, |a, b| a - b) }
+            #[inline] fn mul_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self.zip(rhs, |a, b| a * b) }
+            #[inline] fn div_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self.zip(rhs, |a, b| a / b) }
+            #[inline] fn rem_element_wise(self, rhs: $VectorN<S>) -> $VectorN<S> { self.zip(rhs, |a, b| a % b) }
+
+            #[inline] fn add_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.add_element_wise(rhs); }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.sub_element_wise(rhs); }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.mul_element_wise(rhs); }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.div_element_wise(rhs); }
+            #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { *self = self.rem_element_wise(rhs); }
+        }
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        impl<S: BaseNum> ElementWise<$ScalarN<S>> for $VectorN<S> {
+            #[inline] fn add_element_wise(self, rhs: $ScalarN<S>) -> $VectorN<S> { self.add_element_wise($VectorN::new(rhs.0, rhs.0, rhs.0)) }
+            #[inline] fn sub_element_wise(self, rhs: $ScalarN<S>) -> $VectorN<S> { self.sub_element_wise($VectorN::new(rhs.0, rhs.0, rhs.0)) }
+            #[inline] fn mul_element_wise(self, rhs: $ScalarN<S>) -> $VectorN<S> { self.mul_element_wise($VectorN::new(rhs.0, rhs.0, rhs.0)) }
+            #[inline] fn div_element_wise(self, rhs: $ScalarN<S>) -> $VectorN<S> { self.div_element_wise($VectorN::new(rhs.0, rhs.0, rhs.0)) }
+            #[inline] fn rem_element_wise(self, rhs: $ScalarN<S>) -> $VectorN<S> { self.rem_element_wise($VectorN::new(rhs.0, rhs.0, rhs.0)) }
+
+            #[inline] fn add_assign_element_wise(&mut self, rhs: $ScalarN<S>) { *self = self.add_element_wise(rhs); }
+            #[inline] fn sub_assign_element_wise(&mut self, rhs: $ScalarN<S>) { *self = self.sub_element_wise(rhs); }
+            #[inline] fn mul_assign_element_wise(&mut self, rhs: $ScalarN<S>) { *self = self.mul_element_wise(rhs); }
+            #[inline] fn div_assign_element_wise(&mut self, rhs: $ScalarN<S>) { *self = self.div_element_wise(rhs); }
+            #[inline] fn rem_assign_element_wise(&mut self, rhs: $ScalarN<S>) { *self = self.rem_element_wise(rhs); }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for Matrix3<S> {
+            fn mul(matrix, vector) -> $VectorN<S> {{
+                let (m, v) = (matrix, vector);
+                let row = |r: usize| -> [S; $n] {
+                    array::from_fn(|i| m[0][r] * v.x[i] + (m[1][r] * v.y[i] + m[2][r] * v.z[i]))
+                };
+                $VectorN::new(row(0), row(1), row(2))
+            }}
+        });
+
+        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for Quaternion<S> {
+            fn mul(quaternion, vector) -> $VectorN<S> {
+                $QuaternionN::splat(quaternion.clone()) * vector.clone()
+            }
+        });
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $VectorN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                (0..$n).all(|i| Vector3::abs_diff_eq(&self.lane(i), &other.lane(i), epsilon))
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $VectorN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                (0..$n).all(|i| {
+                    Vector3::relative_eq(&self.lane(i), &other.lane(i), epsilon, max_relative)
+                })
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $VectorN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                (0..$n).all(|i| Vector3::ulps_eq(&self.lane(i), &other.lane(i), epsilon, max_ulps))
+            }
+        }
+
+        #[doc = concat!(
+            "A wide quaternion, holding ", $n, " quaternions in structure-of-arrays ",
+            "layout."
+        )]
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $QuaternionN<S> {
+            /// The vector parts of the lanes.
+            pub v: $VectorN<S>,
+            /// The scalar parts of the lanes.
+            pub s: [S; $n],
+        }
+
+        impl<S: Copy> $QuaternionN<S> {
+            /// Construct a wide quaternion from the scalar and vector parts of its
+            /// lanes.
+            #[inline]
+            pub const fn from_sv(s: [S; $n], v: $VectorN<S>) -> $QuaternionN<S> {
+                $QuaternionN { v, s }
+            }
+
+            /// Construct a wide quaternion with every lane set to `q`.
+            #[inline]
+            pub fn splat(q: Quaternion<S>) -> $QuaternionN<S> {
+                $QuaternionN::from_sv([q.s; $n], $VectorN::splat(q.v))
+            }
+
+            /// Construct a wide quaternion from its lanes.
+            #[inline]
+            pub fn from_quaternions(quaternions: [Quaternion<S>; $n]) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(
+                    quaternions.map(|q| q.s),
+                    $VectorN::from_vectors(quaternions.map(|q| q.v)),
+                )
+            }
+
+            /// Split the wide quaternion into its lanes.
+            #[inline]
+            pub fn to_quaternions(self) -> [Quaternion<S>; $n] {
+                array::from_fn(|i| self.lane(i))
+            }
+
+            /// The quaternion in lane `i`.
+            #[inline]
+            pub fn lane(&self, i: usize) -> Quaternion<S> {
+                Quaternion::from_sv(self.s[i], self.v.lane(i))
+            }
+
+            /// Replace the quaternion in lane `i`.
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, q: Quaternion<S>) {
+                self.s[i] = q.s;
+                self.v.set_lane(i, q.v);
+            }
+        }
+
+        impl<S: BaseFloat> $QuaternionN<S> {
+            /// The conjugates of the lanes.
+            #[inline]
+            pub fn conjugate(self) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(self.s, -self.v)
+            }
+
+            /// Rotate every lane of `v` by the matching lane of the quaternion.
+            #[inline]
+            pub fn rotate_vector(self, v: $VectorN<S>) -> $VectorN<S> {
+                self * v
+            }
+
+            /// The dot products of the lanes.
+            #[inline]
+            pub fn dot(self, other: $QuaternionN<S>) -> $ScalarN<S> {
+                $ScalarN(self.s) * $ScalarN(other.s) + self.v.dot(other.v)
+            }
+
+            /// The squared magnitudes of the lanes.
+            #[inline]
+            pub fn magnitude2(self) -> $ScalarN<S> {
+                self.dot(self)
+            }
+
+            /// The magnitudes of the lanes.
+            #[inline]
+            pub fn magnitude(self) -> $ScalarN<S> {
+                self.magnitude2().sqrt()
+            }
+
+            /// Scale every lane to a magnitude of one.
+            #[inline]
+            pub fn normalize(self) -> $QuaternionN<S> {
+                self * ($ScalarN::one() / self.magnitude())
+            }
+        }
+
+        impl<S: Copy> From<[Quaternion<S>; $n]> for $QuaternionN<S> {
+            #[inline]
+            fn from(quaternions: [Quaternion<S>; $n]) -> $QuaternionN<S> {
+                $QuaternionN::from_quaternions(quaternions)
+            }
+        }
+
+        impl<S: Copy> From<$QuaternionN<S>> for [Quaternion<S>; $n] {
+            #[inline]
+            fn from(q: $QuaternionN<S>) -> [Quaternion<S>; $n] {
+                q.to_quaternions()
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Add<$QuaternionN<S> > for $QuaternionN<S> {
+            fn add(lhs, rhs) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(($ScalarN(lhs.s) + $ScalarN(rhs.s)).0, lhs.v + rhs.v)
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Sub<$QuaternionN<S> > for $QuaternionN<S> {
+            fn sub(lhs, rhs) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(($ScalarN(lhs.s) - $ScalarN(rhs.s)).0, lhs.v - rhs.v)
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Neg for $QuaternionN<S> {
+            fn neg(quaternion) -> $QuaternionN<S> {
+                $QuaternionN::from_sv((-$ScalarN(quaternion.s)).0, -quaternion.v)
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Mul<$ScalarN<S> > for $QuaternionN<S> {
+            fn mul(quaternion, scalar) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(($ScalarN(quaternion.s) * scalar).0, quaternion.v * scalar)
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Div<$ScalarN<S> > for $QuaternionN<S> {
+            fn div(quaternion, scalar) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(($ScalarN(quaternion.s) / scalar).0, quaternion.v / scalar)
+            }
+        });
+
+        impl_operator!(<S: BaseFloat> Rem<$ScalarN<S> > for $QuaternionN<S> {
+            fn rem(quaternion, scalar) -> $QuaternionN<S> {
+                $QuaternionN::from_sv(($ScalarN(quaternion.s) % scalar).0, quaternion.v % scalar)
+            }
+        });
+
+        impl<S: BaseFloat> Zero for $QuaternionN<S> {
+            #[inline]
+            fn zero() -> $QuaternionN<S> {
+                $QuaternionN::splat(Quaternion::zero())
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == $QuaternionN::zero()
+            }
+        }
+
+        impl<S: BaseFloat> iter::Sum<$QuaternionN<S>> for $QuaternionN<S> {
+            #[inline]
+            fn sum<I: Iterator<Item = $QuaternionN<S>>>(iter: I) -> $QuaternionN<S> {
+                iter.fold($QuaternionN::zero(), Add::add)
+            }
+        }
+
+        impl<'a, S: 'a + BaseFloat> iter::Sum<&'a $QuaternionN<S>> for $QuaternionN<S> {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a $QuaternionN<S>>>(iter: I) -> $QuaternionN<S> {
+                iter.fold($QuaternionN::zero(), Add::add)
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$QuaternionN<S> > for $QuaternionN<S> {
+            fn mul(lhs, rhs) -> $QuaternionN<S> {{
+                let (a, b) = (lhs, rhs);
+                let s = array::from_fn(|i| {
+                    a.s[i] * b.s[i] - a.v.x[i] * b.v.x[i] - a.v.y[i] * b.v.y[i] - a.v.z[i] * b.v.z[i]
+                });
+                let x = array::from_fn(|i| {
+                    a.s[i] * b.v.x[i] + a.v.x[i] * b.s[i] + a.v.y[i] * b.v.z[i] - a.v.z[i] * b.v.y[i]
+                });
+                let y = array::from_fn(|i| {
+                    a.s[i] * b.v.y[i] + a.v.y[i] * b.s[i] + a.v.z[i] * b.v.x[i] - a.v.x[i] * b.v.z[i]
+                });
+                let z = array::from_fn(|i| {
+                    a.s[i] * b.v.z[i] + a.v.z[i] * b.s[i] + a.v.x[i] * b.v.y[i] - a.v.y[i] * b.v.x[i]
+                });
+                $QuaternionN::from_sv(s, $VectorN::new(x, y, z))
+            }}
+        });
+
+        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $QuaternionN<S> {
+            fn mul(lhs, rhs) -> $VectorN<S> {{
+                let two: S = cast(2i8).unwrap();
+                let tmp = lhs.v.cross(rhs.clone()) + (rhs.clone() * lhs.s);
+                (lhs.v.cross(tmp) * two) + rhs.clone()
+            }}
+        });
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $QuaternionN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                (0..$n).all(|i| Quaternion::abs_diff_eq(&self.lane(i), &other.lane(i), epsilon))
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $QuaternionN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                (0..$n).all(|i| {
+                    Quaternion::relative_eq(&self.lane(i), &other.lane(i), epsilon, max_relative)
+                })
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $QuaternionN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                (0..$n).all(|i| Quaternion::ulps_eq(&self.lane(i), &other.lane(i), epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+impl_wide!(Scalarx4, Vector3x4, Quaternionx4, BVec4, 4);
+impl_wide!(Scalarx8, Vector3x8, Quaternionx8, Mask8, 8);
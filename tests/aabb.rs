@@ -0,0 +1,174 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_new_orders_corners() {
+    let aabb = Aabb2::new(Point2::new(3, -1), Point2::new(1, 4));
+    assert_eq!(aabb.min, Point2::new(1, -1));
+    assert_eq!(aabb.max, Point2::new(3, 4));
+    assert_eq!(aabb.extents(), Vector2::new(2, 5));
+    assert_eq!(aabb.area(), 10);
+    assert_eq!(aabb.center(), Point2::new(2, 1));
+}
+
+#[test]
+fn test_from_points() {
+    let points = [
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-1.0, 5.0, 0.0),
+        Point3::new(0.0, 0.0, 4.0),
+    ];
+    let aabb = Aabb3::from_points(points.iter().cloned()).unwrap();
+    assert_eq!(aabb.min, Point3::new(-1.0, 0.0, 0.0));
+    assert_eq!(aabb.max, Point3::new(1.0, 5.0, 4.0));
+    assert_eq!(aabb.volume(), 40.0);
+    assert_eq!(aabb.surface_area(), 2.0 * (10.0 + 20.0 + 8.0));
+    assert!(points.iter().all(|&p| aabb.contains(p)));
+
+    assert_eq!(Aabb3::<f32>::from_points(Vec::new()), None);
+}
+
+#[test]
+fn test_grow() {
+    let mut aabb = Aabb2::from_point(Point2::new(0, 0));
+    aabb.grow(Point2::new(2, -3));
+    assert_eq!(aabb, Aabb2::new(Point2::new(0, -3), Point2::new(2, 0)));
+
+    aabb.extend(vec![Point2::new(1, 1), Point2::new(-4, 0)]);
+    assert_eq!(aabb, Aabb2::new(Point2::new(-4, -3), Point2::new(2, 1)));
+}
+
+#[test]
+fn test_union_intersection() {
+    let a = Aabb2::new(Point2::new(0, 0), Point2::new(4, 4));
+    let b = Aabb2::new(Point2::new(2, 3), Point2::new(6, 8));
+    let c = Aabb2::new(Point2::new(5, 0), Point2::new(6, 3));
+
+    assert_eq!(
+        a.union(&b),
+        Aabb2::new(Point2::new(0, 0), Point2::new(6, 8))
+    );
+    assert_eq!(
+        a.intersection(&b),
+        Some(Aabb2::new(Point2::new(2, 3), Point2::new(4, 4)))
+    );
+    assert_eq!(a.intersection(&c), None);
+    assert!(a.overlaps(&b) && b.overlaps(&a));
+    assert!(!a.overlaps(&c) && b.overlaps(&c));
+
+    // Boxes that share an edge overlap
+    let d = Aabb2::new(Point2::new(4, 1), Point2::new(5, 2));
+    assert_eq!(
+        a.intersection(&d),
+        Some(Aabb2::new(Point2::new(4, 1), Point2::new(4, 2)))
+    );
+}
+
+#[test]
+fn test_contains() {
+    let aabb = Aabb3::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2));
+    assert!(aabb.contains(Point3::new(1, 1, 1)));
+    assert!(aabb.contains(Point3::new(2, 0, 2)));
+    assert!(!aabb.contains(Point3::new(1, 3, 1)));
+
+    assert!(aabb.contains_aabb(&Aabb3::new(Point3::new(0, 1, 0), Point3::new(1, 2, 1))));
+    assert!(!aabb.contains_aabb(&Aabb3::new(Point3::new(0, 1, 0), Point3::new(1, 3, 1))));
+}
+
+#[test]
+fn test_transform_matches_corners() {
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.5, 2.0), Point3::new(3.0, 1.5, 4.0));
+    let decomposed = Decomposed {
+        scale: 1.5,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(50.0)),
+        disp: Vector3::new(1.0, -2.0, 0.5),
+    };
+    let matrices = [
+        Matrix4::from(decomposed),
+        Matrix4::from_nonuniform_scale(-2.0, 1.0, 0.5) * Matrix4::from_angle_z(Deg(30.0)),
+    ];
+
+    for &m in &matrices {
+        let corners = aabb
+            .corners()
+            .iter()
+            .map(|&p| m.transform_point(p))
+            .collect::<Vec<_>>();
+        let expected = Aabb3::from_points(corners).unwrap();
+        let transformed = aabb.transform(m);
+        assert_relative_eq!(transformed.min, expected.min, epsilon = 1e-12);
+        assert_relative_eq!(transformed.max, expected.max, epsilon = 1e-12);
+    }
+    assert_eq!(
+        aabb.transform(decomposed),
+        aabb.transform(Matrix4::from(decomposed))
+    );
+}
+
+#[test]
+fn test_transform_2d() {
+    let aabb = Aabb2::new(Point2::new(0, 0), Point2::new(2, 1));
+
+    // Rotate a quarter turn and translate, keeping integer coordinates
+    let m = Matrix3::new(0, 1, 0, -1, 0, 0, 5, 5, 1);
+    assert_eq!(
+        aabb.transform(m),
+        Aabb2::new(Point2::new(4, 5), Point2::new(5, 7))
+    );
+
+    let rotation = Decomposed {
+        scale: 1.0,
+        rot: Basis2::from_angle(Deg(45.0)),
+        disp: Vector2::new(0.0, 0.0),
+    };
+    let aabb = Aabb2::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0));
+    let transformed = aabb.transform(rotation);
+    let r = 2.0f64.sqrt();
+    assert_relative_eq!(transformed.min, Point2::new(-r, -r), epsilon = 1e-12);
+    assert_relative_eq!(transformed.max, Point2::new(r, r), epsilon = 1e-12);
+}
+
+#[test]
+fn test_closest_point() {
+    let aabb = Aabb2::new(Point2::new(0, 0), Point2::new(4, 2));
+    assert_eq!(aabb.closest_point(Point2::new(1, 1)), Point2::new(1, 1));
+    assert_eq!(aabb.closest_point(Point2::new(-3, 1)), Point2::new(0, 1));
+    assert_eq!(aabb.closest_point(Point2::new(7, 6)), Point2::new(4, 2));
+    assert_eq!(aabb.distance2(Point2::new(7, 6)), 25);
+    assert_eq!(aabb.distance2(Point2::new(2, 2)), 0);
+
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    assert_eq!(
+        aabb.closest_point(Point3::new(0.5, 3.0, -2.0)),
+        Point3::new(0.5, 1.0, -1.0)
+    );
+    assert_eq!(aabb.distance2(Point3::new(0.5, 3.0, -2.0)), 5.0);
+}
+
+#[test]
+fn test_distance2_unsigned() {
+    let aabb = Aabb2::new(Point2::new(2u32, 3), Point2::new(5, 6));
+    assert_eq!(aabb.distance2(Point2::new(0, 0)), 13);
+    assert_eq!(aabb.distance2(Point2::new(8, 4)), 9);
+    assert_eq!(aabb.distance2(Point2::new(3, 4)), 0);
+
+    let aabb = Aabb3::new(Point3::new(1u8, 1, 1), Point3::new(2, 2, 2));
+    assert_eq!(aabb.distance2(Point3::new(0, 4, 2)), 5);
+}
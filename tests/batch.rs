@@ -0,0 +1,263 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn points() -> [Point3<f32>; 4] {
+    [
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-4.0, 0.5, 2.0),
+        Point3::new(0.0, -1.0, 0.25),
+        Point3::new(7.0, 3.0, -2.0),
+    ]
+}
+
+fn vectors() -> [Vector3<f32>; 4] {
+    points().map(|p| p.to_vec())
+}
+
+fn decomposed() -> Decomposed<Vector3<f32>, Quaternion<f32>> {
+    Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: vec3(1.0, -2.0, 3.0),
+    }
+}
+
+#[test]
+fn test_matrix4_points_and_vectors() {
+    let m = Matrix4::from_translation(vec3(1.0, 2.0, 3.0)) * Matrix4::from_angle_y(Deg(45.0));
+
+    let mut points = points();
+    m.transform_points_in_place(&mut points);
+    for (p, &q) in points.iter().zip(&self::points()) {
+        assert_eq!(*p, m.transform_point(q));
+    }
+
+    let mut output = [Vector3::zero(); 4];
+    m.transform_vectors_into(&vectors(), &mut output);
+    for (v, &u) in output.iter().zip(&vectors()) {
+        assert_eq!(*v, m.transform_vector(u));
+    }
+}
+
+#[test]
+fn test_matrix3_points2() {
+    let m = Matrix3::from_translation(vec2(1.0, -1.0)) * Matrix3::from_angle_z(Deg(90.0));
+    let input = [Point2::new(1.0f32, 0.0), Point2::new(0.0, 2.0)];
+    let mut output = [Point2::origin(); 2];
+    m.transform_points_into(&input, &mut output);
+    assert_eq!(output[0], m.transform_point(input[0]));
+    assert_eq!(output[1], m.transform_point(input[1]));
+
+    let mut vectors = [vec3(1.0f32, 0.0, 0.0), vec3(0.0, 1.0, 0.0)];
+    BatchTransform::<Point3<f32>>::transform_vectors_in_place(&m, &mut vectors);
+    assert_eq!(vectors[0], m * vec3(1.0, 0.0, 0.0));
+    assert_eq!(vectors[1], m * vec3(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_decomposed() {
+    let t = decomposed();
+    let mut output = [Point3::origin(); 4];
+    t.transform_points_into(&points(), &mut output);
+    for (p, &q) in output.iter().zip(&points()) {
+        assert_eq!(*p, t.transform_point(q));
+    }
+
+    let mut vectors = vectors();
+    t.transform_vectors_in_place(&mut vectors);
+    for (v, &u) in vectors.iter().zip(&self::vectors()) {
+        assert_eq!(*v, t.transform_vector(u));
+    }
+}
+
+#[test]
+fn test_quaternion() {
+    let q = Quaternion::from_angle_z(Deg(90.0f32));
+    let mut points = [Point3::new(1.0, 0.0, 5.0)];
+    q.transform_points_in_place(&mut points);
+    assert_relative_eq!(points[0], Point3::new(0.0, 1.0, 5.0), epsilon = 1e-6);
+
+    let mut output = [Vector3::zero(); 4];
+    q.transform_vectors_into(&vectors(), &mut output);
+    for (v, &u) in output.iter().zip(&vectors()) {
+        assert_eq!(*v, q * u);
+    }
+}
+
+/// Enough points for two full chunks of four and a partial one.
+fn many_points() -> Vec<Point3<f32>> {
+    (0..11)
+        .map(|i| {
+            let t = i as f32;
+            Point3::new(t * 0.7 - 3.0, 2.0 - t * t * 0.1, t * 1.3 + 0.5)
+        })
+        .collect()
+}
+
+fn check_wide<T: BatchTransform<Point3<f32>> + Transform<Point3<f32>>>(t: &T) {
+    let points = many_points();
+    let vectors: Vec<Vector3<f32>> = points.iter().map(|p| p.to_vec()).collect();
+
+    let mut in_place = points.clone();
+    t.transform_points_in_place(&mut in_place);
+    let mut output = vec![Point3::origin(); points.len()];
+    t.transform_points_into(&points, &mut output);
+    for ((p, q), &r) in in_place.iter().zip(&output).zip(&points) {
+        assert_eq!(*p, t.transform_point(r));
+        assert_eq!(*q, t.transform_point(r));
+    }
+
+    let mut in_place = vectors.clone();
+    t.transform_vectors_in_place(&mut in_place);
+    let mut output = vec![Vector3::zero(); vectors.len()];
+    t.transform_vectors_into(&vectors, &mut output);
+    for ((v, w), &u) in in_place.iter().zip(&output).zip(&vectors) {
+        assert_eq!(*v, t.transform_vector(u));
+        assert_eq!(*w, t.transform_vector(u));
+    }
+}
+
+#[test]
+fn test_wide_chunks() {
+    let affine = Matrix4::from_translation(vec3(1.0, 2.0, 3.0))
+        * Matrix4::from_nonuniform_scale(2.0, 0.5, -1.0)
+        * Matrix4::from_angle_x(Deg(20.0));
+    let projective = Matrix4::from(PerspectiveFov {
+        fovy: Deg(60.0f32).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    }) * Matrix4::from_translation(vec3(0.0, 0.0, -20.0));
+    let rotation = Quaternion::from_axis_angle(vec3(1.0, -2.0, 0.5).normalize(), Deg(70.0));
+
+    check_wide(&affine);
+    check_wide(&projective);
+    check_wide(&Matrix3::from_angle_z(Deg(35.0)));
+    check_wide(&decomposed());
+    check_wide(&Decomposed {
+        scale: 0.5,
+        rot: Basis3::from(rotation),
+        disp: vec3(-1.0, 0.0, 4.0),
+    });
+
+    let points = many_points();
+    let mut rotated = points.clone();
+    rotation.transform_points_in_place(&mut rotated);
+    for (p, &q) in rotated.iter().zip(&points) {
+        assert_eq!(*p, rotation.rotate_point(q));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_into_length_mismatch() {
+    let mut output = [Point3::origin(); 3];
+    Matrix4::<f32>::identity().transform_points_into(&points(), &mut output);
+}
+
+#[test]
+fn test_strided() {
+    // Interleaved position and normal, followed by a trailing partial vertex.
+    let mut buffer = Vec::new();
+    for (p, n) in points().iter().zip(&vectors()) {
+        for c in [p.x, p.y, p.z, n.x, n.y, n.z] {
+            buffer.extend_from_slice(&c.to_ne_bytes());
+        }
+    }
+    buffer.extend_from_slice(&[0; 8]);
+
+    let t = decomposed();
+    t.transform_points_strided(&mut buffer, 0, 24);
+    t.transform_vectors_strided(&mut buffer, 12, 24);
+
+    let read = |start: usize| {
+        let c = |i: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&buffer[start + 4 * i..start + 4 * i + 4]);
+            f32::from_ne_bytes(bytes)
+        };
+        vec3(c(0), c(1), c(2))
+    };
+    for (i, (&p, &n)) in points().iter().zip(&vectors()).enumerate() {
+        assert_eq!(read(24 * i), t.transform_point(p).to_vec());
+        assert_eq!(read(24 * i + 12), t.transform_vector(n));
+    }
+    assert_eq!(&buffer[96..], &[0; 8]);
+}
+
+#[test]
+fn test_strided_wide_chunks() {
+    // Positions padded to 16 bytes, starting after a 4 byte header, with room
+    // for the position of the last vertex but not its padding.
+    let points = many_points();
+    let mut buffer = vec![0xAB; 4];
+    for p in &points {
+        for c in [p.x, p.y, p.z, 1.0] {
+            buffer.extend_from_slice(&c.to_ne_bytes());
+        }
+    }
+    buffer.truncate(buffer.len() - 4);
+
+    let m = Matrix4::from_translation(vec3(1.0, 2.0, 3.0)) * Matrix4::from_angle_y(Deg(30.0));
+    m.transform_points_strided(&mut buffer, 4, 16);
+
+    for (i, &p) in points.iter().enumerate() {
+        let start = 4 + 16 * i;
+        let c = |j: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&buffer[start + 4 * j..start + 4 * j + 4]);
+            f32::from_ne_bytes(bytes)
+        };
+        let expected = m.transform_point(p);
+        assert_eq!(Point3::new(c(0), c(1), c(2)), expected);
+        if start + 16 <= buffer.len() {
+            assert_eq!(c(3), 1.0);
+        }
+    }
+    assert_eq!(&buffer[..4], &[0xAB; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_strided_stride_too_small() {
+    let mut buffer = [0; 64];
+    Matrix4::<f32>::identity().transform_points_strided(&mut buffer, 0, 8);
+}
+
+#[test]
+fn test_homogeneous() {
+    let m = Matrix4::from(PerspectiveFov {
+        fovy: Deg(60.0f32).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    });
+    let input = points().map(|p| vec4(p.x, p.y, p.z - 10.0, 1.0));
+    let mut output = [Point3::origin(); 4];
+    m.transform_homogeneous_into(&input, &mut output);
+
+    let mut in_place = input;
+    m.transform_homogeneous_in_place(&mut in_place);
+    for i in 0..4 {
+        let clip = m * input[i];
+        assert_eq!(output[i], Point3::from_homogeneous(clip));
+        assert_eq!(in_place[i], output[i].to_homogeneous());
+    }
+}
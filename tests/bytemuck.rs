@@ -0,0 +1,86 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "bytemuck")]
+
+extern crate bytemuck;
+extern crate cgmath;
+
+use std::mem;
+
+use cgmath::*;
+
+#[test]
+fn test_size_and_alignment() {
+    macro_rules! assert_layout {
+        ($T:ty, $S:ty, $n:expr) => {
+            assert_eq!(mem::size_of::<$T>(), $n * mem::size_of::<$S>());
+            assert_eq!(mem::align_of::<$T>(), mem::align_of::<$S>());
+        };
+    }
+
+    assert_layout!(Vector1<f32>, f32, 1);
+    assert_layout!(Vector2<f32>, f32, 2);
+    assert_layout!(Vector3<f32>, f32, 3);
+    assert_layout!(Vector4<f32>, f32, 4);
+    assert_layout!(Point1<f64>, f64, 1);
+    assert_layout!(Point2<f64>, f64, 2);
+    assert_layout!(Point3<f64>, f64, 3);
+    assert_layout!(Matrix2<f32>, f32, 4);
+    assert_layout!(Matrix3<f32>, f32, 9);
+    assert_layout!(Matrix4<f32>, f32, 16);
+    assert_layout!(Quaternion<f32>, f32, 4);
+    assert_layout!(Rad<f32>, f32, 1);
+    assert_layout!(Deg<f64>, f64, 1);
+    assert_layout!(Euler<Rad<f32>>, f32, 3);
+    assert_layout!(Vector3<u16>, u16, 3);
+}
+
+#[test]
+fn test_cast_to_bytes() {
+    let m = Matrix4::from_translation(vec3(1.0f32, 2.0, 3.0));
+    let bytes = bytemuck::bytes_of(&m);
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(
+        bytemuck::cast_slice::<u8, f32>(bytes),
+        AsRef::<[f32; 16]>::as_ref(&m)
+    );
+    assert_eq!(*bytemuck::from_bytes::<Matrix4<f32>>(bytes), m);
+}
+
+#[test]
+fn test_cast_slice() {
+    let vertices = [point3(1.0f32, 2.0, 3.0), point3(4.0, 5.0, 6.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&vertices);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    // The vector part is stored before the scalar part
+    let q = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(bytemuck::cast::<_, [f32; 4]>(q), [2.0, 3.0, 4.0, 1.0]);
+
+    let angles = Euler::new(Deg(10.0f32), Deg(20.0), Deg(30.0));
+    assert_eq!(bytemuck::cast::<_, [f32; 3]>(angles), [10.0, 20.0, 30.0]);
+    assert_eq!(bytemuck::cast::<_, Rad<f64>>(1.5f64), Rad(1.5));
+}
+
+#[test]
+fn test_zeroed() {
+    let v: Vector4<f32> = bytemuck::Zeroable::zeroed();
+    assert_eq!(v, Vector4::zero());
+    let m: Matrix3<f64> = bytemuck::Zeroable::zeroed();
+    assert_eq!(m, Matrix3::zero());
+    let q: Quaternion<f32> = bytemuck::Zeroable::zeroed();
+    assert_eq!(q, Quaternion::zero());
+}
@@ -0,0 +1,257 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+mod first_person {
+    use cgmath::*;
+
+    #[test]
+    fn test_default_looks_down_negative_z() {
+        let camera = FirstPersonCamera::new(Point3::new(1.0, 2.0, 3.0), Deg(0.0), Deg(0.0));
+        assert_ulps_eq!(camera.forward(), -Vector3::unit_z());
+        assert_ulps_eq!(camera.right(), Vector3::unit_x());
+        assert_ulps_eq!(
+            camera.view(),
+            Matrix4::look_at_rh(
+                Point3::new(1.0, 2.0, 3.0),
+                Point3::new(1.0, 2.0, 2.0),
+                Vector3::unit_y(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut camera = FirstPersonCamera::new(Point3::new(0.0, 0.0, 0.0), Deg(0.0), Deg(0.0));
+
+        // Turning left looks down the negative x axis
+        camera.rotate(Deg(90.0), Deg(0.0));
+        assert_relative_eq!(camera.forward(), -Vector3::unit_x(), epsilon = 1e-12);
+
+        camera.rotate(Deg(0.0), Deg(45.0));
+        let expected = Vector3::new(-1.0, 1.0, 0.0).normalize();
+        assert_relative_eq!(camera.forward(), expected, epsilon = 1e-12);
+
+        // The orientation agrees with the view direction
+        assert_relative_eq!(
+            camera.orientation().rotate_vector(-Vector3::unit_z()),
+            camera.forward(),
+            epsilon = 1e-12
+        );
+
+        // Points in front of the camera are on the negative z axis of view space
+        let p = Point3::from_vec(camera.forward() * 3.0);
+        assert_relative_eq!(
+            camera.view().transform_point(p),
+            Point3::new(0.0, 0.0, -3.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_pitch_clamp() {
+        let mut camera = FirstPersonCamera::new(Point3::new(0.0, 0.0, 0.0), Deg(0.0), Deg(120.0));
+        assert_ulps_eq!(camera.pitch, Deg(89.0).into());
+
+        camera.pitch_limit = Deg(60.0).into();
+        camera.rotate(Deg(0.0), Deg(-200.0));
+        assert_ulps_eq!(camera.pitch, Deg(-60.0).into());
+        assert!(camera.view().is_finite());
+    }
+
+    #[test]
+    fn test_translate_local() {
+        let mut camera = FirstPersonCamera::new(Point3::new(0.0, 0.0, 0.0), Deg(90.0), Deg(45.0));
+        camera.translate_local(Vector3::new(1.0, 2.0, 3.0));
+
+        // Moving forwards ignores the pitch
+        assert_relative_eq!(
+            camera.position,
+            Point3::new(-3.0, 2.0, -1.0),
+            epsilon = 1e-12
+        );
+    }
+}
+
+mod orbit {
+    use cgmath::*;
+
+    #[test]
+    fn test_eye() {
+        let target = Point3::new(1.0, 2.0, 3.0);
+        let camera = OrbitCamera::new(target, 5.0, Deg(0.0), Deg(0.0));
+        assert_ulps_eq!(camera.eye(), Point3::new(1.0, 2.0, 8.0));
+
+        // A positive pitch looks down from above
+        let camera = OrbitCamera::new(target, 5.0, Deg(90.0), Deg(30.0));
+        let eye = camera.eye();
+        assert_relative_eq!(eye.distance(target), 5.0, epsilon = 1e-12);
+        assert!(eye.y > target.y);
+        assert_relative_eq!(eye.y - target.y, 2.5, epsilon = 1e-12);
+        assert!(eye.x > target.x);
+    }
+
+    #[test]
+    fn test_view_looks_at_target() {
+        let target = Point3::new(-4.0, 1.0, 0.5);
+        let mut camera = OrbitCamera::new(target, 7.0, Deg(35.0), Deg(-20.0));
+        camera.rotate(Deg(100.0), Deg(50.0));
+
+        assert_relative_eq!(
+            camera.view().transform_point(target),
+            Point3::new(0.0, 0.0, -7.0),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            camera.view(),
+            Matrix4::look_at_rh(camera.eye(), target, Vector3::unit_y()),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_zoom() {
+        let mut camera = OrbitCamera::new(Point3::new(0.0, 0.0, 0.0), 10.0, Deg(0.0), Deg(0.0));
+        camera.min_distance = 2.0;
+        camera.max_distance = 50.0;
+
+        camera.zoom(0.5);
+        assert_eq!(camera.distance, 5.0);
+        camera.zoom(0.1);
+        assert_eq!(camera.distance, 2.0);
+        camera.zoom(100.0);
+        assert_eq!(camera.distance, 50.0);
+    }
+
+    #[test]
+    fn test_pan() {
+        let mut camera = OrbitCamera::new(Point3::new(0.0, 0.0, 0.0), 10.0, Deg(30.0), Deg(40.0));
+        let before = camera.view().transform_point(Point3::new(0.0, 0.0, 0.0));
+        camera.pan(Vector2::new(1.0, 2.0));
+
+        // The old target moves in the opposite direction on screen
+        let after = camera.view().transform_point(Point3::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(
+            after - before,
+            Vector3::new(-1.0, -2.0, 0.0),
+            epsilon = 1e-12
+        );
+    }
+}
+
+mod arcball {
+    use cgmath::*;
+
+    #[test]
+    fn test_project_cursor() {
+        assert_ulps_eq!(
+            Arcball::project_cursor(Vector2::new(0.0, 0.0)),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+        assert_ulps_eq!(
+            Arcball::project_cursor(Vector2::new(0.6, 0.0)),
+            Vector3::new(0.6, 0.0, 0.8)
+        );
+        assert_ulps_eq!(
+            Arcball::project_cursor(Vector2::new(0.0, -3.0)),
+            Vector3::new(0.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_drag_rotation() {
+        // Dragging from the center to the edge rotates by twice the arc
+        let q = Arcball::drag_rotation(Vector2::new(0.0, 0.0), Vector2::new(0.5f64.sqrt(), 0.0));
+        let expected = Quaternion::from_angle_y(Deg(90.0));
+        assert_relative_eq!(q, expected, epsilon = 1e-12);
+
+        let q = Arcball::drag_rotation(Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.5f64.sqrt()));
+        let expected = Quaternion::from_angle_x(Deg(-90.0));
+        assert_relative_eq!(q, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_drag() {
+        let target = Point3::new(1.0, 1.0, 1.0);
+        let mut arcball = Arcball::new(target, 3.0);
+        assert!(!arcball.is_dragging());
+
+        // Dragging without a drag being started has no effect
+        arcball.drag(Vector2::new(0.5, 0.5));
+        assert_eq!(arcball.rotation, Quaternion::one());
+
+        arcball.begin_drag(Vector2::new(-0.2, 0.1));
+        assert!(arcball.is_dragging());
+        arcball.drag(Vector2::new(0.3, 0.4));
+        arcball.drag(Vector2::new(0.1, -0.3));
+        let rotation = arcball.rotation;
+        assert_relative_eq!(
+            rotation,
+            Arcball::drag_rotation(Vector2::new(-0.2, 0.1), Vector2::new(0.1, -0.3)),
+            epsilon = 1e-12
+        );
+
+        // Returning to the starting point restores the orientation
+        arcball.drag(Vector2::new(-0.2, 0.1));
+        assert_relative_eq!(arcball.rotation, Quaternion::one(), epsilon = 1e-12);
+
+        // Rotations accumulate between drags
+        arcball.drag(Vector2::new(0.1, -0.3));
+        arcball.end_drag();
+        assert!(!arcball.is_dragging());
+        arcball.begin_drag(Vector2::new(0.0, 0.0));
+        arcball.drag(Vector2::new(0.2, 0.0));
+        assert_relative_eq!(
+            arcball.rotation,
+            Arcball::drag_rotation(Vector2::new(0.0, 0.0), Vector2::new(0.2, 0.0)) * rotation,
+            epsilon = 1e-12
+        );
+
+        // The target stays in the center of the view
+        assert_relative_eq!(
+            arcball.view().transform_point(target),
+            Point3::new(0.0, 0.0, -3.0),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_views_are_rigid() {
+    let views = [
+        FirstPersonCamera::new(Point3::new(1.0, 2.0, 3.0), Deg(20.0), Deg(-30.0)).view(),
+        OrbitCamera::new(Point3::new(1.0, 2.0, 3.0), 4.0, Deg(20.0), Deg(-30.0)).view(),
+        {
+            let mut arcball = Arcball::new(Point3::new(1.0, 2.0, 3.0), 4.0);
+            arcball.begin_drag(Vector2::new(0.1, 0.2));
+            arcball.drag(Vector2::new(-0.4, 0.3));
+            arcball.view()
+        },
+    ];
+
+    for view in &views {
+        let rotation = Matrix3::from_cols(view.x.truncate(), view.y.truncate(), view.z.truncate());
+        assert_relative_eq!(rotation.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            rotation * rotation.transpose(),
+            Matrix3::identity(),
+            epsilon = 1e-12
+        );
+    }
+}
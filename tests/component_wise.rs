@@ -0,0 +1,95 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_min_max_clamp() {
+    let a = Vector3::new(1, 5, -3);
+    let b = Vector3::new(4, 2, -3);
+    assert_eq!(a.min(b), Vector3::new(1, 2, -3));
+    assert_eq!(a.max(b), Vector3::new(4, 5, -3));
+
+    let lo = Point2::new(0.0, 0.0);
+    let hi = Point2::new(1.0, 2.0);
+    assert_eq!(Point2::new(-1.0, 3.0).clamp(lo, hi), Point2::new(0.0, 2.0));
+    assert_eq!(Point2::new(0.5, 1.5).clamp(lo, hi), Point2::new(0.5, 1.5));
+}
+
+#[test]
+fn test_abs_signum() {
+    let v = Vector4::new(-2.0, 0.0, 3.0, -0.5);
+    assert_eq!(v.abs(), Vector4::new(2.0, 0.0, 3.0, 0.5));
+    assert_eq!(Vector3::new(-7, 0, 4).signum(), Vector3::new(-1, 0, 1));
+}
+
+#[test]
+fn test_rounding() {
+    let v = Vector4::new(-1.5, -0.25, 0.5, 2.75);
+    assert_eq!(v.floor(), Vector4::new(-2.0, -1.0, 0.0, 2.0));
+    assert_eq!(v.ceil(), Vector4::new(-1.0, -0.0, 1.0, 3.0));
+    assert_eq!(v.round(), Vector4::new(-2.0, -0.0, 1.0, 3.0));
+    assert_eq!(v.trunc(), Vector4::new(-1.0, -0.0, 0.0, 2.0));
+    assert_eq!(v.fract(), Vector4::new(0.5, 0.75, 0.5, 0.75));
+}
+
+#[test]
+fn test_powers() {
+    let v = Vector3::new(1.0f64, 4.0, 9.0);
+    assert_eq!(v.sqrt(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.powf(0.5), v.sqrt());
+    assert_eq!(v.recip(), Vector3::new(1.0, 0.25, 1.0 / 9.0));
+    assert_relative_eq!(
+        Point2::new(0.0f64, 1.0).exp(),
+        Point2::new(1.0, core::f64::consts::E)
+    );
+}
+
+#[test]
+fn test_min_max_element() {
+    assert_eq!(Vector4::new(3, 1, 4, 1).min_element(), (1, 1));
+    assert_eq!(Vector4::new(3, 1, 4, 1).max_element(), (2, 4));
+    assert_eq!(Point3::new(2.0, 2.0, -1.0).max_element(), (0, 2.0));
+    assert_eq!(Vector1::new(5).min_element(), (0, 5));
+}
+
+#[test]
+fn test_mix() {
+    let a = Vector3::new(0.0, 10.0, -4.0);
+    let b = Vector3::new(1.0, 20.0, 4.0);
+    let amount = Vector3::new(0.5, 0.0, 0.25);
+    assert_eq!(a.mix(b, amount), Vector3::new(0.5, 10.0, -2.0));
+    assert_eq!(a.mix(b, Vector3::from_value(0.5)), a.lerp(b, 0.5));
+}
+
+#[test]
+fn test_step() {
+    let edge = Vector3::new(1.0, 1.0, 1.0);
+    assert_eq!(
+        Vector3::new(0.5, 1.0, 1.5).step(edge),
+        Vector3::new(0.0, 1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_smoothstep() {
+    let edge0 = Point3::new(0.0, 0.0, 0.0);
+    let edge1 = Point3::new(1.0, 2.0, 4.0);
+    let p = Point3::new(0.25, 1.0, 5.0).smoothstep(edge0, edge1);
+    assert_relative_eq!(p, Point3::new(0.15625, 0.5, 1.0));
+}
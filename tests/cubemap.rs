@@ -0,0 +1,144 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const UVS: [[f64; 2]; 5] = [
+    [0.5, 0.5],
+    [0.1, 0.2],
+    [0.9, 0.3],
+    [0.25, 0.75],
+    [0.8, 0.95],
+];
+
+#[test]
+fn test_face_index() {
+    for (i, face) in CubeFace::ALL.iter().enumerate() {
+        assert_eq!(face.index(), i);
+        assert_eq!(CubeFace::from_index(i), Some(*face));
+    }
+    assert_eq!(CubeFace::from_index(6), None);
+}
+
+#[test]
+fn test_from_direction() {
+    let cases = [
+        (Vector3::new(3.0, 1.0, -1.0), CubeFace::PositiveX),
+        (Vector3::new(-3.0, 1.0, -1.0), CubeFace::NegativeX),
+        (Vector3::new(0.5, 2.0, -1.0), CubeFace::PositiveY),
+        (Vector3::new(0.5, -2.0, -1.0), CubeFace::NegativeY),
+        (Vector3::new(0.5, 0.1, 1.0), CubeFace::PositiveZ),
+        (Vector3::new(0.5, 0.1, -1.0), CubeFace::NegativeZ),
+    ];
+    for &(dir, expected) in &cases {
+        let (face, uv) = CubeFace::from_direction(dir);
+        assert_eq!(face, expected);
+        assert!(uv.x >= 0.0 && uv.x <= 1.0 && uv.y >= 0.0 && uv.y <= 1.0);
+        assert_eq!(CubeFace::from_direction(dir * 4.0), (face, uv));
+    }
+
+    // Known values from the cube map selection table of the OpenGL spec
+    let (face, uv) = CubeFace::from_direction(Vector3::new(1.0, 0.5, 0.5));
+    assert_eq!(face, CubeFace::PositiveX);
+    assert_ulps_eq!(uv, Vector2::new(0.25, 0.25));
+    let (face, uv) = CubeFace::from_direction(Vector3::new(0.5, 1.0, 0.5));
+    assert_eq!(face, CubeFace::PositiveY);
+    assert_ulps_eq!(uv, Vector2::new(0.75, 0.75));
+}
+
+#[test]
+fn test_direction_round_trip() {
+    for &face in CubeFace::ALL.iter() {
+        assert_ulps_eq!(face.direction(Vector2::new(0.5, 0.5)), face.normal());
+        for uv in UVS.iter() {
+            let uv = Vector2::new(uv[0], uv[1]);
+            let dir = face.direction(uv);
+            assert_ulps_eq!(dir.magnitude(), 1.0);
+
+            let (f, st) = CubeFace::from_direction(dir);
+            assert_eq!(f, face);
+            assert_relative_eq!(st, uv, epsilon = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_opengl_views_match_sampling() {
+    let eye = Point3::new(1.0, -2.0, 3.0);
+    let convention = CubeMapConvention::OpenGl;
+    let views = convention.views(eye);
+    let projection = convention.projection(0.1, 10.0);
+
+    for &face in CubeFace::ALL.iter() {
+        assert_eq!(
+            views[face.index()],
+            Matrix4::look_to_rh(eye, face.normal(), face.up(convention))
+        );
+        for uv in UVS.iter() {
+            let uv = Vector2::new(uv[0], uv[1]);
+            let p = eye + face.direction(uv) * 2.0;
+            let ndc = (projection * views[face.index()]).transform_point(p);
+
+            // The first row of the render target is at the bottom of the viewport
+            assert_relative_eq!((ndc.x + 1.0) * 0.5, uv.x, epsilon = 1e-12);
+            assert_relative_eq!((ndc.y + 1.0) * 0.5, uv.y, epsilon = 1e-12);
+            assert!(ndc.z > -1.0 && ndc.z < 1.0);
+        }
+    }
+}
+
+#[test]
+fn test_direct3d_views_match_sampling() {
+    let eye = Point3::new(1.0, -2.0, 3.0);
+    let convention = CubeMapConvention::Direct3D;
+    let views = convention.views(eye);
+    let projection = convention.projection(0.1, 10.0);
+
+    for &face in CubeFace::ALL.iter() {
+        assert_eq!(
+            views[face.index()],
+            Matrix4::look_to_lh(eye, face.normal(), face.up(convention))
+        );
+        for uv in UVS.iter() {
+            let uv = Vector2::new(uv[0], uv[1]);
+            let p = eye + face.direction(uv) * 2.0;
+            let ndc = (projection * views[face.index()]).transform_point(p);
+
+            // The first row of the render target is at the top of the viewport
+            assert_relative_eq!((ndc.x + 1.0) * 0.5, uv.x, epsilon = 1e-12);
+            assert_relative_eq!((1.0 - ndc.y) * 0.5, uv.y, epsilon = 1e-12);
+            assert!(ndc.z > 0.0 && ndc.z < 1.0);
+        }
+    }
+}
+
+#[test]
+fn test_projection_depth_range() {
+    for &(convention, near_depth) in &[
+        (CubeMapConvention::OpenGl, -1.0),
+        (CubeMapConvention::Direct3D, 0.0),
+    ] {
+        let eye = Point3::new(0.0, 0.0, 0.0);
+        let vp = convention.projection(0.5, 20.0) * CubeFace::PositiveZ.view(eye, convention);
+
+        let near = vp.transform_point(Point3::new(0.0, 0.0, 0.5));
+        let far = vp.transform_point(Point3::new(0.0, 0.0, 20.0));
+        assert_relative_eq!(near.z, near_depth, epsilon = 1e-12);
+        assert_relative_eq!(far.z, 1.0, epsilon = 1e-12);
+    }
+}
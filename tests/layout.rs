@@ -0,0 +1,228 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::layout::*;
+use cgmath::*;
+
+fn floats(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[test]
+fn test_alignment_and_size() {
+    assert_eq!((<f32 as Std140>::ALIGN, <f32 as Std140>::SIZE), (4, 4));
+    assert_eq!(
+        (
+            <Vector2<f32> as Std140>::ALIGN,
+            <Vector2<f32> as Std140>::SIZE
+        ),
+        (8, 8)
+    );
+    assert_eq!(
+        (
+            <Vector3<f32> as Std140>::ALIGN,
+            <Vector3<f32> as Std140>::SIZE
+        ),
+        (16, 12)
+    );
+    assert_eq!(
+        (
+            <Point3<u32> as Std430>::ALIGN,
+            <Point3<u32> as Std430>::SIZE
+        ),
+        (16, 12)
+    );
+    assert_eq!(
+        (
+            <Vector3<f64> as Std430>::ALIGN,
+            <Vector3<f64> as Std430>::SIZE
+        ),
+        (32, 24)
+    );
+    assert_eq!(
+        (
+            <Quaternion<f32> as Std140>::ALIGN,
+            <Quaternion<f32> as Std140>::SIZE
+        ),
+        (16, 16)
+    );
+
+    assert_eq!(
+        (
+            <Matrix2<f32> as Std140>::ALIGN,
+            <Matrix2<f32> as Std140>::SIZE
+        ),
+        (16, 32)
+    );
+    assert_eq!(
+        (
+            <Matrix2<f32> as Std430>::ALIGN,
+            <Matrix2<f32> as Std430>::SIZE
+        ),
+        (8, 16)
+    );
+    assert_eq!(
+        (
+            <Matrix3<f32> as Std140>::ALIGN,
+            <Matrix3<f32> as Std140>::SIZE
+        ),
+        (16, 48)
+    );
+    assert_eq!(
+        (
+            <Matrix3<f32> as Std430>::ALIGN,
+            <Matrix3<f32> as Std430>::SIZE
+        ),
+        (16, 48)
+    );
+    assert_eq!(
+        (
+            <Matrix4<f64> as Std140>::ALIGN,
+            <Matrix4<f64> as Std140>::SIZE
+        ),
+        (32, 128)
+    );
+
+    assert_eq!(
+        (<[f32; 3] as Std140>::ALIGN, <[f32; 3] as Std140>::SIZE),
+        (16, 48)
+    );
+    assert_eq!(
+        (<[f32; 3] as Std430>::ALIGN, <[f32; 3] as Std430>::SIZE),
+        (4, 12)
+    );
+    assert_eq!(
+        (
+            <[Vector3<f32>; 2] as Std430>::ALIGN,
+            <[Vector3<f32>; 2] as Std430>::SIZE
+        ),
+        (16, 32)
+    );
+}
+
+#[test]
+fn test_matrix3_padding() {
+    let m = Matrix3::new(1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let mut bytes = [0xff; 48];
+    m.write_std140(&mut bytes);
+    assert_eq!(
+        floats(&bytes),
+        [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0]
+    );
+
+    let mut bytes = [0xff; 48];
+    m.write_std430(&mut bytes);
+    assert_eq!(
+        floats(&bytes),
+        [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0]
+    );
+}
+
+#[test]
+fn test_array_padding() {
+    let array = [1.0f32, 2.0];
+    let mut bytes = [0xff; 32];
+    array.write_std140(&mut bytes);
+    assert_eq!(floats(&bytes), [1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0]);
+
+    let mut bytes = [0xff; 8];
+    array.write_std430(&mut bytes);
+    assert_eq!(floats(&bytes), [1.0, 2.0]);
+}
+
+#[test]
+fn test_quaternion() {
+    let mut bytes = [0; 16];
+    Quaternion::new(1.0f32, 2.0, 3.0, 4.0).write_std430(&mut bytes);
+    assert_eq!(floats(&bytes), [2.0, 3.0, 4.0, 1.0]);
+}
+
+// struct {
+//     vec3 a;
+//     float b;
+//     mat3 c;
+//     vec2 d;
+//     float e[2];
+//     mat2 f;
+// };
+#[test]
+fn test_std140_writer() {
+    let mut bytes = [0xff; 160];
+    let mut writer = Std140Writer::new(&mut bytes);
+    assert_eq!(writer.write(&vec3(1.0f32, 2.0, 3.0)), 0);
+    assert_eq!(writer.write(&4.0f32), 12);
+    assert_eq!(writer.write(&Matrix3::<f32>::identity()), 16);
+    assert_eq!(writer.write(&vec2(5.0f32, 6.0)), 64);
+    assert_eq!(writer.write(&[7.0f32, 8.0]), 80);
+    assert_eq!(writer.write(&Matrix2::<f32>::identity()), 112);
+    assert_eq!(writer.offset(), 144);
+    assert_eq!(writer.finish(), 144);
+
+    assert_eq!(floats(&bytes[0..16]), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(floats(&bytes[64..80]), [5.0, 6.0, 0.0, 0.0]);
+    assert_eq!(
+        floats(&bytes[80..112]),
+        [7.0, 0.0, 0.0, 0.0, 8.0, 0.0, 0.0, 0.0]
+    );
+    assert_eq!(
+        floats(&bytes[112..144]),
+        [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]
+    );
+}
+
+#[test]
+fn test_std430_writer() {
+    let mut bytes = [0xff; 96];
+    let mut writer = Std430Writer::new(&mut bytes);
+    assert_eq!(writer.write(&vec3(1.0f32, 2.0, 3.0)), 0);
+    assert_eq!(writer.write(&4.0f32), 12);
+    assert_eq!(writer.write(&Matrix3::<f32>::identity()), 16);
+    assert_eq!(writer.write(&vec2(5.0f32, 6.0)), 64);
+    assert_eq!(writer.write(&[7.0f32, 8.0]), 72);
+    assert_eq!(writer.write(&Matrix2::<f32>::identity()), 80);
+    assert_eq!(writer.finish(), 96);
+
+    assert_eq!(
+        floats(&bytes[64..96]),
+        [5.0, 6.0, 7.0, 8.0, 1.0, 0.0, 0.0, 1.0]
+    );
+}
+
+#[test]
+fn test_writer_finish_pads_to_alignment() {
+    let mut bytes = [0xff; 16];
+    let mut writer = Std140Writer::new(&mut bytes);
+    writer.write(&1.0f32);
+    assert_eq!(writer.finish(), 16);
+    assert_eq!(floats(&bytes), [1.0, 0.0, 0.0, 0.0]);
+
+    let mut bytes = [0xff; 16];
+    let mut writer = Std430Writer::new(&mut bytes);
+    writer.write(&1.0f32);
+    writer.write(&2u32);
+    assert_eq!(writer.finish(), 8);
+}
+
+#[test]
+#[should_panic]
+fn test_writer_buffer_too_small() {
+    let mut bytes = [0; 32];
+    Std140Writer::new(&mut bytes).write(&Matrix3::<f32>::identity());
+}
@@ -0,0 +1,183 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_ray_at() {
+    let ray = Ray3::new(Point3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 2.0, 0.0));
+    assert_eq!(ray.at(0.0), ray.origin);
+    assert_eq!(ray.at(1.5), Point3::new(1.0, 5.0, 3.0));
+
+    let ray = ray.normalize();
+    assert_eq!(ray.direction, Vector3::unit_y());
+    assert_eq!(ray.at(1.5), Point3::new(1.0, 3.5, 3.0));
+}
+
+#[test]
+fn test_ray_transform() {
+    let ray = Ray2::new(Point2::new(1.0, 0.0), Vector2::new(1.0, 1.0));
+    let transform = Decomposed {
+        scale: 2.0,
+        rot: Basis2::from_angle(Deg(90.0)),
+        disp: Vector2::new(0.0, 1.0),
+    };
+    let transformed = ray.transform(&transform);
+
+    // Points along the ray keep their parameters
+    for &t in &[0.0, 0.5, 3.0] {
+        assert_relative_eq!(
+            transformed.at(t),
+            transform.transform_point(ray.at(t)),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_line() {
+    let line = Line::from_points(Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 3.0, 4.0));
+    assert_eq!(line.direction, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(line.at(-1.0), Point3::new(0.0, -1.0, -2.0));
+
+    let ray = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::unit_x());
+    assert_eq!(Line::from(ray), Line::new(ray.origin, ray.direction));
+}
+
+#[test]
+fn test_segment() {
+    let segment = Segment::new(Point2::new(1.0, 1.0), Point2::new(4.0, 5.0));
+    assert_eq!(segment.direction(), Vector2::new(3.0, 4.0));
+    assert_eq!(segment.length2(), 25.0);
+    assert_eq!(segment.length(), 5.0);
+    assert_eq!(segment.midpoint(), Point2::new(2.5, 3.0));
+    assert_eq!(segment.at(0.0), segment.start);
+    assert_eq!(segment.at(1.0), segment.end);
+
+    let reversed = segment.reverse();
+    assert_eq!(reversed.start, segment.end);
+    assert_eq!(reversed.end, segment.start);
+
+    assert_eq!(segment.to_ray().at(1.0), segment.end);
+    assert_eq!(segment.to_line().at(1.0), segment.end);
+
+    let transform = Matrix3::from_translation(Vector2::new(-1.0, 2.0));
+    assert_eq!(
+        segment.transform(&transform),
+        Segment::new(Point2::new(0.0, 3.0), Point2::new(3.0, 7.0))
+    );
+}
+
+#[test]
+fn test_segment_closest_point() {
+    let segment = Segment::new(Point3::new(0.0, 0.0, 0.0), Point3::new(4.0, 0.0, 0.0));
+    assert_eq!(
+        segment.closest_point(Point3::new(1.0, 2.0, 0.0)),
+        Point3::new(1.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        segment.closest_point(Point3::new(-3.0, 1.0, 0.0)),
+        segment.start
+    );
+    assert_eq!(
+        segment.closest_point(Point3::new(7.0, 1.0, 0.0)),
+        segment.end
+    );
+    assert_eq!(segment.distance2(Point3::new(7.0, 4.0, 0.0)), 25.0);
+
+    // Degenerate segments behave like points
+    let point = Segment::new(Point2::new(1.0, 1.0), Point2::new(1.0, 1.0));
+    assert_eq!(point.closest_point(Point2::new(3.0, 4.0)), point.start);
+}
+
+#[test]
+fn test_ray_closest_point() {
+    let ray = Ray2::new(Point2::new(1.0, 1.0), Vector2::new(2.0, 0.0));
+    assert_eq!(ray.closest_parameter(Point2::new(5.0, 3.0)), 2.0);
+    assert_eq!(
+        ray.closest_point(Point2::new(5.0, 3.0)),
+        Point2::new(5.0, 1.0)
+    );
+    assert_eq!(ray.closest_point(Point2::new(-5.0, 3.0)), ray.origin);
+    assert_eq!(ray.distance2(Point2::new(-2.0, 5.0)), 25.0);
+}
+
+#[test]
+fn test_segment_closest_points() {
+    // Crossing segments at different heights
+    let a = Segment::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    let b = Segment::new(Point3::new(0.5, -1.0, 2.0), Point3::new(0.5, 3.0, 2.0));
+    assert_eq!(a.closest_parameters(&b), (0.75, 0.25));
+    assert_eq!(
+        a.closest_points(&b),
+        (Point3::new(0.5, 0.0, 0.0), Point3::new(0.5, 0.0, 2.0))
+    );
+    assert_eq!(a.distance2_to_segment(&b), 4.0);
+
+    // The closest points are at the ends of the segments
+    let c = Segment::new(Point3::new(3.0, 1.0, 0.0), Point3::new(5.0, 2.0, 0.0));
+    assert_eq!(a.closest_points(&c), (a.end, c.start));
+    assert_eq!(c.closest_points(&a), (c.start, a.end));
+
+    // Parallel segments
+    let d = Segment::new(Point3::new(0.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0));
+    assert_eq!(a.distance2_to_segment(&d), 1.0);
+}
+
+#[test]
+fn test_segment_closest_points_brute_force() {
+    let segments = [
+        Segment::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0)),
+        Segment::new(Point3::new(2.0, -1.0, 0.5), Point3::new(-1.0, 1.0, 2.0)),
+        Segment::new(Point3::new(1.0, 3.0, -2.0), Point3::new(1.5, 2.0, 4.0)),
+        Segment::new(Point3::new(-2.0, 0.0, 1.0), Point3::new(3.0, 0.0, 1.0)),
+    ];
+    for a in &segments {
+        for b in &segments {
+            let mut best = f64::INFINITY;
+            for i in 0..=100 {
+                for j in 0..=100 {
+                    let p = a.at(i as f64 / 100.0);
+                    let q = b.at(j as f64 / 100.0);
+                    best = best.min(p.distance2(q));
+                }
+            }
+            let distance2 = a.distance2_to_segment(b);
+            assert!(distance2 <= best + 1e-12);
+            assert!(distance2 >= best - 0.01);
+        }
+    }
+}
+
+#[test]
+fn test_ray_closest_parameters() {
+    let a = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    let b = Ray3::new(Point3::new(3.0, -2.0, 1.0), Vector3::new(0.0, 2.0, 0.0));
+    assert_eq!(a.closest_parameters(&b), (3.0, 1.0));
+    assert_eq!(
+        a.closest_points(&b),
+        (Point3::new(3.0, 0.0, 0.0), Point3::new(3.0, 0.0, 1.0))
+    );
+    assert_eq!(a.distance2_to_ray(&b), 1.0);
+
+    // The closest point on the lines is behind the origin of a ray
+    let c = Ray3::new(Point3::new(-3.0, -2.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(a.closest_parameters(&c), (0.0, 2.0));
+    let d = Ray3::new(Point3::new(3.0, 2.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(a.closest_parameters(&d), (3.0, 0.0));
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_any_all_none() {
+    assert!(BVec3::new(false, true, false).any());
+    assert!(!BVec3::new(false, true, false).all());
+    assert!(!BVec3::new(false, true, false).none());
+    assert!(BVec4::splat(true).all());
+    assert!(BVec2::splat(false).none());
+    assert!(BVec2::default().none());
+}
+
+#[test]
+fn test_bit_operators() {
+    let a = BVec4::new(true, true, false, false);
+    let b = BVec4::new(true, false, true, false);
+    assert_eq!(a & b, BVec4::new(true, false, false, false));
+    assert_eq!(a | b, BVec4::new(true, true, true, false));
+    assert_eq!(a ^ b, BVec4::new(false, true, true, false));
+    assert_eq!(!a, BVec4::new(false, false, true, true));
+}
+
+#[test]
+fn test_conversions() {
+    let mask = BVec3::from([true, false, true]);
+    assert_eq!(mask, BVec3::new(true, false, true));
+    assert_eq!(<[bool; 3]>::from(mask), [true, false, true]);
+}
+
+#[test]
+fn test_compare_vectors() {
+    let a = vec4(1.0, 2.0, 3.0, f64::NAN);
+    let b = vec4(2.0, 2.0, 2.0, 2.0);
+    assert_eq!(a.lt_element_wise(b), BVec4::new(true, false, false, false));
+    assert_eq!(a.le_element_wise(b), BVec4::new(true, true, false, false));
+    assert_eq!(a.gt_element_wise(b), BVec4::new(false, false, true, false));
+    assert_eq!(a.ge_element_wise(b), BVec4::new(false, true, true, false));
+    assert_eq!(a.eq_element_wise(b), BVec4::new(false, true, false, false));
+    assert_eq!(a.ne_element_wise(b), BVec4::new(true, false, true, true));
+}
+
+#[test]
+fn test_compare_scalar() {
+    let v = vec3(-1, 0, 1);
+    assert_eq!(v.lt_element_wise(0), BVec3::new(true, false, false));
+    assert_eq!(v.ge_element_wise(0), BVec3::new(false, true, true));
+    assert_eq!(v.eq_element_wise(0), BVec3::new(false, true, false));
+
+    let p = point2(0.5, 2.0);
+    assert_eq!(p.gt_element_wise(1.0), BVec2::new(false, true));
+    assert_eq!(p.le_element_wise(point2(0.5, 1.0)), BVec2::new(true, false));
+}
+
+#[test]
+fn test_approx_eq() {
+    let a = vec3(1.0, 2.0, 3.0);
+    let b = vec3(1.0 + 1e-10, 2.1, 3.0 - 1e-12);
+    assert_eq!(
+        a.abs_diff_eq_element_wise(&b, 1e-8),
+        BVec3::new(true, false, true)
+    );
+    assert_eq!(
+        a.relative_eq_element_wise(&b, 1e-12, 1e-9),
+        BVec3::new(true, false, true)
+    );
+    assert_eq!(
+        a.ulps_eq_element_wise(&b, 0.0, 4),
+        BVec3::new(false, false, false)
+    );
+    assert_eq!(a.ulps_eq_element_wise(&a, 0.0, 4), BVec3::splat(true));
+}
+
+#[test]
+fn test_select() {
+    let mask = BVec3::new(true, false, true);
+    let a = vec3(1, 2, 3);
+    let b = vec3(4, 5, 6);
+    assert_eq!(mask.select(a, b), vec3(1, 5, 3));
+    assert_eq!(
+        mask.select(point3(1, 2, 3), point3(4, 5, 6)),
+        point3(1, 5, 3)
+    );
+
+    // Clamp the negative components to zero, like GLSL's `mix` with a bvec
+    let v = vec2(-1.5, 2.5);
+    assert_eq!(
+        v.lt_element_wise(0.0).select(Vector2::zero(), v),
+        vec2(0.0, 2.5)
+    );
+}
@@ -0,0 +1,276 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "alloc")]
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::mesh::{self, NormalWeighting};
+use cgmath::*;
+
+/// A unit square in the xy plane, with texture coordinates given by `uv`.
+fn square<F: Fn(f64, f64) -> Vector2<f64>>(uv: F) -> (Vec<Point3<f64>>, Vec<Vector2<f64>>) {
+    let corners = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    let positions = corners
+        .iter()
+        .map(|c| Point3::new(c[0], c[1], 0.0))
+        .collect();
+    let uvs = corners.iter().map(|c| uv(c[0], c[1])).collect();
+    (positions, uvs)
+}
+
+const SQUARE: [[usize; 3]; 2] = [[0, 1, 2], [0, 2, 3]];
+
+#[test]
+fn test_face_normals() {
+    let positions = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 2.0),
+        Point3::new(4.0, 0.0, 0.0),
+    ];
+    let normals = mesh::face_normals(&positions, &[[0, 1, 2], [0, 2, 3], [0, 1, 4]]);
+    assert_eq!(
+        normals,
+        vec![Vector3::unit_z(), Vector3::unit_x(), Vector3::zero()]
+    );
+}
+
+#[test]
+fn test_vertex_normal_weighting() {
+    // A large triangle in the xy plane and a small one in the yz plane,
+    // meeting at right angles at the origin
+    let positions = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+        Point3::new(0.0, 4.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(9.0, 9.0, 9.0),
+    ];
+    let triangles = [[0, 1, 2], [0, 3, 4]];
+
+    let by_area = mesh::vertex_normals(&positions, &triangles, NormalWeighting::Area);
+    assert_ulps_eq!(by_area[0], Vector3::new(-1.0, 0.0, 16.0).normalize());
+
+    let by_angle = mesh::vertex_normals(&positions, &triangles, NormalWeighting::Angle);
+    assert_ulps_eq!(by_angle[0], Vector3::new(-1.0, 0.0, 1.0).normalize());
+    assert_ulps_eq!(by_angle[1], Vector3::unit_z());
+
+    // Vertices outside of any triangle have no normal
+    assert_eq!(by_area[5], Vector3::zero());
+    assert_eq!(by_angle[5], Vector3::zero());
+}
+
+#[test]
+fn test_angle_weighting_ignores_tessellation() {
+    // A corner of a cube, where one face is split into more triangles than
+    // the others
+    let positions = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 0.0),
+    ];
+    let triangles = [[0, 2, 4], [0, 4, 1], [0, 3, 2], [0, 1, 3]];
+    let normals = mesh::vertex_normals(&positions, &triangles, NormalWeighting::Angle);
+    assert_relative_eq!(
+        normals[0],
+        Vector3::new(-1.0, -1.0, -1.0).normalize(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_tangents_follow_uvs() {
+    let normals = vec![Vector3::unit_z(); 4];
+
+    // The u coordinate increases along the y axis
+    let (positions, uvs) = square(|x, y| Vector2::new(y, 1.0 - x));
+    let tangents = mesh::tangents(&positions, &normals, &uvs, &SQUARE);
+    assert_eq!(tangents.triangles, SQUARE);
+    for t in &tangents.tangents {
+        assert_relative_eq!(*t, Vector4::new(0.0, 1.0, 0.0, 1.0), epsilon = 1e-12);
+        assert_relative_eq!(
+            mesh::bitangent(Vector3::unit_z(), *t),
+            -Vector3::unit_x(),
+            epsilon = 1e-12
+        );
+    }
+
+    // Mirrored texture coordinates flip the handedness
+    let (positions, uvs) = square(|x, y| Vector2::new(1.0 - x, y));
+    let tangents = mesh::tangents(&positions, &normals, &uvs, &SQUARE);
+    assert_eq!(tangents.triangles, SQUARE);
+    for t in &tangents.tangents {
+        assert_relative_eq!(*t, Vector4::new(-1.0, 0.0, 0.0, -1.0), epsilon = 1e-12);
+        assert_relative_eq!(
+            mesh::bitangent(Vector3::unit_z(), *t),
+            Vector3::unit_y(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_tangents_are_orthogonal_to_normals() {
+    // A strip bent around the y axis, with u increasing around the bend
+    let count = 8;
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    for i in 0..=count {
+        let angle = Rad(i as f64 / count as f64 * 1.5);
+        for &y in &[0.0, 1.0] {
+            positions.push(Point3::new(angle.0.sin(), y, angle.0.cos()));
+            uvs.push(Vector2::new(i as f64 / count as f64, y));
+        }
+    }
+    let mut triangles = Vec::new();
+    for i in 0..count {
+        let (a, b, c, d) = (2 * i, 2 * i + 2, 2 * i + 3, 2 * i + 1);
+        triangles.push([a, b, c]);
+        triangles.push([a, c, d]);
+    }
+
+    let normals = mesh::vertex_normals(&positions, &triangles, NormalWeighting::Angle);
+    let tangents = mesh::tangents(&positions, &normals, &uvs, &triangles);
+    assert_eq!(tangents.tangents.len(), positions.len());
+
+    for (&n, &t) in normals.iter().zip(&tangents.tangents) {
+        assert_relative_eq!(n.dot(t.truncate()), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(t.truncate().magnitude(), 1.0, epsilon = 1e-12);
+        assert_eq!(t.w, 1.0);
+
+        // The tangent follows the bend, and the bitangent the y axis
+        let expected = Vector3::new(n.z, 0.0, -n.x).normalize();
+        assert_relative_eq!(t.truncate(), expected, epsilon = 1e-12);
+        assert_relative_eq!(mesh::bitangent(n, t), Vector3::unit_y(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_degenerate_uvs() {
+    let (positions, _) = square(Vector2::new);
+    let uvs = vec![Vector2::new(0.5, 0.5); 4];
+    let normals = vec![Vector3::unit_z(); 4];
+    for t in mesh::tangents(&positions, &normals, &uvs, &SQUARE).tangents {
+        assert_ulps_eq!(t.truncate().dot(Vector3::unit_z()), 0.0);
+        assert_ulps_eq!(t.truncate().magnitude(), 1.0);
+    }
+}
+
+type Mesh = (Vec<Point3<f64>>, Vec<Vector2<f64>>, Vec<[usize; 3]>);
+
+/// Two unit squares side by side in the xy plane, sharing the vertices on the
+/// y axis, with the texture mirrored across the y axis.
+fn mirrored_strip() -> Mesh {
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    for &y in &[0.0, 1.0] {
+        for &x in &[-1.0, 0.0, 1.0] {
+            positions.push(Point3::new(x, y, 0.0));
+            uvs.push(Vector2::new(f64::abs(x), y));
+        }
+    }
+    let triangles = vec![[0, 1, 4], [0, 4, 3], [1, 2, 5], [1, 5, 4]];
+    (positions, uvs, triangles)
+}
+
+#[test]
+fn test_tangents_split_mirrored_vertices() {
+    let (positions, uvs, triangles) = mirrored_strip();
+    let normals = vec![Vector3::unit_z(); positions.len()];
+    let tangents = mesh::tangents(&positions, &normals, &uvs, &triangles);
+
+    // The vertices on the mirroring seam are split in two
+    assert_eq!(tangents.tangents.len(), 8);
+    assert_eq!(tangents.remap, vec![0, 1, 2, 3, 4, 5, 1, 4]);
+    assert_eq!(
+        tangents.triangles,
+        vec![[0, 1, 4], [0, 4, 3], [6, 2, 5], [6, 5, 7]]
+    );
+
+    let split_positions = tangents.remap_attribute(&positions);
+    for (i, triangle) in tangents.triangles.iter().enumerate() {
+        for &v in triangle {
+            let t = tangents.tangents[v];
+            if i < 2 {
+                // The mirrored half has a left-handed tangent space
+                assert_eq!(t, Vector4::new(-1.0, 0.0, 0.0, -1.0));
+            } else {
+                assert_eq!(t, Vector4::new(1.0, 0.0, 0.0, 1.0));
+            }
+            assert_eq!(mesh::bitangent(Vector3::unit_z(), t), Vector3::unit_y());
+            assert_eq!(split_positions[v], positions[tangents.remap[v]]);
+        }
+    }
+}
+
+#[test]
+fn test_tangents_weld_identical_vertices() {
+    // The mirrored strip again, but with separate vertices for each corner
+    let (positions, uvs, triangles) = mirrored_strip();
+    let corners: Vec<usize> = triangles.iter().flat_map(|t| t.iter().cloned()).collect();
+    let unindexed: Vec<[usize; 3]> = (0..triangles.len())
+        .map(|i| [3 * i, 3 * i + 1, 3 * i + 2])
+        .collect();
+    let normals = vec![Vector3::unit_z(); corners.len()];
+    let tangents = mesh::tangents(
+        &corners.iter().map(|&i| positions[i]).collect::<Vec<_>>(),
+        &normals,
+        &corners.iter().map(|&i| uvs[i]).collect::<Vec<_>>(),
+        &unindexed,
+    );
+
+    let indexed = mesh::tangents(&positions, &normals[..6], &uvs, &triangles);
+    assert_eq!(tangents.tangents.len(), corners.len());
+    for (triangle, expected) in tangents.triangles.iter().zip(&indexed.triangles) {
+        for (&v, &e) in triangle.iter().zip(expected) {
+            assert_eq!(tangents.tangents[v], indexed.tangents[e]);
+        }
+    }
+}
+
+#[test]
+fn test_tangents_of_degenerate_triangles() {
+    // The last triangle has two corners at the same position
+    let (positions, uvs) = square(Vector2::new);
+    let normals = vec![Vector3::unit_z(); 4];
+    let triangles = [[0, 1, 2], [0, 2, 3], [1, 2, 2]];
+    let tangents = mesh::tangents(&positions, &normals, &uvs, &triangles);
+    assert_eq!(tangents.triangles[2], [1, 2, 2]);
+    for t in &tangents.tangents {
+        assert_relative_eq!(*t, Vector4::new(1.0, 0.0, 0.0, 1.0), epsilon = 1e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_tangents_missing_normals() {
+    let (positions, uvs) = square(Vector2::new);
+    let normals = vec![Vector3::unit_z(); 3];
+    mesh::tangents(&positions, &normals, &uvs, &SQUARE);
+}
+
+#[test]
+#[should_panic]
+fn test_tangents_missing_uvs() {
+    let (positions, uvs) = square(Vector2::new);
+    let normals = vec![Vector3::unit_z(); 4];
+    mesh::tangents(&positions, &normals, &uvs[..3], &SQUARE);
+}
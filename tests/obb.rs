@@ -0,0 +1,191 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn sorted(v: Vector3<f64>) -> [f64; 3] {
+    let mut a: [f64; 3] = v.into();
+    a.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    a
+}
+
+#[test]
+fn test_contains_and_corners() {
+    let obb = Obb3::new(
+        Point3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 1.0, 0.5),
+        Quaternion::from_angle_z(Deg(90.0)),
+    );
+
+    // The local x axis points along the world y axis
+    assert!(obb.contains(Point3::new(1.0, 3.9, 3.0)));
+    assert!(!obb.contains(Point3::new(2.9, 2.0, 3.0)));
+
+    for corner in &obb.corners() {
+        let inner = obb.center + (*corner - obb.center) * 0.999;
+        let outer = obb.center + (*corner - obb.center) * 1.001;
+        assert!(obb.contains(inner));
+        assert!(!obb.contains(outer));
+    }
+
+    let aabb = obb.to_aabb();
+    assert_relative_eq!(aabb.min, Point3::new(0.0, 0.0, 2.5), epsilon = 1e-12);
+    assert_relative_eq!(aabb.max, Point3::new(2.0, 4.0, 3.5), epsilon = 1e-12);
+}
+
+#[test]
+fn test_to_aabb_matches_corners() {
+    let obb = Obb3::new(
+        Point3::new(-1.0, 0.5, 2.0),
+        Vector3::new(0.5, 1.5, 2.5),
+        Quaternion::from_axis_angle(Vector3::new(1.0, -2.0, 0.5).normalize(), Deg(37.0)),
+    );
+    let expected = Aabb3::from_points(obb.corners().iter().cloned()).unwrap();
+    let aabb = obb.to_aabb();
+    assert_relative_eq!(aabb.min, expected.min, epsilon = 1e-12);
+    assert_relative_eq!(aabb.max, expected.max, epsilon = 1e-12);
+}
+
+#[test]
+fn test_overlaps_face_axes() {
+    let a = Obb3::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Basis3::one(),
+    );
+    let rotated = |x: f64| {
+        Obb3::new(
+            Point3::new(x, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Quaternion::from_angle_z(Deg(45.0)),
+        )
+    };
+
+    // The corner of the rotated box reaches sqrt(2) from its center
+    assert!(a.overlaps(&rotated(2.4)));
+    assert!(rotated(2.4).overlaps(&a));
+    assert!(!a.overlaps(&rotated(2.5)));
+    assert!(!rotated(2.5).overlaps(&a));
+}
+
+#[test]
+fn test_overlaps_edge_axes() {
+    // Two boxes with edges crossing at right angles, which are only separated
+    // by the cross product of the edge directions
+    let a = Obb3::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Quaternion::from_angle_x(Deg(45.0)),
+    );
+    let b = |z: f64| {
+        Obb3::new(
+            Point3::new(0.0, 0.0, z),
+            Vector3::new(1.0, 1.0, 1.0),
+            Quaternion::from_angle_y(Deg(45.0)),
+        )
+    };
+
+    assert!(a.overlaps(&b(2.7)));
+    assert!(!a.overlaps(&b(3.2)));
+    assert!(!b(3.2).overlaps(&a));
+}
+
+#[test]
+fn test_overlaps_aabb() {
+    let aabb = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+    let obb = |x: f64| {
+        Obb3::new(
+            Point3::new(x, 1.0, 1.0),
+            Vector3::new(1.0, 0.5, 0.5),
+            Quaternion::from_angle_y(Deg(90.0)),
+        )
+    };
+
+    // The long axis of the box is rotated onto the z axis
+    assert!(obb(2.4).overlaps_aabb(&aabb));
+    assert!(!obb(2.6).overlaps_aabb(&aabb));
+
+    let other = Aabb3::new(Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 4.0, 5.0));
+    assert_eq!(
+        Obb3::from_aabb(&other).overlaps_aabb(&aabb),
+        other.overlaps(&aabb)
+    );
+}
+
+#[test]
+fn test_from_points() {
+    assert_eq!(Obb3::<f64>::from_points(&[]), None);
+
+    let original = Obb3::new(
+        Point3::new(3.0, -1.0, 2.0),
+        Vector3::new(4.0, 2.0, 0.5),
+        Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+    );
+    let mut points = original.corners().to_vec();
+    points.push(original.center);
+
+    let fitted = Obb3::from_points(&points).unwrap();
+    assert_relative_eq!(fitted.center, original.center, epsilon = 1e-9);
+    assert_relative_eq!(
+        sorted(fitted.half_extents)[..],
+        sorted(original.half_extents)[..],
+        epsilon = 1e-9
+    );
+    for &p in &points {
+        let inflated = Obb3::new(
+            fitted.center,
+            fitted.half_extents * (1.0 + 1e-9),
+            fitted.orientation,
+        );
+        assert!(inflated.contains(p));
+    }
+
+    // The orientation is a proper rotation
+    let m: Matrix3<f64> = fitted.orientation.into();
+    assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_closest_point() {
+    let obb = Obb3::new(
+        Point3::new(1.0, 2.0, 3.0),
+        Vector3::new(2.0, 1.0, 0.5),
+        Quaternion::from_angle_z(Deg(90.0)),
+    );
+    let inside = Point3::new(1.5, 3.0, 3.2);
+    assert_relative_eq!(obb.closest_point(inside), inside, epsilon = 1e-12);
+    assert_relative_eq!(obb.distance2(inside), 0.0, epsilon = 1e-12);
+
+    // The local x axis points along the world y axis
+    let outside = Point3::new(4.0, 7.0, 3.0);
+    assert_relative_eq!(
+        obb.closest_point(outside),
+        Point3::new(2.0, 4.0, 3.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(obb.distance2(outside), 13.0, epsilon = 1e-12);
+
+    // An axis-aligned box gives the same results as an AABB
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 5.0));
+    let p = Point3::new(4.0, -2.0, 3.0);
+    assert_relative_eq!(
+        Obb3::from_aabb(&aabb).closest_point(p),
+        aabb.closest_point(p),
+        epsilon = 1e-12
+    );
+}
@@ -0,0 +1,156 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_f16_values() {
+    let v = Vector4::new(1.0f32, -2.0, 65504.0, 0.1);
+    assert_eq!(
+        v.to_f16_bits(),
+        Vector4::new(0x3c00, 0xc000, 0x7bff, 0x2e66)
+    );
+
+    // Overflow to infinity, underflow to zero, and the smallest subnormal
+    let v = Vector4::new(65520.0f32, -1e6, 1e-8, 2.0f32.powi(-24));
+    assert_eq!(
+        v.to_f16_bits(),
+        Vector4::new(0x7c00, 0xfc00, 0x0000, 0x0001)
+    );
+
+    let nan = Vector2::from_f16_bits(Vector2::new(0x7e00, 0x7c00));
+    assert!(nan.x.is_nan());
+    assert_eq!(nan.y, f32::INFINITY);
+    assert_eq!(Vector1::new(f32::NAN).to_f16_bits().x & 0x7e00, 0x7e00);
+}
+
+#[test]
+fn test_f16_rounds_to_even() {
+    let ulp = 2.0f32.powi(-10);
+    let v = Vector3::new(1.0 + ulp / 2.0, 1.0 + 3.0 * ulp / 2.0, 1.0 + ulp * 0.51);
+    assert_eq!(v.to_f16_bits(), Vector3::new(0x3c00, 0x3c02, 0x3c01));
+
+    // Between the two smallest subnormals
+    let v = Vector2::new(1.5f32 * 2.0f32.powi(-24), 2.0f32.powi(-25));
+    assert_eq!(v.to_f16_bits(), Vector2::new(0x0002, 0x0000));
+}
+
+#[test]
+fn test_f16_round_trip() {
+    for bits in 0..=u16::MAX {
+        let value = Vector1::from_f16_bits(Vector1::new(bits));
+        if !value.x.is_nan() {
+            assert_eq!(value.to_f16_bits().x, bits);
+        }
+    }
+}
+
+#[test]
+fn test_unorm() {
+    let v = Vector4::new(0.5f32, -1.0, 2.0, f32::NAN);
+    assert_eq!(v.to_unorm8(), Vector4::new(128, 0, 255, 0));
+    assert_eq!(v.to_unorm16(), Vector4::new(32768, 0, 65535, 0));
+
+    assert_eq!(
+        Vector2::from_unorm8(Vector2::new(0, 255)),
+        Vector2::new(0.0, 1.0)
+    );
+    assert_eq!(
+        Vector2::from_unorm16(Vector2::new(0, 65535)),
+        Vector2::new(0.0, 1.0)
+    );
+    for i in 0..=255u8 {
+        let v = Vector1::from_unorm8(Vector1::new(i));
+        assert_eq!(v.to_unorm8().x, i);
+    }
+}
+
+#[test]
+fn test_snorm() {
+    let v = Vector4::new(0.5f32, -1.0, 2.0, f32::NAN);
+    assert_eq!(v.to_snorm8(), Vector4::new(64, -127, 127, 0));
+    assert_eq!(v.to_snorm16(), Vector4::new(16384, -32767, 32767, 0));
+
+    let v = Vector3::from_snorm8(Vector3::new(-128, -127, 127));
+    assert_eq!(v, Vector3::new(-1.0, -1.0, 1.0));
+    let v = Vector3::from_snorm16(Vector3::new(-32768, 0, 32767));
+    assert_eq!(v, Vector3::new(-1.0, 0.0, 1.0));
+    for i in -127..=127i8 {
+        let v = Vector1::from_snorm8(Vector1::new(i));
+        assert_eq!(v.to_snorm8().x, i);
+    }
+}
+
+#[test]
+fn test_unorm_10_10_10_2() {
+    let v = Vector4::new(1.0f32, 0.0, 0.5, 1.0);
+    let bits = v.to_unorm_10_10_10_2();
+    assert_eq!(bits, 0x3ff | 512 << 20 | 3 << 30);
+    assert_relative_eq!(
+        Vector4::from_unorm_10_10_10_2(bits),
+        Vector4::new(1.0, 0.0, 512.0 / 1023.0, 1.0)
+    );
+    assert_eq!(
+        Vector4::new(-1.0f32, 2.0, f32::NAN, 0.4).to_unorm_10_10_10_2(),
+        0x3ff << 10 | 1 << 30
+    );
+}
+
+#[test]
+fn test_snorm_10_10_10_2() {
+    let v = Vector4::new(-1.0f32, 1.0, 0.0, -1.0);
+    let bits = v.to_snorm_10_10_10_2();
+    assert_eq!(bits, 0x201 | 0x1ff << 10 | 3 << 30);
+    assert_eq!(Vector4::from_snorm_10_10_10_2(bits), v);
+
+    // The most negative values
+    let bits = 0x200 | 0x200 << 10 | 2 << 30;
+    assert_eq!(
+        Vector4::from_snorm_10_10_10_2(bits),
+        Vector4::new(-1.0, -1.0, 0.0, -1.0)
+    );
+}
+
+#[test]
+fn test_octahedral() {
+    let directions = [
+        Vector3::new(1.0f32, 0.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-3.0, 0.5, -2.0),
+        Vector3::new(0.2, -0.7, -0.1),
+        Vector3::new(-1.0, -1.0, -1.0),
+    ];
+    for &d in &directions {
+        let n = d.normalize();
+        let encoded = d.to_octahedral();
+        assert!(encoded.x.abs() <= 1.0 && encoded.y.abs() <= 1.0);
+        assert_relative_eq!(Vector3::from_octahedral(encoded), n, epsilon = 1e-6);
+
+        let packed = Vector2::from_snorm16(encoded.to_snorm16());
+        assert_relative_eq!(Vector3::from_octahedral(packed), n, epsilon = 1e-4);
+    }
+
+    assert_eq!(Vector3::zero().to_octahedral(), Vector2::new(0.0, 0.0));
+    assert_eq!(
+        Vector3::from_octahedral(Vector2::new(0.0, 0.0)),
+        Vector3::unit_z()
+    );
+}
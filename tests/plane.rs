@@ -0,0 +1,149 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_from_point_normal() {
+    let plane = Plane::from_point_normal(Point3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+    assert_eq!(plane, Plane::new(Vector3::unit_y(), -3.0));
+    assert_eq!(plane.signed_distance(Point3::new(5.0, 5.0, 5.0)), 2.0);
+    assert_eq!(plane.signed_distance(Point3::new(5.0, 1.0, 5.0)), -2.0);
+    assert_eq!(plane.origin(), Point3::new(0.0, 3.0, 0.0));
+}
+
+#[test]
+fn test_from_points() {
+    let plane = Plane::from_points(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    )
+    .unwrap();
+    let n = 1.0 / 3.0f64.sqrt();
+    assert_ulps_eq!(plane, Plane::new(Vector3::new(n, n, n), -n));
+
+    assert_eq!(
+        Plane::from_points(
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0),
+            Point3::new(4.0, 4.0, 4.0),
+        ),
+        None
+    );
+
+    // Small triangles are not mistaken for collinear points
+    let small = Plane::from_points(
+        Point3::new(0.0f32, 0.0, 0.0),
+        Point3::new(1e-4, 0.0, 0.0),
+        Point3::new(0.0, 1e-4, 0.0),
+    )
+    .unwrap();
+    assert_eq!(small, Plane::new(Vector3::unit_z(), 0.0));
+    assert_eq!(
+        Plane::from_points(
+            Point3::new(0.0f32, 0.0, 0.0),
+            Point3::new(1e-4, 1e-4, 1e-4),
+            Point3::new(3e-4, 3e-4, 3e-4),
+        ),
+        None
+    );
+    assert_eq!(
+        Plane::from_points(
+            Point3::new(1.0, 2.0, 3.0),
+            Point3::new(1.0, 2.0, 3.0),
+            Point3::new(0.0, 0.0, 1.0)
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_vector4() {
+    let plane = Plane::from(Vector4::new(0.0, 0.0, -2.0, 4.0));
+    assert_eq!(plane, Plane::new(-Vector3::unit_z(), 2.0));
+    assert_eq!(Vector4::from(plane), Vector4::new(0.0, 0.0, -1.0, 2.0));
+    assert_eq!(-plane, Plane::new(Vector3::unit_z(), -2.0));
+}
+
+#[test]
+fn test_project_and_reflect() {
+    let plane = Plane::from_point_normal(Point3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 0.0));
+    let p = Point3::new(3.0, 2.0, 5.0);
+
+    let projected = plane.project_point(p);
+    assert_relative_eq!(plane.signed_distance(projected), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(
+        plane.project_vector(p - projected),
+        Vector3::zero(),
+        epsilon = 1e-12
+    );
+
+    let reflected = plane.reflect_point(p);
+    assert_relative_eq!(
+        plane.signed_distance(reflected),
+        -plane.signed_distance(p),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(reflected.midpoint(p), projected, epsilon = 1e-12);
+    assert_relative_eq!(
+        plane.reflection_matrix().transform_point(p),
+        reflected,
+        epsilon = 1e-12
+    );
+
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    assert_relative_eq!(
+        plane.reflection_matrix().transform_vector(v),
+        plane.reflect_vector(v),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_transform() {
+    let plane = Plane::from_point_normal(Point3::new(1.0, 2.0, 3.0), Vector3::new(1.0, -1.0, 2.0));
+    let transforms = [
+        Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_angle_x(Deg(30.0)),
+        Matrix4::from_nonuniform_scale(2.0, 0.5, 3.0),
+        Matrix4::from_nonuniform_scale(-1.0, 1.0, 1.0),
+    ];
+    let points = [Point3::new(4.0, -1.0, 2.0), Point3::new(-3.0, 0.0, 1.0)];
+
+    for transform in &transforms {
+        let transformed = plane.transform(transform);
+        assert_relative_eq!(
+            transformed,
+            plane.transform_matrix(transform).unwrap(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(transformed.normal.magnitude(), 1.0, epsilon = 1e-12);
+
+        // Points keep the side of the plane that they are on
+        for &p in &points {
+            let before = plane.signed_distance(p);
+            let after = transformed.signed_distance(transform.transform_point(p));
+            assert_eq!(before > 0.0, after > 0.0);
+        }
+        assert_relative_eq!(
+            transformed.signed_distance(transform.transform_point(plane.origin())),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::polygon::{self, FillRule, Winding};
+use cgmath::*;
+
+fn points(coords: &[[f64; 2]]) -> Vec<Point2<f64>> {
+    coords.iter().map(|&c| Point2::from(c)).collect()
+}
+
+/// An L shape with a reflex vertex at (1, 1).
+fn l_shape() -> Vec<Point2<f64>> {
+    points(&[
+        [0.0, 0.0],
+        [3.0, 0.0],
+        [3.0, 1.0],
+        [1.0, 1.0],
+        [1.0, 3.0],
+        [0.0, 3.0],
+    ])
+}
+
+/// A five-pointed star drawn with a single self-intersecting outline.
+fn pentagram() -> Vec<Point2<f64>> {
+    (0..5)
+        .map(|i| {
+            let angle = Deg(90.0 + 144.0 * i as f64);
+            Point2::new(Rad::from(angle).0.cos(), Rad::from(angle).0.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn test_signed_area_and_winding() {
+    let mut shape = l_shape();
+    assert_eq!(polygon::signed_area(&shape), 5.0);
+    assert_eq!(polygon::winding(&shape), Some(Winding::CounterClockwise));
+
+    shape.reverse();
+    assert_eq!(polygon::signed_area(&shape), -5.0);
+    assert_eq!(polygon::winding(&shape), Some(Winding::Clockwise));
+
+    let line = points(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+    assert_eq!(polygon::winding(&line), None);
+}
+
+#[test]
+fn test_contains() {
+    let shape = l_shape();
+    for &rule in &[FillRule::EvenOdd, FillRule::NonZero] {
+        assert!(polygon::contains(&shape, Point2::new(0.5, 2.5), rule));
+        assert!(polygon::contains(&shape, Point2::new(2.5, 0.5), rule));
+        assert!(!polygon::contains(&shape, Point2::new(2.0, 2.0), rule));
+        assert!(!polygon::contains(&shape, Point2::new(-1.0, 0.5), rule));
+    }
+
+    // The center of a pentagram is wound around twice
+    let star = pentagram();
+    let center = Point2::new(0.0, 0.0);
+    assert_eq!(polygon::winding_number(&star, center), 2);
+    assert!(!polygon::contains(&star, center, FillRule::EvenOdd));
+    assert!(polygon::contains(&star, center, FillRule::NonZero));
+
+    // The points of the star are wound around once
+    let tip = Point2::new(0.0, 0.9);
+    assert!(polygon::contains(&star, tip, FillRule::EvenOdd));
+    assert!(polygon::contains(&star, tip, FillRule::NonZero));
+}
+
+#[test]
+fn test_is_convex() {
+    let square = points(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    assert!(polygon::is_convex(&square));
+    let reversed: Vec<_> = square.iter().rev().cloned().collect();
+    assert!(polygon::is_convex(&reversed));
+
+    // Collinear vertices are allowed
+    let with_midpoint = points(&[[0.0, 0.0], [0.5, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    assert!(polygon::is_convex(&with_midpoint));
+
+    assert!(!polygon::is_convex(&l_shape()));
+    assert!(!polygon::is_convex(&pentagram()));
+    assert!(!polygon::is_convex(&points(&[
+        [0.0, 0.0],
+        [1.0, 1.0],
+        [2.0, 2.0]
+    ])));
+}
+
+#[test]
+fn test_centroid() {
+    let square = points(&[[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]]);
+    assert_eq!(polygon::centroid(&square), Some(Point2::new(2.0, 2.0)));
+
+    // The L shape is a 3x1 and a 1x2 rectangle
+    let expected = Point2::new((1.5 * 3.0 + 0.5 * 2.0) / 5.0, (0.5 * 3.0 + 2.0 * 2.0) / 5.0);
+    let mut shape = l_shape();
+    assert_ulps_eq!(polygon::centroid(&shape).unwrap(), expected);
+    shape.reverse();
+    assert_ulps_eq!(polygon::centroid(&shape).unwrap(), expected);
+
+    assert_eq!(polygon::centroid(&points(&[[0.0, 0.0], [1.0, 1.0]])), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_convex_hull() {
+    let cloud = points(&[
+        [0.0, 0.0],
+        [1.0, 1.0],
+        [2.0, 0.0],
+        [2.0, 2.0],
+        [1.0, 0.0],
+        [0.0, 2.0],
+        [0.5, 1.5],
+        [2.0, 2.0],
+        [1.0, 2.5],
+    ]);
+    let hull = polygon::convex_hull(&cloud);
+    assert_eq!(
+        hull,
+        points(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [1.0, 2.5], [0.0, 2.0]])
+    );
+    assert!(polygon::is_convex(&hull));
+    assert_eq!(polygon::winding(&hull), Some(Winding::CounterClockwise));
+
+    assert_eq!(
+        polygon::convex_hull(&points(&[[1.0, 1.0], [1.0, 1.0]])).len(),
+        1
+    );
+    let collinear = points(&[[0.0, 0.0], [2.0, 2.0], [1.0, 1.0]]);
+    assert_eq!(
+        polygon::convex_hull(&collinear),
+        points(&[[0.0, 0.0], [2.0, 2.0]])
+    );
+}
+
+#[cfg(feature = "alloc")]
+fn assert_triangulation(shape: &[Point2<f64>]) {
+    let triangles = polygon::triangulate(shape);
+    assert_eq!(triangles.len(), shape.len() - 2);
+
+    let total = triangles.iter().fold(0.0, |sum, t| {
+        let triangle = [shape[t[0]], shape[t[1]], shape[t[2]]];
+        let area = polygon::signed_area(&triangle);
+        // The triangles keep the winding of the polygon
+        assert_eq!(polygon::winding(&triangle), polygon::winding(shape));
+        sum + area
+    });
+    assert_ulps_eq!(total, polygon::signed_area(shape));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_triangulate() {
+    let mut shape = l_shape();
+    assert_triangulation(&shape);
+    shape.reverse();
+    assert_triangulation(&shape);
+
+    // A comb with several reflex vertices
+    let comb = points(&[
+        [0.0, 0.0],
+        [5.0, 0.0],
+        [5.0, 3.0],
+        [4.0, 3.0],
+        [4.0, 1.0],
+        [3.0, 1.0],
+        [3.0, 3.0],
+        [2.0, 3.0],
+        [2.0, 1.0],
+        [1.0, 1.0],
+        [1.0, 3.0],
+        [0.0, 3.0],
+    ]);
+    assert_triangulation(&comb);
+
+    assert_eq!(
+        polygon::triangulate(&points(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])),
+        vec![[0, 1, 2]]
+    );
+    assert!(polygon::triangulate(&points(&[[0.0, 0.0], [1.0, 0.0]])).is_empty());
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "alloc")]
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::predicates::{incircle, insphere, orient2d, orient3d};
+use cgmath::*;
+
+const ULP_AT_HALF: f64 = 1.0 / (1u64 << 53) as f64;
+
+fn next_up(x: f64) -> f64 {
+    if x >= 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+fn sign(x: f64) -> i32 {
+    if x > 0.0 {
+        1
+    } else if x < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Evaluate orient2d exactly on points with coordinates `0.5 + i * ulp`, by
+/// scaling them to integers.
+fn exact_orient2d(a: [i128; 2], b: [i128; 2], c: [i128; 2]) -> i32 {
+    let det = (a[0] - c[0]) * (b[1] - c[1]) - (a[1] - c[1]) * (b[0] - c[0]);
+    det.signum() as i32
+}
+
+#[test]
+fn test_orient2d_nearly_collinear() {
+    // The classic example of a naive orientation test failing, evaluated on a
+    // grid of points around (0.5, 0.5) spaced one ulp apart
+    let scale = (1u64 << 53) as i128;
+    let b = Point2::new(12.0, 12.0);
+    let c = Point2::new(24.0, 24.0);
+    let exact_b = [12 * scale, 12 * scale];
+    let exact_c = [24 * scale, 24 * scale];
+
+    for i in 0..64 {
+        for j in 0..64 {
+            let a = Point2::new(0.5 + i as f64 * ULP_AT_HALF, 0.5 + j as f64 * ULP_AT_HALF);
+            let exact_a = [scale / 2 + i as i128, scale / 2 + j as i128];
+            let expected = exact_orient2d(exact_a, exact_b, exact_c);
+
+            assert_eq!(sign(orient2d(a, b, c)), expected);
+            assert_eq!(sign(orient2d(b, c, a)), expected);
+            assert_eq!(sign(orient2d(b, a, c)), -expected);
+        }
+    }
+}
+
+#[test]
+fn test_orient2d_simple() {
+    let a = Point2::new(0.0, 0.0);
+    let b = Point2::new(1.0, 0.0);
+    let c = Point2::new(0.0, 1.0);
+    assert_eq!(orient2d(a, b, c), 1.0);
+    assert_eq!(orient2d(a, c, b), -1.0);
+    assert_eq!(orient2d(a, b, Point2::new(2.0, 0.0)), 0.0);
+
+    // Single precision points are promoted without rounding
+    let a = Point2::new(0.1f32, 0.1);
+    let b = Point2::new(0.3f32, 0.3);
+    let c = Point2::new(0.7f32, 0.7);
+    let exact = orient2d(
+        Point2::new(0.1f32 as f64, 0.1f32 as f64),
+        Point2::new(0.3f32 as f64, 0.3f32 as f64),
+        Point2::new(0.7f32 as f64, 0.7f32 as f64),
+    );
+    assert_eq!(sign(orient2d(a, b, c)), sign(exact));
+}
+
+#[test]
+fn test_orient3d() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(1.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 1.0, 0.0);
+
+    // Points below the counter-clockwise triangle are positive
+    assert!(orient3d(a, b, c, Point3::new(0.2, 0.2, -1.0)) > 0.0);
+    assert!(orient3d(a, b, c, Point3::new(0.2, 0.2, 1.0)) < 0.0);
+
+    // A tilted plane through points with inexact coordinates
+    let a = Point3::new(0.1, 0.2, 0.3);
+    let b = Point3::new(12.1, 12.2, 12.3);
+    let c = Point3::new(24.1, 48.2, 0.3);
+    for &t in &[0.5, 1.0 / 3.0, 7.0, -2.25] {
+        // A point near the plane, nudged to either side along the z axis
+        let d = a + (b - a) * t;
+        let on_plane = orient3d(a, b, c, d);
+        let above = orient3d(
+            a,
+            b,
+            c,
+            Point3::new(d.x, d.y, next_up(next_up(d.z) + 1e-12)),
+        );
+        let below = orient3d(a, b, c, Point3::new(d.x, d.y, next_down(d.z - 1e-12)));
+        assert!(above != 0.0 && below != 0.0);
+        assert_eq!(sign(above), -sign(below));
+        assert_eq!(sign(orient3d(b, a, c, d)), -sign(on_plane));
+        assert_eq!(sign(orient3d(b, c, a, d)), sign(on_plane));
+    }
+
+    // Exactly coplanar points
+    let d = Point3::new(3.0, 5.0, 0.0);
+    assert_eq!(
+        orient3d(
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            d
+        ),
+        0.0
+    );
+}
+
+#[test]
+fn test_incircle() {
+    // Points on a circle of radius 5, which are exactly representable
+    let a = Point2::new(5.0, 0.0);
+    let b = Point2::new(3.0, 4.0);
+    let c = Point2::new(-4.0, 3.0);
+    assert!(orient2d(a, b, c) > 0.0);
+
+    assert_eq!(incircle(a, b, c, Point2::new(0.0, -5.0)), 0.0);
+    assert_eq!(incircle(a, b, c, Point2::new(-3.0, -4.0)), 0.0);
+    assert!(incircle(a, b, c, Point2::new(0.0, 0.0)) > 0.0);
+    assert!(incircle(a, b, c, Point2::new(6.0, 0.0)) < 0.0);
+
+    // Moving a cocircular point by a single ulp is detected
+    assert!(incircle(a, b, c, Point2::new(0.0, next_up(-5.0))) > 0.0);
+    assert!(incircle(a, b, c, Point2::new(0.0, next_down(-5.0))) < 0.0);
+    assert!(incircle(a, b, c, Point2::new(next_down(-3.0), -4.0)) < 0.0);
+
+    // The sign is reversed for clockwise triangles
+    assert!(incircle(a, c, b, Point2::new(0.0, next_up(-5.0))) < 0.0);
+}
+
+#[test]
+fn test_insphere() {
+    // Points on a sphere of radius 3, which are exactly representable
+    let a = Point3::new(3.0, 0.0, 0.0);
+    let b = Point3::new(0.0, 3.0, 0.0);
+    let c = Point3::new(0.0, 0.0, 3.0);
+    let d = Point3::new(-3.0, 0.0, 0.0);
+    let (a, b) = if orient3d(a, b, c, d) > 0.0 {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    assert!(orient3d(a, b, c, d) > 0.0);
+
+    assert_eq!(insphere(a, b, c, d, Point3::new(2.0, 2.0, 1.0)), 0.0);
+    assert_eq!(insphere(a, b, c, d, Point3::new(-1.0, 2.0, -2.0)), 0.0);
+    assert!(insphere(a, b, c, d, Point3::new(0.0, 0.0, 0.0)) > 0.0);
+    assert!(insphere(a, b, c, d, Point3::new(0.0, 4.0, 0.0)) < 0.0);
+
+    // Moving a cospherical point by a single ulp is detected
+    assert!(insphere(a, b, c, d, Point3::new(2.0, 2.0, next_down(1.0))) > 0.0);
+    assert!(insphere(a, b, c, d, Point3::new(2.0, 2.0, next_up(1.0))) < 0.0);
+    assert!(insphere(a, b, c, d, Point3::new(-1.0, next_up(2.0), -2.0)) < 0.0);
+
+    // The sign is reversed for negatively oriented points
+    assert!(insphere(b, a, c, d, Point3::new(2.0, 2.0, next_down(1.0))) < 0.0);
+
+    // Single precision input
+    let f = |p: Point3<f64>| p.cast::<f32>().unwrap();
+    assert!(insphere(f(a), f(b), f(c), f(d), Point3::new(0.0f32, 0.0, 0.0)) > 0.0);
+}
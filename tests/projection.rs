@@ -13,9 +13,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::{
+    halton, ortho, Deg, DepthRange, InnerSpace, JitterSequence, Matrix4, Perspective,
+    PerspectiveFov, Point3, Vector2, Vector3, Vector4,
+};
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +71,191 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+/// A view space plane through `(0, 0, -2)`, tilted around the x axis and facing
+/// away from the camera.
+fn tilted_clip_plane() -> (Vector3<f64>, Vector4<f64>) {
+    let normal = Vector3::new(0.0, 0.5, -1.0).normalize();
+    let point = Vector3::new(0.0, 0.0, -2.0);
+    (normal, normal.extend(-normal.dot(point)))
+}
+
+fn project(m: Matrix4<f64>, p: Point3<f64>) -> Vector3<f64> {
+    let clip = m * p.to_homogeneous();
+    clip.truncate() / clip.w
+}
+
+fn check_oblique(m: Matrix4<f64>, near_depth: f64) {
+    let (normal, plane) = tilted_clip_plane();
+    let tangent = Vector3::unit_x();
+    let bitangent = normal.cross(tangent);
+
+    for &(s, t) in &[(0.0, 0.0), (0.3, 0.0), (-0.4, 0.2), (0.1, -0.5)] {
+        let on_plane = Point3::new(0.0, 0.0, -2.0) + tangent * s + bitangent * t;
+        assert_ulps_eq!(plane.dot(on_plane.to_homogeneous()), 0.0);
+        assert_relative_eq!(project(m, on_plane).z, near_depth, epsilon = 1e-12);
+
+        let in_front = on_plane + normal * 0.5;
+        assert!(project(m, in_front).z > near_depth);
+        let behind = on_plane - normal * 0.5;
+        assert!(project(m, behind).z < near_depth);
+    }
+}
+
+#[test]
+fn test_perspective_fov_oblique() {
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let (_, plane) = tilted_clip_plane();
+
+    check_oblique(persp.to_oblique(plane, DepthRange::NegativeOneToOne), -1.0);
+    check_oblique(persp.to_oblique(plane, DepthRange::ZeroToOne), 0.0);
+}
+
+#[test]
+fn test_perspective_oblique() {
+    let persp = Perspective {
+        left: -0.05,
+        right: 0.15,
+        bottom: -0.1,
+        top: 0.08,
+        near: 0.1,
+        far: 100.0,
+    };
+    let (_, plane) = tilted_clip_plane();
+
+    check_oblique(persp.to_oblique(plane, DepthRange::NegativeOneToOne), -1.0);
+    check_oblique(persp.to_oblique(plane, DepthRange::ZeroToOne), 0.0);
+}
+
+#[test]
+fn test_oblique_near_plane_parallel() {
+    // A clip plane that coincides with the near plane leaves the projection
+    // unchanged.
+    let persp = PerspectiveFov {
+        fovy: Deg(90.0).into(),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+    };
+    let m: Matrix4<f64> = persp.into();
+    let plane = Vector4::new(0.0, 0.0, -1.0, -1.0);
+    let oblique = cgmath::oblique_near_plane(m, plane, DepthRange::NegativeOneToOne);
+
+    assert_relative_eq!(project(oblique, Point3::new(0.3, 0.2, -1.0)).z, -1.0, epsilon = 1e-12);
+    assert_relative_eq!(project(oblique, Point3::new(0.0, 0.0, -10.0)).z, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_jitter() {
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let viewport = Vector2::new(1920.0, 1280.0);
+    let offset = Vector2::new(0.25, -0.375);
+    let m: Matrix4<f64> = persp.into();
+    let jittered: Matrix4<f64> = persp.jitter(offset, viewport).into();
+
+    for &p in &[
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(1.0, -2.0, -5.0),
+        Point3::new(-3.0, 1.0, -20.0),
+    ] {
+        let a = project(m, p);
+        let b = project(jittered, p);
+        let shift = (b.truncate() - a.truncate()) * 0.5;
+        assert_relative_eq!(shift.x * viewport.x, offset.x, epsilon = 1e-9);
+        assert_relative_eq!(shift.y * viewport.y, offset.y, epsilon = 1e-9);
+        assert_relative_eq!(a.z, b.z, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_halton() {
+    assert_eq!(halton::<f64>(0, 2), 0.0);
+    assert_eq!(halton::<f64>(1, 2), 0.5);
+    assert_eq!(halton::<f64>(2, 2), 0.25);
+    assert_eq!(halton::<f64>(3, 2), 0.75);
+    assert_eq!(halton::<f64>(4, 2), 0.125);
+    assert_ulps_eq!(halton::<f64>(1, 3), 1.0 / 3.0);
+    assert_ulps_eq!(halton::<f64>(2, 3), 2.0 / 3.0);
+    assert_ulps_eq!(halton::<f64>(3, 3), 1.0 / 9.0);
+    assert_ulps_eq!(halton::<f64>(4, 3), 4.0 / 9.0);
+}
+
+#[test]
+fn test_jitter_sequence() {
+    assert_eq!(
+        JitterSequence::Halton23.sample::<f64>(0),
+        Vector2::new(0.0, 1.0 / 3.0 - 0.5)
+    );
+    assert_eq!(JitterSequence::R2.sample::<f64>(0), Vector2::new(0.0, 0.0));
+
+    for &sequence in &[JitterSequence::Halton23, JitterSequence::R2] {
+        let samples: Vec<Vector2<f32>> = (0..64).map(|i| sequence.sample(i)).collect();
+        for (i, s) in samples.iter().enumerate() {
+            assert!(s.x >= -0.5 && s.x < 0.5 && s.y >= -0.5 && s.y < 0.5);
+            assert!(samples[i + 1..].iter().all(|t| t != s));
+        }
+
+        let last: Vector2<f32> = sequence.sample(u32::MAX);
+        assert!(last.x >= -0.5 && last.x < 0.5 && last.y >= -0.5 && last.y < 0.5);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_tiles() {
+    let persp = Perspective {
+        left: -0.2,
+        right: 0.4,
+        bottom: -0.1,
+        top: 0.2,
+        near: 0.1,
+        far: 100.0,
+    };
+    let tiles = persp.tiles(3, 2);
+    assert_eq!(tiles.len(), 6);
+
+    // The top left tile
+    assert_ulps_eq!(tiles[0].left, -0.2);
+    assert_ulps_eq!(tiles[0].right, 0.0);
+    assert_ulps_eq!(tiles[0].bottom, 0.05);
+    assert_ulps_eq!(tiles[0].top, 0.2);
+    // The bottom right tile
+    assert_ulps_eq!(tiles[5].left, 0.2);
+    assert_ulps_eq!(tiles[5].right, 0.4);
+    assert_ulps_eq!(tiles[5].bottom, -0.1);
+    assert_ulps_eq!(tiles[5].top, 0.05);
+    assert_eq!(tiles[4], persp.tile(3, 2, 1, 1));
+
+    // A point in the full image lands in the matching place of its tile
+    let m: Matrix4<f64> = persp.into();
+    let p = Point3::new(3.0, 1.0, -1.0);
+    let full = project(m, p);
+    let tile = project(tiles[2].into(), p);
+    assert_relative_eq!((full.x + 1.0) * 1.5 - 2.0, (tile.x + 1.0) * 0.5, epsilon = 1e-9);
+    assert_relative_eq!((full.y + 1.0) - 1.0, (tile.y + 1.0) * 0.5, epsilon = 1e-9);
+    assert_relative_eq!(full.z, tile.z, epsilon = 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn test_tile_out_of_range() {
+    let persp = Perspective {
+        left: -1.0,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 0.1,
+        far: 100.0,
+    };
+    persp.tile(2, 2, 2, 0);
+}
@@ -0,0 +1,221 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn ray(origin: [f64; 3], direction: [f64; 3]) -> Ray3<f64> {
+    Ray3::new(origin.into(), direction.into())
+}
+
+#[test]
+fn test_plane() {
+    let plane = Plane::from_point_normal(Point3::new(0.0, 2.0, 0.0), Vector3::unit_y());
+
+    let hit = plane
+        .intersect_ray(&ray([1.0, 5.0, 0.0], [0.0, -2.0, 0.0]))
+        .unwrap();
+    assert_eq!(hit.distance, 1.5);
+    assert_eq!(hit.point, Point3::new(1.0, 2.0, 0.0));
+    assert_eq!(hit.normal, Vector3::unit_y());
+    assert_eq!(hit.barycentric, None);
+
+    // Hits from behind keep the normal of the plane
+    let hit = plane
+        .intersect_ray(&ray([0.0, 0.0, 0.0], [0.0, 1.0, 1.0]))
+        .unwrap();
+    assert_eq!(hit.point, Point3::new(0.0, 2.0, 2.0));
+    assert_eq!(hit.normal, Vector3::unit_y());
+
+    assert_eq!(
+        plane.intersect_ray(&ray([0.0, 5.0, 0.0], [0.0, 1.0, 0.0])),
+        None
+    );
+    assert_eq!(
+        plane.intersect_ray(&ray([0.0, 5.0, 0.0], [1.0, 0.0, 0.0])),
+        None
+    );
+}
+
+#[test]
+fn test_disc() {
+    let disc = Disc::new(
+        Point3::new(0.0, 0.0, -3.0),
+        Vector3::new(0.0, 0.0, 2.0),
+        1.0,
+    );
+    let hit = disc
+        .intersect_ray(&ray([0.5, 0.5, 0.0], [0.0, 0.0, -1.0]))
+        .unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, Vector3::unit_z());
+    assert_eq!(
+        disc.intersect_ray(&ray([0.8, 0.8, 0.0], [0.0, 0.0, -1.0])),
+        None
+    );
+}
+
+#[test]
+fn test_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0);
+
+    let hit = sphere
+        .intersect_ray(&ray([0.0, 0.0, 0.0], [0.0, 0.0, -2.0]))
+        .unwrap();
+    assert_ulps_eq!(hit.distance, 2.0);
+    assert_ulps_eq!(hit.point, Point3::new(0.0, 0.0, -4.0));
+    assert_ulps_eq!(hit.normal, Vector3::unit_z());
+
+    // Starting inside the sphere hits the far side
+    let hit = sphere
+        .intersect_ray(&ray([0.0, 0.0, -5.0], [0.0, 0.0, -1.0]))
+        .unwrap();
+    assert_ulps_eq!(hit.distance, 1.0);
+    assert_ulps_eq!(hit.normal, -Vector3::unit_z());
+
+    // Pointing away from the sphere, or missing it
+    assert_eq!(
+        sphere.intersect_ray(&ray([0.0, 0.0, 0.0], [0.0, 0.0, 1.0])),
+        None
+    );
+    assert_eq!(
+        sphere.intersect_ray(&ray([0.0, 1.5, 0.0], [0.0, 0.0, -1.0])),
+        None
+    );
+}
+
+#[test]
+fn test_aabb() {
+    let aabb = Aabb3::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 2.0, 3.0));
+
+    let hit = aabb
+        .intersect_ray(&ray([-5.0, 0.0, 0.0], [1.0, 0.0, 0.0]))
+        .unwrap();
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.normal, -Vector3::unit_x());
+
+    let hit = aabb
+        .intersect_ray(&ray([0.0, 0.0, 10.0], [0.1, 0.1, -1.0]))
+        .unwrap();
+    assert_ulps_eq!(hit.distance, 7.0);
+    assert_ulps_eq!(hit.point, Point3::new(0.7, 0.7, 3.0));
+    assert_eq!(hit.normal, Vector3::unit_z());
+
+    // Starting inside the box hits the face where the ray leaves
+    let hit = aabb
+        .intersect_ray(&ray([0.0, 0.0, 0.0], [0.0, 1.0, 0.0]))
+        .unwrap();
+    assert_eq!(hit.distance, 2.0);
+    assert_eq!(hit.normal, Vector3::unit_y());
+
+    assert_eq!(
+        aabb.intersect_ray(&ray([-5.0, 2.5, 0.0], [1.0, 0.0, 0.0])),
+        None
+    );
+    assert_eq!(
+        aabb.intersect_ray(&ray([-5.0, 0.0, 0.0], [-1.0, 0.0, 0.0])),
+        None
+    );
+    assert_eq!(
+        aabb.intersect_ray(&ray([-5.0, 0.0, 0.0], [1.0, 1.0, 0.0])),
+        None
+    );
+}
+
+#[test]
+fn test_obb() {
+    let obb = Obb3::new(
+        Point3::new(1.0, 0.0, 0.0),
+        Vector3::new(2.0, 0.5, 0.5),
+        Quaternion::from_angle_z(Deg(90.0)),
+    );
+
+    // The long axis of the box points along the world y axis
+    let hit = obb
+        .intersect_ray(&ray([1.0, 5.0, 0.0], [0.0, -1.0, 0.0]))
+        .unwrap();
+    assert_relative_eq!(hit.distance, 3.0, epsilon = 1e-12);
+    assert_relative_eq!(hit.point, Point3::new(1.0, 2.0, 0.0), epsilon = 1e-12);
+    assert_relative_eq!(hit.normal, Vector3::unit_y(), epsilon = 1e-12);
+
+    assert_eq!(
+        obb.intersect_ray(&ray([2.0, 5.0, 0.0], [0.0, -1.0, 0.0])),
+        None
+    );
+
+    // An axis-aligned box gives the same hits as an AABB
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 5.0));
+    let r = ray([-4.0, 3.0, 1.0], [1.0, -0.4, 0.5]);
+    let expected = aabb.intersect_ray(&r).unwrap();
+    let hit = Obb3::from_aabb(&aabb).intersect_ray(&r).unwrap();
+    assert_relative_eq!(hit.distance, expected.distance, epsilon = 1e-12);
+    assert_relative_eq!(hit.normal, expected.normal, epsilon = 1e-12);
+}
+
+#[test]
+fn test_triangle() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(2.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 2.0, 0.0);
+
+    let front = ray([0.5, 1.0, 3.0], [0.0, 0.0, -1.0]);
+    let hit = intersect_ray_triangle(&front, a, b, c, true).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.point, Point3::new(0.5, 1.0, 0.0));
+    assert_eq!(hit.normal, Vector3::unit_z());
+    let barycentric = hit.barycentric.unwrap();
+    assert_ulps_eq!(barycentric, Vector3::new(0.25, 0.25, 0.5));
+    assert_ulps_eq!(
+        Point3::from_vec(
+            a.to_vec() * barycentric.x + b.to_vec() * barycentric.y + c.to_vec() * barycentric.z
+        ),
+        hit.point
+    );
+
+    // Back faces are only hit without culling
+    let back = ray([0.5, 1.0, -3.0], [0.0, 0.0, 1.0]);
+    assert_eq!(intersect_ray_triangle(&back, a, b, c, true), None);
+    let hit = intersect_ray_triangle(&back, a, b, c, false).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, Vector3::unit_z());
+
+    // Outside the triangle, behind the ray, and parallel to the triangle
+    let miss = ray([1.5, 1.5, 3.0], [0.0, 0.0, -1.0]);
+    assert_eq!(intersect_ray_triangle(&miss, a, b, c, false), None);
+    let behind = ray([0.5, 0.5, -3.0], [0.0, 0.0, -1.0]);
+    assert_eq!(intersect_ray_triangle(&behind, a, b, c, false), None);
+    let parallel = ray([-1.0, 0.5, 0.0], [1.0, 0.0, 0.0]);
+    assert_eq!(intersect_ray_triangle(&parallel, a, b, c, false), None);
+}
+
+#[test]
+fn test_f32() {
+    let sphere = Sphere::new(Point3::new(0.0f32, 0.0, -5.0), 1.0);
+    let r = Ray3::new(Point3::new(0.0f32, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    assert_ulps_eq!(sphere.ray_distance(&r).unwrap(), 4.0f32);
+    assert_ulps_eq!(sphere.intersect_ray(&r).unwrap().distance, 4.0f32);
+
+    let hit = intersect_ray_triangle(
+        &r,
+        Point3::new(-1.0f32, -1.0, -2.0),
+        Point3::new(1.0, -1.0, -2.0),
+        Point3::new(0.0, 1.0, -2.0),
+        true,
+    )
+    .unwrap();
+    assert_eq!(hit.distance, 2.0f32);
+}
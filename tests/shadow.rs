@@ -0,0 +1,290 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn perspective_fov() -> PerspectiveFov<f64> {
+    PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 1.0,
+        far: 1000.0,
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_cascade_splits() {
+    let persp = perspective_fov();
+
+    let uniform = persp.cascade_splits(3, 0.0);
+    assert_eq!(uniform.len(), 4);
+    assert_ulps_eq!(uniform[1], 334.0);
+    assert_ulps_eq!(uniform[2], 667.0);
+
+    let log = persp.cascade_splits(3, 1.0);
+    assert_relative_eq!(log[1], 10.0, epsilon = 1e-9);
+    assert_relative_eq!(log[2], 100.0, epsilon = 1e-9);
+
+    let practical = persp.cascade_splits(3, 0.5);
+    assert_eq!(practical[0], 1.0);
+    assert_eq!(practical[3], 1000.0);
+    for i in 1..3 {
+        assert_relative_eq!(practical[i], (uniform[i] + log[i]) * 0.5, epsilon = 1e-9);
+    }
+    assert!(practical.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_frustum_corners() {
+    let persp = perspective_fov();
+    let tan_y = (30.0f64).to_radians().tan();
+    let tan_x = tan_y * 1.5;
+
+    let corners = persp.frustum_corners(2.0, 4.0, &Matrix4::identity());
+    assert_ulps_eq!(corners[0], Point3::new(-2.0 * tan_x, -2.0 * tan_y, -2.0));
+    assert_ulps_eq!(corners[2], Point3::new(2.0 * tan_x, 2.0 * tan_y, -2.0));
+    assert_ulps_eq!(corners[5], Point3::new(4.0 * tan_x, -4.0 * tan_y, -4.0));
+    assert_ulps_eq!(corners[7], Point3::new(-4.0 * tan_x, 4.0 * tan_y, -4.0));
+
+    // The corners are moved into world space by the camera transform
+    let view = Matrix4::look_at_rh(
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let camera = view.invert().unwrap();
+    let world = persp.frustum_corners(2.0, 4.0, &camera);
+    for (w, c) in world.iter().zip(corners.iter()) {
+        assert_relative_eq!(view.transform_point(*w), *c, epsilon = 1e-12);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_tight_cascades_enclose_frustum() {
+    let persp = perspective_fov();
+    let view = Matrix4::look_at_rh(
+        Point3::new(10.0, 5.0, -3.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let camera = view.invert().unwrap();
+    let light_view = Matrix4::look_to_rh(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(-1.0, -2.0, 0.5),
+        Vector3::unit_y(),
+    );
+
+    let cascades = persp.cascades(4, 0.75, &camera, &light_view, CascadeFit::Tight);
+    assert_eq!(cascades.len(), 4);
+    assert_eq!(cascades[0].near, 1.0);
+    assert_eq!(cascades[3].far, 1000.0);
+
+    for (cascade, next) in cascades.iter().zip(cascades[1..].iter()) {
+        assert_eq!(cascade.far, next.near);
+    }
+
+    for cascade in &cascades {
+        let m: Matrix4<f64> = cascade.projection.into();
+        let light_proj = m * light_view;
+        let mut touches = [false; 6];
+        for corner in &cascade.corners {
+            let p = light_proj.transform_point(*corner);
+            for (i, &c) in [p.x, p.y, p.z].iter().enumerate() {
+                assert!(
+                    c.abs() <= 1.0 + 1e-9,
+                    "{:?} is outside of the projection",
+                    p
+                );
+                touches[2 * i] |= abs_diff_eq!(c, -1.0, epsilon = 1e-9);
+                touches[2 * i + 1] |= abs_diff_eq!(c, 1.0, epsilon = 1e-9);
+            }
+        }
+        // The fit is tight on all sides
+        assert_eq!(touches, [true; 6]);
+    }
+}
+
+fn light_view() -> Matrix4<f64> {
+    Matrix4::look_to_rh(
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::new(-1.0, -2.0, 0.5),
+        Vector3::unit_y(),
+    )
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_stable_cascades_enclose_frustum() {
+    let persp = perspective_fov();
+    let view = Matrix4::look_at_rh(
+        Point3::new(10.0, 5.0, -3.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let camera = view.invert().unwrap();
+    let light_view = light_view();
+
+    let cascades = persp.cascades(4, 0.75, &camera, &light_view, CascadeFit::Stable(2048.0));
+    for cascade in &cascades {
+        let ortho = cascade.projection;
+        assert_eq!(ortho.right - ortho.left, ortho.top - ortho.bottom);
+
+        let texel = (ortho.right - ortho.left) / 2048.0;
+        assert_relative_eq!(
+            ortho.left / texel,
+            (ortho.left / texel).round(),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            ortho.bottom / texel,
+            (ortho.bottom / texel).round(),
+            epsilon = 1e-6
+        );
+
+        let m: Matrix4<f64> = ortho.into();
+        let light_proj = m * light_view;
+        for corner in &cascade.corners {
+            let p = light_proj.transform_point(*corner);
+            for &c in &[p.x, p.y, p.z] {
+                assert!(c.abs() <= 1.0, "{:?} is outside of the projection", p);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_stable_cascades_do_not_change_size() {
+    let persp = perspective_fov();
+    let light_view = light_view();
+    let eye = Point3::new(10.0, 5.0, -3.0);
+
+    let cascades_at = |angle: Deg<f64>| {
+        let rotation = Matrix4::from_angle_y(angle) * Matrix4::from_angle_x(angle / 3.0);
+        let camera = Matrix4::from_translation(eye.to_vec()) * rotation;
+        persp.cascades(4, 0.75, &camera, &light_view, CascadeFit::Stable(2048.0))
+    };
+
+    let reference = cascades_at(Deg(0.0));
+    for i in 1..36 {
+        let cascades = cascades_at(Deg(10.0 * i as f64));
+        for (cascade, reference) in cascades.iter().zip(reference.iter()) {
+            let (ortho, expected) = (cascade.projection, reference.projection);
+            assert_ulps_eq!(ortho.right - ortho.left, expected.right - expected.left);
+            assert_ulps_eq!(ortho.top - ortho.bottom, expected.top - expected.bottom);
+            assert_ulps_eq!(ortho.far - ortho.near, expected.far - expected.near);
+        }
+    }
+}
+
+#[test]
+fn test_stable_fit_moves_in_texels() {
+    let points = [
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.5, -0.5, 0.25),
+    ];
+    let light_view = light_view();
+    let ortho = Ortho::fit_stable(&points, &light_view, 512.0);
+    let texel = (ortho.right - ortho.left) / 512.0;
+
+    let offset = Vector3::new(0.3 * texel, -0.2 * texel, 0.0);
+    let moved: Vec<_> = points.iter().map(|&p| p + offset).collect();
+    let moved = Ortho::fit_stable(&moved, &light_view, 512.0);
+    assert_eq!(moved.right - moved.left, ortho.right - ortho.left);
+
+    let dx = (moved.left - ortho.left) / texel;
+    let dy = (moved.bottom - ortho.bottom) / texel;
+    assert_relative_eq!(dx, dx.round(), epsilon = 1e-6);
+    assert_relative_eq!(dy, dy.round(), epsilon = 1e-6);
+    assert!(dx.abs() <= 1.0 + 1e-6 && dy.abs() <= 1.0 + 1e-6);
+}
+
+#[test]
+fn test_stable_fit_encloses_points() {
+    // Points on the bounding sphere along the axes of the light
+    let identity = Matrix4::identity();
+    for i in 0..64 {
+        let center = Point3::new(i as f64 * 0.0137, i as f64 * -0.0291, 0.0);
+        let points = [
+            center + Vector3::new(-2.0, 0.0, 0.0),
+            center + Vector3::new(2.0, 0.0, 0.0),
+            center + Vector3::new(0.0, -2.0, 0.0),
+            center + Vector3::new(0.0, 2.0, 0.0),
+        ];
+        let ortho = Ortho::fit_stable(&points, &identity, 512.0);
+        for p in &points {
+            assert!(ortho.left <= p.x && p.x <= ortho.right);
+            assert!(ortho.bottom <= p.y && p.y <= ortho.top);
+        }
+    }
+
+    let light_view = light_view();
+    for i in 0..64 {
+        let t = i as f64 * 0.37;
+        let offset = Vector3::new(t.sin() * 3.0, t.cos() * 5.0, t * 0.1);
+        let points: Vec<_> = [
+            Point3::new(-1.0, -2.0, -0.5),
+            Point3::new(1.5, 1.0, 1.0),
+            Point3::new(0.25, -0.75, 2.0),
+            Point3::new(-0.5, 2.5, -1.0),
+        ]
+        .iter()
+        .map(|&p| p + offset * (1.0 + t))
+        .collect();
+
+        for &resolution in &[16.0, 100.0, 1024.0] {
+            let ortho = Ortho::fit_stable(&points, &light_view, resolution);
+            for &p in &points {
+                let p = light_view.transform_point(p);
+                assert!(ortho.left <= p.x && p.x <= ortho.right);
+                assert!(ortho.bottom <= p.y && p.y <= ortho.top);
+                assert!(ortho.near <= -p.z && -p.z <= ortho.far);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_snap_to_texels() {
+    let ortho: Ortho<f64> = Ortho {
+        left: -10.3,
+        right: 20.7,
+        bottom: -5.1,
+        top: 10.4,
+        near: 1.0,
+        far: 50.0,
+    };
+    let snapped = ortho.snap_to_texels(1024.0, 512.0);
+    let texel_x = 31.0 / 1024.0;
+    let texel_y = 15.5 / 512.0;
+
+    assert_relative_eq!(snapped.right - snapped.left, 31.0, epsilon = 1e-12);
+    assert_relative_eq!(snapped.top - snapped.bottom, 15.5, epsilon = 1e-12);
+
+    let texels_x = snapped.left / texel_x;
+    let texels_y = snapped.bottom / texel_y;
+    assert_relative_eq!(texels_x, texels_x.round(), epsilon = 1e-9);
+    assert_relative_eq!(texels_y, texels_y.round(), epsilon = 1e-9);
+    assert!(snapped.left <= ortho.left && ortho.left - snapped.left < texel_x);
+    assert!(snapped.bottom <= ortho.bottom && ortho.bottom - snapped.bottom < texel_y);
+    assert_eq!(snapped.near, ortho.near);
+    assert_eq!(snapped.far, ortho.far);
+}
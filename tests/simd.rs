@@ -0,0 +1,184 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+// Checks the `f32` operations that have SIMD implementations against scalar
+// reference implementations, evaluated in the same order as the generic code.
+// The tests pass both with and without the `simd` feature.
+
+use cgmath::*;
+
+/// A small deterministic generator of values in `[-10, 10)`.
+struct Values(u32);
+
+impl Values {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.0 >> 8) as f32 / (1 << 24) as f32 * 20.0 - 10.0
+    }
+
+    fn vector4(&mut self) -> Vector4<f32> {
+        Vector4::new(self.next(), self.next(), self.next(), self.next())
+    }
+
+    fn quaternion(&mut self) -> Quaternion<f32> {
+        Quaternion::new(self.next(), self.next(), self.next(), self.next())
+    }
+
+    fn matrix4(&mut self) -> Matrix4<f32> {
+        Matrix4::from_cols(
+            self.vector4(),
+            self.vector4(),
+            self.vector4(),
+            self.vector4(),
+        )
+    }
+}
+
+fn sample<T, F: FnMut(&mut Values) -> T>(mut f: F) -> Vec<T> {
+    let mut values = Values(0x2545_f491);
+    (0..256).map(|_| f(&mut values)).collect()
+}
+
+fn mul_matrix4_vector4(m: Matrix4<f32>, v: Vector4<f32>) -> Vector4<f32> {
+    let row = |i: usize| m[0][i] * v[0] + (m[1][i] * v[1] + (m[2][i] * v[2] + m[3][i] * v[3]));
+    Vector4::new(row(0), row(1), row(2), row(3))
+}
+
+#[test]
+fn test_vector4_ops() {
+    for (a, b, s) in sample(|v| (v.vector4(), v.vector4(), v.next())) {
+        assert_eq!(
+            a + b,
+            Vector4::new(a.x + b.x, a.y + b.y, a.z + b.z, a.w + b.w)
+        );
+        assert_eq!(
+            a - b,
+            Vector4::new(a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w)
+        );
+        assert_eq!(a * s, Vector4::new(a.x * s, a.y * s, a.z * s, a.w * s));
+        assert_eq!(a / s, Vector4::new(a.x / s, a.y / s, a.z / s, a.w / s));
+        assert_eq!(
+            a.mul_element_wise(b),
+            Vector4::new(a.x * b.x, a.y * b.y, a.z * b.z, a.w * b.w)
+        );
+        assert_eq!(
+            a.div_element_wise(b),
+            Vector4::new(a.x / b.x, a.y / b.y, a.z / b.z, a.w / b.w)
+        );
+    }
+}
+
+#[test]
+fn test_quaternion_mul() {
+    for (a, b) in sample(|v| (v.quaternion(), v.quaternion())) {
+        let expected = Quaternion::new(
+            a.s * b.s - a.v.x * b.v.x - a.v.y * b.v.y - a.v.z * b.v.z,
+            a.s * b.v.x + a.v.x * b.s + a.v.y * b.v.z - a.v.z * b.v.y,
+            a.s * b.v.y + a.v.y * b.s + a.v.z * b.v.x - a.v.x * b.v.z,
+            a.s * b.v.z + a.v.z * b.s + a.v.x * b.v.y - a.v.y * b.v.x,
+        );
+        assert_eq!(a * b, expected);
+    }
+}
+
+#[test]
+fn test_quaternion_invert() {
+    for q in sample(|v| v.quaternion()) {
+        let magnitude2 = q.s * q.s + (q.v.x * q.v.x + (q.v.y * q.v.y + q.v.z * q.v.z));
+        let expected = Quaternion::new(
+            q.s / magnitude2,
+            -q.v.x / magnitude2,
+            -q.v.y / magnitude2,
+            -q.v.z / magnitude2,
+        );
+        assert_eq!(q.invert(), expected);
+    }
+}
+
+#[test]
+fn test_matrix4_mul_vector4() {
+    for (m, v) in sample(|v| (v.matrix4(), v.vector4())) {
+        assert_eq!(m * v, mul_matrix4_vector4(m, v));
+    }
+}
+
+#[test]
+fn test_matrix4_mul() {
+    for (a, b) in sample(|v| (v.matrix4(), v.matrix4())) {
+        let column = |v: Vector4<f32>| {
+            let row = |i: usize| a[0][i] * v[0] + a[1][i] * v[1] + a[2][i] * v[2] + a[3][i] * v[3];
+            Vector4::new(row(0), row(1), row(2), row(3))
+        };
+        let expected = Matrix4::from_cols(column(b.x), column(b.y), column(b.z), column(b.w));
+        assert_eq!(a * b, expected);
+    }
+}
+
+#[test]
+fn test_matrix4_transpose() {
+    for m in sample(|v| v.matrix4()) {
+        let t = m.transpose();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(t[i][j], m[j][i]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matrix4_invert() {
+    for m in sample(|v| v.matrix4()) {
+        let expected = m.cast::<f64>().unwrap().invert().unwrap();
+        let inverse = m.invert().unwrap();
+
+        // Scale the tolerance by the condition number of the matrix
+        let norm = |m: Matrix4<f64>| (0..4).map(|i| m[i].map(f64::abs).sum()).fold(0.0, f64::max);
+        let condition = norm(m.cast().unwrap()) * norm(expected);
+        let tolerance = condition * f64::from(f32::EPSILON) * 16.0;
+
+        let error = inverse.cast::<f64>().unwrap() - expected;
+        assert!(
+            norm(error) <= tolerance * norm(expected),
+            "{:?} is not close to {:?}",
+            inverse,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_matrix4_invert_singular() {
+    let m = Matrix4::from_cols(
+        vec4(1.0f32, 2.0, 3.0, 4.0),
+        vec4(2.0, 4.0, 6.0, 8.0),
+        vec4(0.0, 1.0, 0.0, 1.0),
+        vec4(5.0, 0.0, 2.0, 1.0),
+    );
+    assert!(m.invert().is_none());
+    assert!(Matrix4::<f32>::zero().invert().is_none());
+}
+
+#[test]
+fn test_matrix4_invert_transform() {
+    let m = Matrix4::from_translation(vec3(1.0f32, -2.0, 3.0))
+        * Matrix4::from(Quaternion::from_angle_y(Deg(30.0f32)))
+        * Matrix4::from_nonuniform_scale(2.0, 0.5, 4.0);
+    assert_relative_eq!(m * m.invert().unwrap(), Matrix4::identity(), epsilon = 1e-6);
+    assert_relative_eq!(m.invert().unwrap() * m, Matrix4::identity(), epsilon = 1e-6);
+}
@@ -0,0 +1,194 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+/// A small deterministic generator for test points.
+fn random_points(count: usize, seed: u64) -> Vec<Point3<f64>> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    };
+    (0..count)
+        .map(|_| Point3::new(next(), next() * 3.0, next() * 0.5))
+        .collect()
+}
+
+/// The smallest sphere through any subset of up to four points that contains
+/// all the points.
+fn brute_force(points: &[Point3<f64>]) -> f64 {
+    let contains_all = |s: &Sphere<f64>| {
+        points
+            .iter()
+            .all(|&p| s.center.distance(p) <= s.radius + 1e-9)
+    };
+    let mut best = f64::INFINITY;
+    let n = points.len();
+    for i in 0..n {
+        for j in i..n {
+            for k in j..n {
+                for l in k..n {
+                    let subset = [points[i], points[j], points[k], points[l]];
+                    let sphere = Sphere::minimal_enclosing(&subset).unwrap();
+                    if contains_all(&sphere) && sphere.radius < best {
+                        best = sphere.radius;
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+#[test]
+fn test_contains() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 2.0);
+    assert!(sphere.contains(Point3::new(1.0, 2.0, 3.0)));
+    assert!(sphere.contains(Point3::new(1.0, 4.0, 3.0)));
+    assert!(!sphere.contains(Point3::new(2.5, 3.5, 3.0)));
+
+    assert!(sphere.contains_sphere(&Sphere::new(Point3::new(2.0, 2.0, 3.0), 1.0)));
+    assert!(!sphere.contains_sphere(&Sphere::new(Point3::new(2.0, 2.0, 3.0), 1.5)));
+}
+
+#[test]
+fn test_merge() {
+    let a = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Point3::new(4.0, 0.0, 0.0), 2.0);
+    let merged = a.merge(&b);
+    assert_ulps_eq!(merged, Sphere::new(Point3::new(2.5, 0.0, 0.0), 3.5));
+    assert_eq!(b.merge(&a), merged);
+
+    let inner = Sphere::new(Point3::new(4.5, 0.0, 0.0), 1.0);
+    assert_eq!(b.merge(&inner), b);
+    assert_eq!(inner.merge(&b), b);
+}
+
+#[test]
+fn test_transform() {
+    let sphere = Sphere::new(Point3::new(1.0, 0.0, 0.0), 2.0);
+    let transform = Decomposed {
+        scale: 3.0,
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(0.0, 0.0, 1.0),
+    };
+    assert_relative_eq!(
+        sphere.transform(&transform),
+        Sphere::new(Point3::new(0.0, 3.0, 1.0), 6.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_ray_distance() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0);
+
+    let ray = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -2.0));
+    assert_ulps_eq!(sphere.ray_distance(&ray).unwrap(), 2.0);
+
+    // Starting inside the sphere hits the far side
+    let ray = Ray3::new(Point3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, -1.0));
+    assert_ulps_eq!(sphere.ray_distance(&ray).unwrap(), 1.0);
+
+    // Pointing away from the sphere
+    let ray = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(sphere.ray_distance(&ray), None);
+
+    // Missing the sphere
+    let ray = Ray3::new(Point3::new(0.0, 1.5, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    assert_eq!(sphere.ray_distance(&ray), None);
+}
+
+#[test]
+fn test_minimal_enclosing_known() {
+    assert_eq!(Sphere::<f64>::minimal_enclosing(&[]), None);
+
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(Sphere::minimal_enclosing(&[p]), Some(Sphere::new(p, 0.0)));
+
+    // The vertices of an octahedron, with points inside
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.1, 0.2, 0.3),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    let sphere = Sphere::minimal_enclosing(&points).unwrap();
+    assert_relative_eq!(
+        sphere,
+        Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0),
+        epsilon = 1e-12
+    );
+
+    // Collinear and coplanar points
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 2.0, 2.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(4.0, 4.0, 4.0),
+    ];
+    let sphere = Sphere::minimal_enclosing(&points).unwrap();
+    assert_relative_eq!(sphere.center, Point3::new(2.0, 2.0, 2.0), epsilon = 1e-12);
+    let points = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+    ];
+    let sphere = Sphere::minimal_enclosing(&points).unwrap();
+    assert_relative_eq!(
+        sphere,
+        Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_minimal_enclosing_random() {
+    for seed in 0..20 {
+        let points = random_points(12, seed);
+        let sphere = Sphere::minimal_enclosing(&points).unwrap();
+        for &p in &points {
+            assert!(sphere.center.distance(p) <= sphere.radius + 1e-9);
+        }
+        assert_relative_eq!(sphere.radius, brute_force(&points), epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_ritter() {
+    assert_eq!(Sphere::<f64>::ritter(&[]), None);
+
+    for seed in 0..20 {
+        let points = random_points(100, seed);
+        let approx = Sphere::ritter(&points).unwrap();
+        let minimal = Sphere::minimal_enclosing(&points).unwrap();
+        for &p in &points {
+            assert!(approx.center.distance(p) <= approx.radius + 1e-9);
+        }
+        assert!(approx.radius >= minimal.radius - 1e-9);
+        assert!(approx.radius <= minimal.radius * 1.25);
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_area_and_normal() {
+    let triangle = Triangle::new(
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(4.0, 1.0, 0.0),
+        Point3::new(1.0, 5.0, 0.0),
+    );
+    assert_eq!(triangle.area(), 6.0);
+    assert_eq!(triangle.normal(), Vector3::unit_z());
+    assert_eq!(triangle.plane(), Some(Plane::new(Vector3::unit_z(), 0.0)));
+    assert_eq!(
+        Triangle::new(triangle.a, triangle.c, triangle.b).normal(),
+        -Vector3::unit_z()
+    );
+
+    let flat = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(1.0, 3.0),
+    );
+    assert_ulps_eq!(flat.area(), 3.0);
+    assert_ulps_eq!(flat.centroid(), Point2::new(1.0, 1.0));
+}
+
+#[test]
+fn test_barycentric_round_trip() {
+    let triangle = Triangle::new(
+        Point3::new(0.5, -1.0, 2.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(-2.0, 2.0, 0.0),
+    );
+    assert_relative_eq!(
+        triangle.barycentric(triangle.a),
+        Vector3::new(1.0, 0.0, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        triangle.barycentric(triangle.centroid()),
+        Vector3::new(1.0, 1.0, 1.0) / 3.0,
+        epsilon = 1e-12
+    );
+
+    for weights in &[
+        Vector3::new(0.2, 0.3, 0.5),
+        Vector3::new(-0.5, 1.0, 0.5),
+        Vector3::new(0.0, 0.0, 1.0),
+    ] {
+        let point = triangle.from_barycentric(*weights);
+        assert_relative_eq!(triangle.barycentric(point), *weights, epsilon = 1e-12);
+        assert_eq!(triangle.contains(point), weights.x >= 0.0);
+    }
+
+    // Points off the plane are projected onto it
+    let offset = triangle.normal() * 2.0;
+    let point = triangle.from_barycentric(Vector3::new(0.2, 0.3, 0.5)) + offset;
+    assert_relative_eq!(
+        triangle.barycentric(point),
+        Vector3::new(0.2, 0.3, 0.5),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_interpolate() {
+    let triangle = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 4.0),
+    );
+    let colors = [
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+    ];
+    assert_relative_eq!(
+        triangle.interpolate_at(Point2::new(1.0, 2.0), colors),
+        Vector4::new(0.25, 0.25, 0.5, 0.5),
+        epsilon = 1e-12
+    );
+    let uvs = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+    ];
+    assert_eq!(
+        Triangle::<Point2<f64>>::interpolate(Vector3::new(0.5, 0.25, 0.25), uvs),
+        Vector2::new(0.5, 0.25)
+    );
+}
+
+#[test]
+fn test_circumcenter_and_incenter() {
+    // A right triangle has its circumcenter on the hypotenuse
+    let triangle = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(6.0, 0.0),
+        Point2::new(0.0, 8.0),
+    );
+    let circumcenter = triangle.circumcenter().unwrap();
+    assert_ulps_eq!(circumcenter, Point2::new(3.0, 4.0));
+
+    // The inradius of a 6-8-10 triangle is 2
+    assert_ulps_eq!(triangle.incenter(), Point2::new(2.0, 2.0));
+
+    let triangle = Triangle::new(
+        Point3::new(0.5, -1.0, 2.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(-2.0, 2.0, 0.0),
+    );
+    let center = triangle.circumcenter().unwrap();
+    let r = center.distance(triangle.a);
+    assert_relative_eq!(center.distance(triangle.b), r, epsilon = 1e-12);
+    assert_relative_eq!(center.distance(triangle.c), r, epsilon = 1e-12);
+    assert_relative_eq!(
+        triangle.plane().unwrap().signed_distance(center),
+        0.0,
+        epsilon = 1e-12
+    );
+
+    let collinear = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 2.0),
+    );
+    assert_eq!(collinear.circumcenter(), None);
+}
+
+#[test]
+fn test_intersect_ray() {
+    let triangle = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    );
+    let ray = Ray3::new(Point3::new(0.5, 1.0, -3.0), Vector3::new(0.0, 0.0, 1.0));
+    let hit = triangle.intersect_ray(&ray).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.barycentric.unwrap(), triangle.barycentric(hit.point));
+}
+
+#[test]
+fn test_triangle_closest_point_regions() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(4.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 4.0, 0.0);
+    let triangle = Triangle::new(a, b, c);
+    let closest = |x: f64, y: f64| triangle.closest_point(Point3::new(x, y, 3.0));
+
+    // Vertices
+    assert_eq!(closest(-1.0, -1.0), a);
+    assert_eq!(closest(6.0, -1.0), b);
+    assert_eq!(closest(-1.0, 6.0), c);
+
+    // Edges
+    assert_eq!(closest(2.0, -3.0), Point3::new(2.0, 0.0, 0.0));
+    assert_eq!(closest(-3.0, 2.0), Point3::new(0.0, 2.0, 0.0));
+    assert_eq!(closest(3.0, 3.0), Point3::new(2.0, 2.0, 0.0));
+
+    // Interior
+    assert_eq!(closest(1.0, 1.0), Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(triangle.distance2(Point3::new(1.0, 1.0, 3.0)), 9.0);
+}
+
+#[test]
+fn test_triangle_closest_point_brute_force() {
+    let a = Point3::new(0.5, -1.0, 2.0);
+    let b = Point3::new(3.0, 1.0, -1.0);
+    let c = Point3::new(-2.0, 2.0, 0.0);
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(5.0, 1.0, 1.0),
+        Point3::new(-3.0, 4.0, -2.0),
+        Point3::new(1.0, -3.0, 3.0),
+        Point3::new(0.5, 0.7, 0.3),
+    ];
+
+    for &p in &points {
+        let mut best = f64::INFINITY;
+        for i in 0..=100 {
+            for j in 0..=(100 - i) {
+                let (u, v) = (i as f64 / 100.0, j as f64 / 100.0);
+                let q = a + (b - a) * u + (c - a) * v;
+                best = best.min(p.distance2(q));
+            }
+        }
+        let distance2 = Triangle::new(a, b, c).distance2(p);
+        assert!(distance2 <= best + 1e-12);
+        assert!(distance2 >= best - 0.05);
+    }
+}
+
+#[test]
+fn test_triangle_closest_point_2d() {
+    let triangle = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(0.0, 2.0),
+    );
+    assert_eq!(
+        triangle.closest_point(Point2::new(0.5, 0.5)),
+        Point2::new(0.5, 0.5)
+    );
+    assert_eq!(
+        triangle.closest_point(Point2::new(2.0, 2.0)),
+        Point2::new(1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_closest_point_regions() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(4.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 4.0, 0.0);
+    let closest = |x: f64, y: f64| closest_point_triangle(Point3::new(x, y, 3.0), a, b, c);
+
+    // Vertices
+    assert_eq!(closest(-1.0, -1.0), a);
+    assert_eq!(closest(6.0, -1.0), b);
+    assert_eq!(closest(-1.0, 6.0), c);
+
+    // Edges
+    assert_eq!(closest(2.0, -3.0), Point3::new(2.0, 0.0, 0.0));
+    assert_eq!(closest(-3.0, 2.0), Point3::new(0.0, 2.0, 0.0));
+    assert_eq!(closest(3.0, 3.0), Point3::new(2.0, 2.0, 0.0));
+
+    // Interior
+    assert_eq!(closest(1.0, 1.0), Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(distance2_triangle(Point3::new(1.0, 1.0, 3.0), a, b, c), 9.0);
+}
+
+#[test]
+fn test_closest_point_brute_force() {
+    let a = Point3::new(0.5, -1.0, 2.0);
+    let b = Point3::new(3.0, 1.0, -1.0);
+    let c = Point3::new(-2.0, 2.0, 0.0);
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(5.0, 1.0, 1.0),
+        Point3::new(-3.0, 4.0, -2.0),
+        Point3::new(1.0, -3.0, 3.0),
+        Point3::new(0.5, 0.7, 0.3),
+    ];
+
+    for &p in &points {
+        let mut best = f64::INFINITY;
+        for i in 0..=100 {
+            for j in 0..=(100 - i) {
+                let (u, v) = (i as f64 / 100.0, j as f64 / 100.0);
+                let q = a + (b - a) * u + (c - a) * v;
+                best = best.min(p.distance2(q));
+            }
+        }
+        let distance2 = distance2_triangle(p, a, b, c);
+        assert!(distance2 <= best + 1e-12);
+        assert!(distance2 >= best - 0.05);
+    }
+}
+
+#[test]
+fn test_closest_point_2d() {
+    let a = Point2::new(0.0, 0.0);
+    let b = Point2::new(2.0, 0.0);
+    let c = Point2::new(0.0, 2.0);
+    assert_eq!(
+        closest_point_triangle(Point2::new(0.5, 0.5), a, b, c),
+        Point2::new(0.5, 0.5)
+    );
+    assert_eq!(
+        closest_point_triangle(Point2::new(2.0, 2.0), a, b, c),
+        Point2::new(1.0, 1.0)
+    );
+}
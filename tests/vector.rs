@@ -335,6 +335,110 @@ fn test_project_on() {
     );
 }
 
+#[test]
+fn test_reject_from() {
+    let v = Vector3::new(5.0f64, 6.0, 7.0);
+    let n = Vector3::new(1.0, 1.0, 1.0);
+    assert_ulps_eq!(v.reject_from(n), &Vector3::new(-1.0, 0.0, 1.0));
+    assert_ulps_eq!(v.reject_from(n) + v.project_on(n), &v);
+    assert_ulps_eq!(v.reject_from(n).dot(n), &0.0);
+}
+
+#[test]
+fn test_reflect() {
+    assert_eq!(
+        Vector2::new(1.0f64, -1.0).reflect(Vector2::unit_y()),
+        Vector2::new(1.0, 1.0)
+    );
+    assert_eq!(
+        Vector3::new(1.0f64, 2.0, -3.0).reflect(Vector3::unit_z()),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        Vector3::new(1, 2, -3).reflect(Vector3::unit_z()),
+        Vector3::new(1, 2, 3)
+    );
+}
+
+#[test]
+fn test_refract() {
+    let n = Vector2::unit_y();
+    let i = Vector2::new(1.0f64, -1.0).normalize();
+
+    // No change in the medium.
+    assert_ulps_eq!(i.refract(n, 1.0).unwrap(), &i);
+
+    // Snell's law: sin(t) = eta * sin(i).
+    let eta = 1.0 / 1.5;
+    let t = i.refract(n, eta).unwrap();
+    assert_ulps_eq!(t.magnitude(), &1.0);
+    assert_ulps_eq!(t.x, &(eta * i.x));
+    assert!(t.y < 0.0);
+
+    // Total internal reflection when leaving the denser medium at 45 degrees.
+    assert_eq!(i.refract(n, 1.5), None);
+}
+
+#[test]
+fn test_faceforward() {
+    let n = Vector3::<f64>::unit_z();
+    assert_eq!(n.faceforward(-Vector3::unit_z(), n), n);
+    assert_eq!(n.faceforward(Vector3::unit_z(), n), -n);
+    assert_eq!(n.faceforward(Vector3::unit_z(), -n), n);
+}
+
+#[test]
+fn test_clamp_magnitude() {
+    let v = Vector2::new(3.0f64, 4.0);
+    assert_ulps_eq!(v.clamp_magnitude(2.5), &Vector2::new(1.5, 2.0));
+    assert_eq!(v.clamp_magnitude(5.0), v);
+    assert_eq!(v.clamp_magnitude(10.0), v);
+}
+
+#[test]
+fn test_slerp() {
+    let a = Vector3::unit_x();
+    let b = Vector3::unit_y();
+    let half = f64::consts::FRAC_1_SQRT_2;
+    assert_ulps_eq!(a.slerp(b, 0.0).unwrap(), &a);
+    assert_ulps_eq!(a.slerp(b, 1.0).unwrap(), &b);
+    assert_ulps_eq!(a.slerp(b, 0.5).unwrap(), &Vector3::new(half, half, 0.0));
+    assert_ulps_eq!(a.slerp(b, 0.5).unwrap().magnitude(), &1.0);
+    assert_ulps_eq!(
+        Vector2::new(1.0f64, 0.0)
+            .slerp(Vector2::new(0.0, 1.0), 1.0 / 3.0)
+            .unwrap(),
+        &Vector2::new(3.0f64.sqrt() / 2.0, 0.5)
+    );
+
+    // Parallel vectors fall back to normalized linear interpolation.
+    assert_ulps_eq!(a.slerp(a, 0.5).unwrap(), &a);
+
+    // Opposite vectors have no unique arc.
+    for &amount in &[0.0, 0.25, 0.5] {
+        assert_eq!(a.slerp(-a, amount), None);
+    }
+    assert_eq!(
+        Vector2::new(0.0f32, 1.0).slerp(Vector2::new(0.0, -1.0), 0.75),
+        None
+    );
+}
+
+#[test]
+fn test_angle_signed() {
+    let x = Vector3::new(1.0f64, 0.0, 0.0);
+    let y = Vector3::new(0.0f64, 1.0, 0.0);
+    let z = Vector3::new(0.0f64, 0.0, 1.0);
+    assert_ulps_eq!(x.angle_signed(y, z), &Rad(f64::consts::FRAC_PI_2));
+    assert_ulps_eq!(y.angle_signed(x, z), &-Rad(f64::consts::FRAC_PI_2));
+    assert_ulps_eq!(x.angle_signed(y, z * 5.0), &Rad(f64::consts::FRAC_PI_2));
+    assert_ulps_eq!(
+        x.angle_signed(Vector3::new(-1.0, 1.0, 0.0), -z),
+        &-Rad(3.0 * f64::consts::FRAC_PI_4)
+    );
+    assert_ulps_eq!(x.angle_signed(x * 2.0, z), &Rad(0.0));
+}
+
 #[test]
 fn test_cast() {
     assert_ulps_eq!(
@@ -0,0 +1,294 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn vectors() -> [Vector3<f64>; 4] {
+    [
+        vec3(1.0, 2.0, 3.0),
+        vec3(-4.0, 0.5, 2.0),
+        vec3(0.0, -1.0, 0.25),
+        vec3(7.0, 3.0, -2.0),
+    ]
+}
+
+fn others() -> [Vector3<f64>; 4] {
+    [
+        vec3(0.5, -1.0, 2.0),
+        vec3(3.0, 3.0, -1.0),
+        vec3(-2.0, 0.0, 4.0),
+        vec3(1.0, 1.0, 1.0),
+    ]
+}
+
+fn quaternions() -> [Quaternion<f64>; 4] {
+    [
+        Quaternion::from_angle_x(Deg(30.0)),
+        Quaternion::from_angle_y(Deg(-45.0)),
+        Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0).normalize(), Deg(120.0)),
+        Quaternion::new(1.0, 2.0, -1.0, 0.5),
+    ]
+}
+
+#[test]
+fn test_conversions() {
+    let v = Vector3x4::from_vectors(vectors());
+    assert_eq!(v.x, [1.0, -4.0, 0.0, 7.0]);
+    assert_eq!(v.to_vectors(), vectors());
+    assert_eq!(
+        <[Vector3<f64>; 4]>::from(Vector3x4::from(vectors())),
+        vectors()
+    );
+    assert_eq!(v.lane(1), vec3(-4.0, 0.5, 2.0));
+
+    let mut w = Vector3x4::splat(vec3(1.0, 2.0, 3.0));
+    w.set_lane(2, vec3(4.0, 5.0, 6.0));
+    assert_eq!(w.lane(0), vec3(1.0, 2.0, 3.0));
+    assert_eq!(w.lane(2), vec3(4.0, 5.0, 6.0));
+
+    let q = Quaternionx4::from_quaternions(quaternions());
+    assert_eq!(q.to_quaternions(), quaternions());
+    assert_eq!(q.lane(3), Quaternion::new(1.0, 2.0, -1.0, 0.5));
+}
+
+#[test]
+fn test_arithmetic() {
+    let (a, b) = (vectors(), others());
+    let (wa, wb) = (Vector3x4::from(a), Vector3x4::from(b));
+    let s = [1.0, 2.0, -3.0, 0.5];
+    let t = Scalarx4([0.25, 0.5, 0.0, 1.0]);
+
+    for i in 0..4 {
+        assert_eq!((wa + wb).lane(i), a[i] + b[i]);
+        assert_eq!((wa - wb).lane(i), a[i] - b[i]);
+        assert_eq!((-wa).lane(i), -a[i]);
+        assert_eq!((wa * 2.0).lane(i), a[i] * 2.0);
+        assert_eq!((wa / 2.0).lane(i), a[i] / 2.0);
+        assert_eq!((wa * s).lane(i), a[i] * s[i]);
+        assert_eq!((wa / s).lane(i), a[i] / s[i]);
+        assert_eq!(wa.mul_element_wise(wb).lane(i), a[i].mul_element_wise(b[i]));
+        assert_eq!((wa * Scalarx4(s)).lane(i), a[i] * s[i]);
+        assert_eq!((wa % Scalarx4(s)).lane(i), a[i] % s[i]);
+        assert_eq!(wa.lerp(wb, t).lane(i), a[i].lerp(b[i], t[i]));
+        assert_eq!(wa.add_element_wise(wb).lane(i), a[i] + b[i]);
+        assert_eq!(wa.div_element_wise(wb).lane(i), a[i].div_element_wise(b[i]));
+        assert_eq!(wa.sub_element_wise(t).lane(i), a[i].sub_element_wise(t[i]));
+    }
+
+    let mut c = wa;
+    c += wb;
+    c -= wb;
+    c *= 3.0;
+    c /= 3.0;
+    assert_eq!(c, wa);
+
+    assert_eq!([wa, wb].iter().sum::<Vector3x4<f64>>(), wa + wb);
+    assert!(Vector3x4::<f64>::zero().is_zero());
+}
+
+#[test]
+fn test_scalars() {
+    let a = Scalarx4([1.0, -4.0, 0.25, 9.0]);
+    let b = Scalarx4([2.0, 2.0, 0.5, -3.0]);
+
+    assert_eq!(a + b, Scalarx4([3.0, -2.0, 0.75, 6.0]));
+    assert_eq!(a * b, Scalarx4([2.0, -8.0, 0.125, -27.0]));
+    assert_eq!(Scalarx4::<f64>::one(), Scalarx4::splat(1.0));
+    assert_eq!(a.abs().sqrt(), Scalarx4([1.0, 2.0, 0.5, 3.0]));
+    assert_eq!(a.max(b), Scalarx4([2.0, 2.0, 0.5, 9.0]));
+    assert_eq!(a.min(b), Scalarx4([1.0, -4.0, 0.25, -3.0]));
+    assert_eq!(b.recip(), Scalarx4([0.5, 0.5, 2.0, -1.0 / 3.0]));
+    assert_eq!(a.mul_add(b, a), Scalarx4([3.0, -12.0, 0.375, -18.0]));
+
+    // Comparisons produce a mask rather than a single ordering
+    assert_eq!(a.lt_element_wise(b), BVec4::new(true, true, true, false));
+    assert_eq!(a.ge_element_wise(1.0), BVec4::new(true, false, false, true));
+    assert_eq!(a.eq_element_wise(a), BVec4::splat(true));
+    assert_eq!(a.ne_element_wise(b), BVec4::splat(true));
+
+    let nan = (a - a) / Scalarx4([0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(
+        nan.ne_element_wise(nan),
+        BVec4::new(true, false, true, false)
+    );
+}
+
+#[test]
+fn test_inner_space() {
+    let (a, b) = (vectors(), others());
+    let (wa, wb) = (Vector3x4::from(a), Vector3x4::from(b));
+
+    for i in 0..4 {
+        assert_eq!(wa.dot(wb)[i], a[i].dot(b[i]));
+        assert_eq!(wa.cross(wb).lane(i), a[i].cross(b[i]));
+        assert_eq!(wa.magnitude2()[i], a[i].magnitude2());
+        assert_eq!(wa.magnitude()[i], a[i].magnitude());
+        assert_eq!(wa.distance(wb)[i], a[i].distance(b[i]));
+        assert_ulps_eq!(wb.normalize().lane(i), b[i].normalize());
+        assert_ulps_eq!(wa.project_on(wb).lane(i), a[i].project_on(b[i]));
+        assert_relative_eq!(
+            wa.reflect(wb.normalize()).lane(i),
+            a[i].reflect(b[i].normalize()),
+            epsilon = 1e-12
+        );
+        assert_eq!(wa.reject_from(wb).lane(i), a[i].reject_from(b[i]));
+        assert_eq!(wa.faceforward(wb, wa).lane(i), a[i].faceforward(b[i], a[i]));
+        assert_eq!(
+            wa.clamp_magnitude(Scalarx4::splat(3.0)).lane(i),
+            a[i].clamp_magnitude(3.0)
+        );
+        assert_relative_eq!(wa.angle(wb).0[i], a[i].angle(b[i]).0);
+    }
+}
+
+#[test]
+fn test_refract() {
+    let normal = Vector3x4::splat(Vector3::unit_y());
+    let incident = Vector3x4::from([
+        vec3(1.0, -1.0, 0.0).normalize(),
+        vec3(0.0, -1.0, 0.0),
+        vec3(1.0, -0.1, 0.0).normalize(),
+        vec3(0.0, -1.0, 1.0).normalize(),
+    ]);
+    let eta = Scalarx4::splat(1.5);
+
+    let refracted = incident.refract(normal, eta);
+    for i in 0..4 {
+        let expected = incident
+            .lane(i)
+            .refract(Vector3::unit_y(), 1.5)
+            .unwrap_or(Vector3::zero());
+        assert_ulps_eq!(refracted.lane(i), expected);
+    }
+    assert_eq!(refracted.lane(2), Vector3::zero());
+
+    let grazing = Vector3x4::splat(vec3(1.0, -0.1, 0.0).normalize());
+    assert_eq!(grazing.refract(normal, eta), Vector3x4::zero());
+}
+
+#[test]
+fn test_masks() {
+    let a = Vector3x4::from(vectors());
+    let b = Vector3x4::from(others());
+
+    let mask = a.magnitude2().lt_element_wise(b.magnitude2());
+    assert_eq!(mask, BVec4::new(false, false, true, false));
+    assert!(mask.any());
+    assert!(!mask.all());
+    assert!(!mask.none());
+    assert!((mask | !mask).all());
+    assert!((mask & !mask).none());
+    assert_eq!(mask ^ BVec4::splat(true), !mask);
+    assert_eq!(
+        mask.select(Scalarx4([1, 2, 3, 4]), Scalarx4::splat(0)),
+        Scalarx4([0, 0, 3, 0])
+    );
+
+    let selected = Vector3x4::select(mask, a, b);
+    assert_eq!(selected.lane(0), b.lane(0));
+    assert_eq!(selected.lane(2), a.lane(2));
+
+    let mut c = a;
+    c.set_lane(2, b.lane(2));
+    assert_eq!(c.lanes_eq(a), BVec4::new(true, true, false, true));
+    assert_eq!(c.lanes_ne(a), BVec4::new(false, false, true, false));
+
+    let small = Vector3x4::splat(vec3(-1.0, 0.0, 0.0));
+    let large = Vector3x4::from([
+        vec3(0.0, 1.0, 2.0),
+        vec3(-1.0, 1.0, 1.0),
+        vec3(-2.0, 1.0, 1.0),
+        vec3(0.0, 0.0, 0.0),
+    ]);
+    assert_eq!(small.lt(large), BVec4::new(true, false, false, false));
+    assert_eq!(small.le(large), BVec4::new(true, true, false, true));
+    assert_eq!(large.gt(small), BVec4::new(true, false, false, false));
+    assert_eq!(large.ge(small), BVec4::new(true, true, false, true));
+}
+
+#[test]
+fn test_quaternions() {
+    let (qs, vs) = (quaternions(), vectors());
+    let (wq, wv) = (Quaternionx4::from(qs), Vector3x4::from(vs));
+    let other = Quaternionx4::splat(Quaternion::from_angle_z(Deg(60.0)));
+
+    for i in 0..4 {
+        assert_eq!((wq * other).lane(i), qs[i] * other.lane(i));
+        assert_eq!((wq * wv).lane(i), qs[i] * vs[i]);
+        assert_eq!(wq.rotate_vector(wv).lane(i), qs[i] * vs[i]);
+        assert_eq!(wq.conjugate().lane(i), qs[i].conjugate());
+        assert_eq!(wq.magnitude()[i], qs[i].magnitude());
+        assert_eq!(wq.dot(other)[i], qs[i].dot(other.lane(i)));
+        assert_eq!((wq + other).lane(i), qs[i] + other.lane(i));
+        assert_eq!((-wq).lane(i), -qs[i]);
+        assert_ulps_eq!(wq.normalize().lane(i), qs[i].normalize());
+        assert_eq!((qs[0] * wv).lane(i), qs[0] * vs[i]);
+    }
+}
+
+#[test]
+fn test_matrices() {
+    let vs = vectors();
+    let wv = Vector3x4::from(vs);
+    let m3 = Matrix3::from_angle_y(Deg(30.0)) * Matrix3::from_diagonal(vec3(2.0, 1.0, 0.5));
+    let m4 =
+        perspective(Deg(60.0), 1.5, 0.1, 100.0) * Matrix4::from_translation(vec3(1.0, 2.0, -20.0));
+
+    for (i, &v) in vs.iter().enumerate() {
+        assert_eq!((m3 * wv).lane(i), m3 * v);
+        assert_eq!(
+            wv.transform_point(&m4).lane(i),
+            m4.transform_point(Point3::from_vec(v)).to_vec()
+        );
+        assert_eq!(wv.transform_vector(&m4).lane(i), m4.transform_vector(v));
+    }
+}
+
+#[test]
+fn test_eight_lanes() {
+    let vs: [Vector3<f32>; 8] = [
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.0, 1.0, 0.0),
+        vec3(0.0, 0.0, 1.0),
+        vec3(1.0, 1.0, 0.0),
+        vec3(0.0, 1.0, 1.0),
+        vec3(1.0, 0.0, 1.0),
+        vec3(1.0, 1.0, 1.0),
+        vec3(2.0, -1.0, 3.0),
+    ];
+    let w = Vector3x8::from(vs);
+    let up = Vector3x8::splat(Vector3::unit_y());
+
+    let facing = w.dot(up).gt_element_wise(0.0);
+    assert_eq!(
+        facing,
+        Mask8([false, true, false, true, true, false, true, false])
+    );
+    assert!(facing.any() && !facing.all() && !facing.none());
+    assert_eq!(!facing & facing, Mask8::default());
+    assert_eq!(
+        facing.select(w.dot(up), Scalarx8::splat(-1.0)),
+        Scalarx8([-1.0, 1.0, -1.0, 1.0, 1.0, -1.0, 1.0, -1.0])
+    );
+
+    let crossed = w.cross(up);
+    for (i, v) in vs.iter().enumerate() {
+        assert_eq!(crossed.lane(i), v.cross(Vector3::unit_y()));
+    }
+    assert_eq!(w.to_vectors(), vs);
+}